@@ -111,13 +111,14 @@ impl<T: ComposeSchema> ComposeSchema for Box<T> {
 }
 
 impl<K: ComposeSchema, V: ComposeSchema> ComposeSchema for std::collections::HashMap<K, V> {
-    fn compose(_generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
-        // Map is represented as an object with additionalProperties = true
-        // OpenCLI doesn't support schemas for additionalProperties like OpenAPI does
+    fn compose(generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        // Map is represented as an object whose additionalProperties are the value schema
         RefOr::T(Schema::Object(Box::new(
             crate::Object::new()
                 .schema_type(SchemaType::Object)
-                .additional_properties(Some(true)),
+                .additional_properties(crate::AdditionalProperties::from(schema_or_compose::<V>(
+                    generics, 1,
+                ))),
         )))
     }
 }
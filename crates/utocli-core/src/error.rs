@@ -0,0 +1,79 @@
+//! Error type returned when serializing, deserializing or validating specifications.
+
+use std::fmt;
+
+use crate::opencli::{MergeComponentsError, ParseExitCodeError, ValidationError};
+
+/// An error returned by the OpenCLI specification I/O, validation and building methods.
+///
+/// The serialization backend errors are boxed, so callers can match on the error category
+/// without depending on the serde format crates. The specific errors returned by
+/// [`Components::merge`](crate::opencli::Components::merge) and the [`ExitCode`]
+/// parser convert into this type, so `?` works across all of them.
+///
+/// [`ExitCode`]: crate::opencli::ExitCode
+#[derive(Debug)]
+pub enum Error {
+    /// The specification could not be serialized (e.g., to JSON or TOML).
+    Serialization(Box<dyn std::error::Error + Send + Sync>),
+    /// The input could not be deserialized into a specification.
+    Deserialization(Box<dyn std::error::Error + Send + Sync>),
+    /// The specification is invalid; holds every error found.
+    Validation(Vec<ValidationError>),
+    /// Components could not be merged because of conflicting names.
+    MergeComponents(MergeComponentsError),
+    /// An exit code could not be parsed.
+    ParseExitCode(ParseExitCodeError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Serialization(err) => write!(f, "failed to serialize specification: {err}"),
+            Error::Deserialization(err) => {
+                write!(f, "failed to deserialize specification: {err}")
+            }
+            Error::Validation(errors) => {
+                write!(f, "invalid specification: ")?;
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{err}")?;
+                }
+                Ok(())
+            }
+            Error::MergeComponents(err) => write!(f, "failed to merge components: {err}"),
+            Error::ParseExitCode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Serialization(err) | Error::Deserialization(err) => Some(err.as_ref()),
+            Error::MergeComponents(err) => Some(err),
+            Error::ParseExitCode(err) => Some(err),
+            Error::Validation(_) => None,
+        }
+    }
+}
+
+impl From<Vec<ValidationError>> for Error {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Error::Validation(errors)
+    }
+}
+
+impl From<MergeComponentsError> for Error {
+    fn from(err: MergeComponentsError) -> Self {
+        Error::MergeComponents(err)
+    }
+}
+
+impl From<ParseExitCodeError> for Error {
+    fn from(err: ParseExitCodeError) -> Self {
+        Error::ParseExitCode(err)
+    }
+}
@@ -7,6 +7,7 @@
 
 mod builder_macros;
 mod compose_schema;
+mod error;
 pub mod opencli;
 mod to_response;
 mod to_schema;
@@ -16,11 +17,14 @@ use std::collections::BTreeMap;
 // Re-export main types at the crate root for convenience
 pub use self::{
     compose_schema::{ComposeSchema, schema_or_compose},
+    error::Error,
     opencli::{
-        Architecture, Arity, Array, Command, Commands, Components, Contact, EnvironmentVariable,
-        Extensions, ExternalDocs, Info, License, Map, MediaType, Object, Parameter, ParameterIn,
+        AdditionalProperties, AllOf, AnyOf, Architecture, Arity, Array, Command, CommandExample,
+        CommandNode, Commands, CommandsExt, Components, Contact, Discriminator,
+        EnvironmentVariable, ExclusiveBound, ExitCode, Extensions, ExternalDocs, FlagStyle, Info,
+        License, Lint, Map, MediaType, Object, OneOf, OrderedResponses, Parameter, ParameterIn,
         ParameterScope, Platform, PlatformName, Ref, RefOr, Response, Schema, SchemaFormat,
-        SchemaType, Tag,
+        SchemaType, SpecChange, SpecDiff, Tag, ValidationError,
     },
     to_response::ToResponse,
     to_schema::ToSchema,
@@ -142,6 +146,7 @@ pub trait CommandPath {
     ///
     /// For root commands, this is typically just the command name (e.g., "ocs").
     /// For subcommands, this uses the path format (e.g., "/validate" for a subcommand).
+    /// Nested subcommands include their parent path (e.g., "/remote/add").
     fn path() -> &'static str;
 
     /// Returns [`opencli::Command`] describing the CLI command specification including
@@ -182,4 +187,12 @@ pub trait IntoResponses {
     ///
     /// Exit codes are strings like "0", "1", "2" etc., following shell exit code conventions.
     fn responses() -> BTreeMap<String, RefOr<Response>>;
+
+    /// Returns the responses ordered by exit code.
+    ///
+    /// Unlike [`IntoResponses::responses`], numeric exit codes are ordered numerically (`"2"`
+    /// before `"10"`), followed by ranges, the `"default"` response and any other key.
+    fn ordered_responses() -> OrderedResponses {
+        Self::responses().into()
+    }
 }
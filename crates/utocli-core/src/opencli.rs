@@ -3,34 +3,49 @@
 //! This module provides the core types for representing an OpenCLI v1.0.0 specification,
 //! which describes a CLI application in a machine-readable format.
 
-use crate::builder_macros::{build_fn, builder, from};
+use std::collections::BTreeMap;
+
+use crate::{
+    Error,
+    builder_macros::{build_fn, builder, from},
+};
 
 pub mod command;
 pub mod components;
+pub mod diff;
 pub mod environment;
 pub mod extensions;
 pub mod external_docs;
 pub mod info;
+pub mod lint;
 pub mod map;
 pub mod parameter;
 pub mod platform;
 pub mod response;
 pub mod schema;
+mod serialize;
 pub mod tag;
+pub mod validation;
 
 pub use self::{
-    command::{Command, Commands},
-    components::Components,
+    command::{Command, CommandExample, CommandNode, Commands, CommandsExt},
+    components::{Components, MergeComponentsError},
+    diff::{SpecChange, SpecDiff},
     environment::EnvironmentVariable,
     extensions::Extensions,
     external_docs::ExternalDocs,
     info::{Contact, Info, License},
+    lint::Lint,
     map::Map,
-    parameter::{Arity, Parameter, ParameterIn, ParameterScope},
+    parameter::{Arity, FlagStyle, Parameter, ParameterIn, ParameterScope},
     platform::{Architecture, Platform, PlatformName},
-    response::{MediaType, Response},
-    schema::{Array, Object, Ref, RefOr, Schema, SchemaFormat, SchemaType},
+    response::{ExitCode, MediaType, OrderedResponses, ParseExitCodeError, Response},
+    schema::{
+        AdditionalProperties, AllOf, AnyOf, Array, Discriminator, ExclusiveBound, Object, OneOf,
+        Ref, RefOr, Schema, SchemaFormat, SchemaType,
+    },
     tag::Tag,
+    validation::ValidationError,
 };
 
 builder! {
@@ -53,26 +68,38 @@ builder! {
         pub external_docs: Option<ExternalDocs>,
 
         /// Platform and architecture support information.
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
         pub platforms: Option<Vec<Platform>>,
 
         /// Environment variable mappings.
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
         pub environment: Option<Vec<EnvironmentVariable>>,
 
+        /// Install commands keyed by package manager (e.g., `brew` or `cargo`).
+        #[serde(
+            rename = "x-install",
+            skip_serializing_if = "crate::opencli::serialize::is_none_or_empty"
+        )]
+        pub install: Option<Map<String, String>>,
+
         /// Tags for organizing commands into groups.
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
         pub tags: Option<Vec<Tag>>,
 
         /// The commands exposed by the CLI application.
         pub commands: Commands,
 
         /// Reusable component definitions.
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(skip_serializing_if = "components_is_none_or_empty")]
         pub components: Option<Components>,
     }
 }
 
+/// Returns whether the components are missing or declare no component at all.
+fn components_is_none_or_empty(components: &Option<Components>) -> bool {
+    components.as_ref().is_none_or(Components::is_empty)
+}
+
 impl Default for OpenCliBuilder {
     fn default() -> Self {
         Self {
@@ -81,6 +108,7 @@ impl Default for OpenCliBuilder {
             external_docs: None,
             platforms: None,
             environment: None,
+            install: None,
             tags: None,
             commands: Commands::new(),
             components: None,
@@ -97,6 +125,7 @@ impl OpenCli {
             external_docs: None,
             platforms: None,
             environment: None,
+            install: None,
             tags: None,
             commands: Commands::new(),
             components: None,
@@ -133,11 +162,664 @@ impl OpenCli {
         self
     }
 
+    /// Sets the install commands, keyed by package manager.
+    pub fn install(mut self, install: Map<String, String>) -> Self {
+        self.install = Some(install);
+        self
+    }
+
+    /// Adds the install command of a package manager (e.g., `brew` and `brew install ocs`).
+    pub fn install_command(
+        mut self,
+        manager: impl Into<String>,
+        command: impl Into<String>,
+    ) -> Self {
+        self.install
+            .get_or_insert_with(Map::new)
+            .insert(manager.into(), command.into());
+        self
+    }
+
     /// Sets the external documentation.
     pub fn external_docs(mut self, external_docs: ExternalDocs) -> Self {
         self.external_docs = Some(external_docs);
         self
     }
+
+    /// Serializes the specification as a pretty-printed JSON document.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|err| Error::Serialization(Box::new(err)))
+    }
+
+    /// Serializes the specification as a JSON value without `null` fields and empty collections.
+    ///
+    /// Fields holding `null` (e.g. a `default` set to [`serde_json::Value::Null`]) are dropped,
+    /// recursively, and so are the fields left holding an empty object or array (e.g.
+    /// `properties: {}`, `parameters: []` or `tags: []`). Named entries are kept even when
+    /// empty, so a command without fields stays in `commands`, and user-provided values
+    /// (defaults, examples, enum values and extensions) are kept as is, `null`s and empty
+    /// collections inside them included.
+    pub fn without_nulls(&self) -> Result<serde_json::Value, Error> {
+        let mut value =
+            serde_json::to_value(self).map_err(|err| Error::Serialization(Box::new(err)))?;
+        strip_nulls(&mut value, true);
+        prune_empty(&mut value);
+        Ok(value)
+    }
+
+    /// Serializes the specification as a JSON value with explicit parameter defaults.
+    ///
+    /// This is the opposite of [`OpenCli::without_nulls`]: inline parameters that omit
+    /// `required` or `x-deprecated` are emitted with `required: false` and
+    /// `x-deprecated: false`, for consumers that do not apply the implicit defaults.
+    pub fn with_explicit_defaults(&self) -> Result<serde_json::Value, Error> {
+        let mut opencli = self.clone();
+        let component_parameters = opencli
+            .components
+            .iter_mut()
+            .flat_map(|components| components.parameters.iter_mut())
+            .flat_map(|parameters| parameters.values_mut());
+        let command_parameters = opencli
+            .commands
+            .values_mut()
+            .flat_map(|command| command.parameters.iter_mut().flatten());
+        for parameter in component_parameters.chain(command_parameters) {
+            if let RefOr::T(parameter) = parameter {
+                parameter.required.get_or_insert(false);
+                parameter.deprecated.get_or_insert(false);
+            }
+        }
+        serde_json::to_value(&opencli).map_err(|err| Error::Serialization(Box::new(err)))
+    }
+
+    /// Deserializes a specification from a JSON document.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|err| Error::Deserialization(Box::new(err)))
+    }
+
+    /// Serializes the specification as a TOML document.
+    ///
+    /// TOML cannot represent every structure the JSON and YAML outputs can, so the document
+    /// is normalized before serialization:
+    ///
+    /// - Schema references (`RefOr`) and extensions are flattened into plain tables, as in JSON.
+    /// - `null` values (e.g. in examples or defaults) have no TOML equivalent and are dropped,
+    ///   including `null` array items.
+    /// - Keys are sorted recursively, regardless of the `preserve_order` features of utocli and
+    ///   `serde_json`, so the output is deterministic but does not follow insertion order.
+    ///   Within a table, plain values are still emitted before sub-tables, as TOML requires.
+    /// - Keys that are not valid bare keys (e.g. command paths like `/validate` or `$ref`)
+    ///   are emitted as quoted keys.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, Error> {
+        let mut value =
+            serde_json::to_value(self).map_err(|err| Error::Serialization(Box::new(err)))?;
+        strip_nulls(&mut value, false);
+        sort_keys(&mut value);
+        toml::to_string(&value).map_err(|err| Error::Serialization(Box::new(err)))
+    }
+
+    /// Projects the schema components into a standalone JSON Schema (draft 2020-12) document.
+    ///
+    /// The schemas are placed under `$defs`, and every `#/components/schemas/{name}` reference
+    /// is rewritten to `#/$defs/{name}`, so the document can be used with generic JSON Schema
+    /// tooling. Validating against a given schema requires a root `$ref` to its definition
+    /// (e.g., `#/$defs/ValidationResult`).
+    ///
+    /// Exclusive bounds are emitted in the 2020-12 numeric form: a `minimum` made exclusive by
+    /// `exclusiveMinimum: true` becomes `exclusiveMinimum: <minimum>` (and likewise for
+    /// maximums), and `false` flags are dropped. The OpenAPI `example` keyword has no 2020-12
+    /// counterpart: it is dropped in favor of `examples` when both are set, and becomes a
+    /// single-value `examples` array otherwise.
+    pub fn to_json_schema(&self) -> Result<serde_json::Value, Error> {
+        let mut schemas = self
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.clone())
+            .unwrap_or_default();
+        schemas.values_mut().for_each(use_json_schema_keywords);
+        let mut defs =
+            serde_json::to_value(schemas).map_err(|err| Error::Serialization(Box::new(err)))?;
+        rewrite_schema_refs(&mut defs);
+
+        Ok(serde_json::json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$defs": defs,
+        }))
+    }
+
+    /// Returns the specification with the component maps (schemas, parameters and responses)
+    /// sorted alphabetically by key, regardless of their registration order.
+    ///
+    /// Commands keep their declaration order. Since `$ref` targets reference components by
+    /// name, references remain valid after sorting.
+    pub fn sorted(mut self) -> Self {
+        if let Some(components) = self.components.as_mut() {
+            components.schemas = components.schemas.take().map(sort_map);
+            components.parameters = components.parameters.take().map(sort_map);
+            components.responses = components.responses.take().map(sort_map);
+        }
+        self
+    }
+
+    /// Returns the parameters effectively available to the command at `command_path`.
+    ///
+    /// These are the command's own parameters followed by the `inherited` scope parameters of
+    /// its ancestor commands, from the nearest ancestor to the root command. Ancestors are found
+    /// by path prefix (e.g., `/remote` for `/remote/add`), and the root command is the one named
+    /// after the first path segment or, in single-root specifications, the only root (e.g.,
+    /// `ocs` for `/validate`). Inherited parameters shadowed by a parameter of the same name
+    /// are skipped, and references to parameter components are resolved.
+    ///
+    /// Returns an empty list if no command is registered under `command_path`.
+    pub fn effective_parameters(&self, command_path: &str) -> Vec<&Parameter> {
+        let Some(command) = self.commands.get_command(command_path) else {
+            return Vec::new();
+        };
+
+        let mut parameters: Vec<&Parameter> = self.command_parameters(command).collect();
+
+        for ancestor in self.ancestor_commands(command_path) {
+            for parameter in self.command_parameters(ancestor) {
+                let inherited = parameter.scope == Some(ParameterScope::Inherited);
+                let shadowed = parameters.iter().any(|p| p.name == parameter.name);
+                if inherited && !shadowed {
+                    parameters.push(parameter);
+                }
+            }
+        }
+
+        parameters
+    }
+
+    /// Returns the ancestor commands of the command path, from the nearest to the root.
+    fn ancestor_commands(&self, command_path: &str) -> Vec<&Command> {
+        // Root commands have no ancestors
+        let Some(path) = command_path.strip_prefix('/') else {
+            return Vec::new();
+        };
+
+        let segments: Vec<&str> = path.split('/').collect();
+        let mut ancestors: Vec<&Command> = (1..segments.len())
+            .rev()
+            .filter_map(|len| {
+                let ancestor_path = format!("/{}", segments[..len].join("/"));
+                self.commands.get_command(&ancestor_path)
+            })
+            .collect();
+
+        let roots: Vec<&str> = self.commands.roots().collect();
+        let root = roots
+            .iter()
+            .find(|root| **root == segments[0])
+            .or(match roots.as_slice() {
+                [root] => Some(root),
+                _ => None,
+            });
+        ancestors.extend(root.and_then(|root| self.commands.get_command(root)));
+
+        ancestors
+    }
+
+    /// Returns the command parameters, resolving references to parameter components.
+    fn command_parameters<'a>(
+        &'a self,
+        command: &'a Command,
+    ) -> impl Iterator<Item = &'a Parameter> {
+        command
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(move |parameter| match parameter {
+                RefOr::T(parameter) => Some(parameter),
+                RefOr::Ref(reference) => {
+                    let name = reference
+                        .ref_path
+                        .strip_prefix("#/components/parameters/")?;
+                    match self.components.as_ref()?.parameters.as_ref()?.get(name)? {
+                        RefOr::T(parameter) => Some(parameter),
+                        RefOr::Ref(_) => None,
+                    }
+                }
+            })
+    }
+
+    /// Inlines the schema component `name` wherever it is referenced and removes the component.
+    ///
+    /// Every `#/components/schemas/{name}` reference in the commands and components is replaced
+    /// by `schema`. This backs the `#[schema(inline)]` container attribute of the `ToSchema`
+    /// derive, for small leaf types that are better documented in place.
+    pub fn inline_schema(mut self, name: &str, schema: Schema) -> Self {
+        let ref_path = format!("#/components/schemas/{name}");
+
+        if let Some(components) = self.components.as_mut() {
+            if let Some(schemas) = components.schemas.as_mut() {
+                schemas.retain(|key, _| key != name);
+                schemas
+                    .values_mut()
+                    .for_each(|ref_or| inline_schema_ref(ref_or, &ref_path, &schema));
+                if schemas.is_empty() {
+                    components.schemas = None;
+                }
+            }
+            for parameter in components
+                .parameters
+                .iter_mut()
+                .flat_map(|p| p.values_mut())
+            {
+                if let RefOr::T(parameter) = parameter {
+                    inline_parameter_schema_ref(parameter, &ref_path, &schema);
+                }
+            }
+            for response in components.responses.iter_mut().flat_map(|r| r.values_mut()) {
+                if let RefOr::T(response) = response {
+                    inline_response_schema_ref(response, &ref_path, &schema);
+                }
+            }
+        }
+
+        for command in self.commands.values_mut() {
+            for parameter in command.parameters.iter_mut().flatten() {
+                if let RefOr::T(parameter) = parameter {
+                    inline_parameter_schema_ref(parameter, &ref_path, &schema);
+                }
+            }
+            for response in command.responses.iter_mut().flat_map(|r| r.values_mut()) {
+                inline_response_schema_ref(response, &ref_path, &schema);
+            }
+        }
+
+        self
+    }
+
+    /// Rewrites every `$ref` path starting with `from` to start with `to` instead.
+    ///
+    /// References to schema, parameter and response components are rewritten in the commands
+    /// and components, e.g. `rewrite_refs("#/components/schemas/", "#/$defs/")` to embed the
+    /// specification in a document keeping its schemas under `$defs`. The components
+    /// themselves are not moved.
+    pub fn rewrite_refs(mut self, from: &str, to: &str) -> Self {
+        if let Some(components) = self.components.as_mut() {
+            for schema in components.schemas.iter_mut().flat_map(|s| s.values_mut()) {
+                rewrite_schema_refs_prefix(schema, from, to);
+            }
+            for parameter in components
+                .parameters
+                .iter_mut()
+                .flat_map(|p| p.values_mut())
+            {
+                rewrite_parameter_refs(parameter, from, to);
+            }
+            for response in components.responses.iter_mut().flat_map(|r| r.values_mut()) {
+                match response {
+                    RefOr::Ref(reference) => rewrite_ref(reference, from, to),
+                    RefOr::T(response) => rewrite_response_refs(response, from, to),
+                }
+            }
+        }
+
+        for command in self.commands.values_mut() {
+            for parameter in command.parameters.iter_mut().flatten() {
+                rewrite_parameter_refs(parameter, from, to);
+            }
+            for response in command.responses.iter_mut().flat_map(|r| r.values_mut()) {
+                rewrite_response_refs(response, from, to);
+            }
+        }
+
+        self
+    }
+
+    /// Reshapes the commands map into a tree, following the `/`-separated command paths.
+    ///
+    /// With a single root command, the root anchors the tree and subcommand paths are nested
+    /// under it, whether or not they start with the root name (e.g., `/validate` and
+    /// `/ocs/validate` are both children of `ocs`). With several roots (or none), the tree is
+    /// anchored by a node named after the [`Info::title`] without a command, holding the roots
+    /// and their subcommands. Intermediate paths without a command of their own become nodes
+    /// without a command, regardless of the order the commands were inserted in. Children are
+    /// sorted by name at every level.
+    pub fn command_tree(&self) -> CommandNode<'_> {
+        let roots: Vec<&str> = self.commands.roots().collect();
+        let single_root = match roots.as_slice() {
+            [root] => Some(*root),
+            _ => None,
+        };
+
+        let mut tree = CommandNode::new(self.info.title.clone());
+        for (path, command) in &self.commands {
+            let mut segments: Vec<&str> = match path.strip_prefix('/') {
+                Some(path) => path.split('/').filter(|s| !s.is_empty()).collect(),
+                None => vec![path.as_str()],
+            };
+            if let Some(root) = single_root
+                && segments.first() != Some(&root)
+            {
+                segments.insert(0, root);
+            }
+            tree.insert(&segments, command);
+        }
+
+        match single_root {
+            Some(_) => tree.children.remove(0),
+            None => tree,
+        }
+    }
+
+    /// Aggregates the exit codes declared across all commands.
+    ///
+    /// Returns, for each exit code, the list of `(command, description)` pairs of the commands
+    /// declaring a response for it. Responses without a description map to an empty string.
+    pub fn exit_code_catalog(&self) -> BTreeMap<String, Vec<(String, String)>> {
+        let mut catalog: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+        for (command_name, command) in &self.commands {
+            let Some(responses) = &command.responses else {
+                continue;
+            };
+            for (code, response) in responses {
+                catalog.entry(code.clone()).or_default().push((
+                    command_name.clone(),
+                    response.description.clone().unwrap_or_default(),
+                ));
+            }
+        }
+        catalog
+    }
+}
+
+/// Replaces the schema references to `ref_path` with `schema`, recursively.
+fn inline_schema_ref(ref_or: &mut RefOr<Schema>, ref_path: &str, schema: &Schema) {
+    match ref_or {
+        RefOr::Ref(reference) if reference.ref_path == ref_path => {
+            *ref_or = RefOr::T(schema.clone());
+        }
+        RefOr::Ref(_) => {}
+        RefOr::T(Schema::OneOf(OneOf { items, .. }))
+        | RefOr::T(Schema::AllOf(AllOf { items, .. }))
+        | RefOr::T(Schema::AnyOf(AnyOf { items, .. })) => items
+            .iter_mut()
+            .for_each(|item| inline_schema_ref(item, ref_path, schema)),
+        RefOr::T(Schema::Object(object)) => {
+            for property in object.properties.iter_mut().flat_map(|p| p.values_mut()) {
+                inline_schema_ref(property, ref_path, schema);
+            }
+            if let Some(AdditionalProperties::Schema(value)) = object.additional_properties.as_mut()
+            {
+                inline_schema_ref(value, ref_path, schema);
+            }
+        }
+        RefOr::T(Schema::Array(array)) => {
+            if let Some(items) = array.items.as_mut() {
+                inline_schema_ref(items, ref_path, schema);
+            }
+        }
+    }
+}
+
+/// Replaces the references to `ref_path` in the parameter schema with `schema`.
+fn inline_parameter_schema_ref(parameter: &mut Parameter, ref_path: &str, schema: &Schema) {
+    if let Some(ref_or) = parameter.schema.as_mut() {
+        inline_schema_ref(ref_or, ref_path, schema);
+    }
+}
+
+/// Replaces the references to `ref_path` in the response content schemas with `schema`.
+fn inline_response_schema_ref(response: &mut Response, ref_path: &str, schema: &Schema) {
+    for media_type in response.content.iter_mut().flat_map(|c| c.values_mut()) {
+        if let Some(ref_or) = media_type.schema.as_mut() {
+            inline_schema_ref(ref_or, ref_path, schema);
+        }
+    }
+}
+
+/// Replaces the `from` prefix of the reference path with `to`.
+fn rewrite_ref(reference: &mut Ref, from: &str, to: &str) {
+    if let Some(rest) = reference.ref_path.strip_prefix(from) {
+        reference.ref_path = format!("{to}{rest}");
+    }
+}
+
+/// Rewrites the `from` prefix of the schema references to `to`, recursively.
+///
+/// The reference paths of `oneOf` discriminator mappings are rewritten as well.
+fn rewrite_schema_refs_prefix(ref_or: &mut RefOr<Schema>, from: &str, to: &str) {
+    match ref_or {
+        RefOr::Ref(reference) => rewrite_ref(reference, from, to),
+        RefOr::T(Schema::OneOf(OneOf {
+            items,
+            discriminator,
+            ..
+        })) => {
+            let mapping = discriminator
+                .iter_mut()
+                .flat_map(|discriminator| discriminator.mapping.iter_mut())
+                .flat_map(|mapping| mapping.values_mut());
+            for ref_path in mapping {
+                if let Some(rest) = ref_path.strip_prefix(from) {
+                    *ref_path = format!("{to}{rest}");
+                }
+            }
+            items
+                .iter_mut()
+                .for_each(|item| rewrite_schema_refs_prefix(item, from, to));
+        }
+        RefOr::T(Schema::AllOf(AllOf { items, .. }))
+        | RefOr::T(Schema::AnyOf(AnyOf { items, .. })) => items
+            .iter_mut()
+            .for_each(|item| rewrite_schema_refs_prefix(item, from, to)),
+        RefOr::T(Schema::Object(object)) => {
+            for property in object.properties.iter_mut().flat_map(|p| p.values_mut()) {
+                rewrite_schema_refs_prefix(property, from, to);
+            }
+            if let Some(AdditionalProperties::Schema(value)) = object.additional_properties.as_mut()
+            {
+                rewrite_schema_refs_prefix(value, from, to);
+            }
+        }
+        RefOr::T(Schema::Array(array)) => {
+            if let Some(items) = array.items.as_mut() {
+                rewrite_schema_refs_prefix(items, from, to);
+            }
+        }
+    }
+}
+
+/// Rewrites the `from` prefix of the parameter reference or its schema references to `to`.
+fn rewrite_parameter_refs(parameter: &mut RefOr<Parameter>, from: &str, to: &str) {
+    match parameter {
+        RefOr::Ref(reference) => rewrite_ref(reference, from, to),
+        RefOr::T(parameter) => {
+            if let Some(ref_or) = parameter.schema.as_mut() {
+                rewrite_schema_refs_prefix(ref_or, from, to);
+            }
+        }
+    }
+}
+
+/// Rewrites the `from` prefix of the response content schema references to `to`.
+fn rewrite_response_refs(response: &mut Response, from: &str, to: &str) {
+    for media_type in response.content.iter_mut().flat_map(|c| c.values_mut()) {
+        if let Some(ref_or) = media_type.schema.as_mut() {
+            rewrite_schema_refs_prefix(ref_or, from, to);
+        }
+    }
+}
+
+/// Rebuilds the map with its entries sorted by key.
+fn sort_map<V>(map: Map<String, V>) -> Map<String, V> {
+    let mut entries: Vec<_> = map.into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Map::from_iter(entries)
+}
+
+/// Converts the exclusive bound flags and the `example` of the schema to their 2020-12 form,
+/// recursively.
+fn use_json_schema_keywords(ref_or: &mut RefOr<Schema>) {
+    let numeric = |flag: Option<ExclusiveBound>, bound: &mut Option<f64>| match flag {
+        Some(ExclusiveBound::Flag(true)) => bound.take().map(ExclusiveBound::Value),
+        Some(ExclusiveBound::Flag(false)) => None,
+        other => other,
+    };
+    match ref_or {
+        RefOr::Ref(_) => {}
+        RefOr::T(Schema::OneOf(OneOf { items, .. }))
+        | RefOr::T(Schema::AllOf(AllOf { items, .. }))
+        | RefOr::T(Schema::AnyOf(AnyOf { items, .. })) => {
+            items.iter_mut().for_each(use_json_schema_keywords)
+        }
+        RefOr::T(Schema::Object(object)) => {
+            object.exclusive_minimum = numeric(object.exclusive_minimum, &mut object.minimum);
+            object.exclusive_maximum = numeric(object.exclusive_maximum, &mut object.maximum);
+            if let Some(example) = object.example.take() {
+                object.examples.get_or_insert_with(|| vec![example]);
+            }
+            for property in object.properties.iter_mut().flat_map(|p| p.values_mut()) {
+                use_json_schema_keywords(property);
+            }
+            if let Some(AdditionalProperties::Schema(value)) = object.additional_properties.as_mut()
+            {
+                use_json_schema_keywords(value);
+            }
+        }
+        RefOr::T(Schema::Array(array)) => {
+            if let Some(items) = array.items.as_mut() {
+                use_json_schema_keywords(items);
+            }
+        }
+    }
+}
+
+/// Rewrites `$ref` paths (and discriminator mapping paths) to schema components as `$defs`
+/// paths, recursively.
+fn rewrite_schema_refs(value: &mut serde_json::Value) {
+    let to_defs = |ref_path: &mut String| {
+        if let Some(name) = ref_path.strip_prefix("#/components/schemas/") {
+            *ref_path = format!("#/$defs/{name}");
+        }
+    };
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "$ref"
+                    && let serde_json::Value::String(ref_path) = value
+                {
+                    to_defs(ref_path);
+                } else if key == "discriminator"
+                    && let Some(mapping) = value
+                        .get_mut("mapping")
+                        .and_then(serde_json::Value::as_object_mut)
+                {
+                    for ref_path in mapping.values_mut() {
+                        if let serde_json::Value::String(ref_path) = ref_path {
+                            to_defs(ref_path);
+                        }
+                    }
+                } else {
+                    rewrite_schema_refs(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rewrite_schema_refs),
+        _ => {}
+    }
+}
+
+/// Fields holding user-provided values rather than specification structure.
+const PAYLOAD_FIELDS: &[&str] = &["default", "example", "examples", "enum"];
+
+/// Fields holding maps keyed by name (e.g. command paths or status codes) rather than by field.
+const NAMED_MAP_FIELDS: &[&str] = &[
+    "commands",
+    "schemas",
+    "parameters",
+    "responses",
+    "properties",
+    "content",
+];
+
+/// Removes `null` values from objects and arrays, recursively.
+///
+/// With `keep_payloads`, user-provided values (see [`PAYLOAD_FIELDS`]) and extensions are
+/// kept as is: a `null` field holding them is dropped, but their contents are not visited.
+fn strip_nulls(value: &mut serde_json::Value, keep_payloads: bool) {
+    strip_nulls_in(value, keep_payloads, false);
+}
+
+fn strip_nulls_in(value: &mut serde_json::Value, keep_payloads: bool, named_map: bool) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.retain(|_, v| !v.is_null());
+            for (key, value) in map.iter_mut() {
+                let is_payload =
+                    !named_map && (PAYLOAD_FIELDS.contains(&key.as_str()) || key.starts_with("x-"));
+                if keep_payloads && is_payload {
+                    continue;
+                }
+                let is_named_map = !named_map && NAMED_MAP_FIELDS.contains(&key.as_str());
+                strip_nulls_in(value, keep_payloads, is_named_map);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            items.retain(|v| !v.is_null());
+            items
+                .iter_mut()
+                .for_each(|item| strip_nulls_in(item, keep_payloads, false));
+        }
+        _ => {}
+    }
+}
+
+/// Removes the fields holding an empty object or array, recursively.
+///
+/// Fields emptied by the removal of their own empty fields are removed as well. The entries of
+/// named maps (see [`NAMED_MAP_FIELDS`]) are kept even when empty, and user-provided values
+/// (see [`PAYLOAD_FIELDS`]) and extensions are kept as is.
+fn prune_empty(value: &mut serde_json::Value) {
+    prune_empty_in(value, false);
+}
+
+fn prune_empty_in(value: &mut serde_json::Value, named_map: bool) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let is_payload =
+                |key: &str| !named_map && (PAYLOAD_FIELDS.contains(&key) || key.starts_with("x-"));
+            for (key, value) in map.iter_mut() {
+                if is_payload(key) {
+                    continue;
+                }
+                let is_named_map = !named_map && NAMED_MAP_FIELDS.contains(&key.as_str());
+                prune_empty_in(value, is_named_map);
+            }
+            if !named_map {
+                map.retain(|key, value| {
+                    is_payload(key)
+                        || !matches!(value, serde_json::Value::Object(map) if map.is_empty())
+                            && !matches!(value, serde_json::Value::Array(items) if items.is_empty())
+                });
+            }
+        }
+        serde_json::Value::Array(items) => {
+            items
+                .iter_mut()
+                .for_each(|item| prune_empty_in(item, false));
+        }
+        _ => {}
+    }
+}
+
+/// Sorts the keys of every object, recursively.
+#[cfg(feature = "toml")]
+fn sort_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = std::mem::take(map).into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entries.iter_mut().for_each(|(_, value)| sort_keys(value));
+            *map = entries.into_iter().collect();
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
 }
 
 impl OpenCliBuilder {
@@ -181,8 +863,687 @@ impl OpenCliBuilder {
         crate::builder_macros::set_value!(self environment environment.into())
     }
 
+    /// Sets the install commands, keyed by package manager.
+    pub fn install(mut self, install: impl Into<Option<Map<String, String>>>) -> Self {
+        crate::builder_macros::set_value!(self install install.into())
+    }
+
     /// Sets the external documentation.
     pub fn external_docs(mut self, external_docs: impl Into<Option<ExternalDocs>>) -> Self {
         crate::builder_macros::set_value!(self external_docs external_docs.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Collects the `$ref` paths of the JSON value, recursively.
+    fn collect_refs(value: &serde_json::Value, refs: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    match value {
+                        serde_json::Value::String(ref_path) if key == "$ref" => {
+                            refs.push(ref_path.clone())
+                        }
+                        _ => collect_refs(value, refs),
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn rewrite_refs_with_defs_base_updates_every_schema_ref() {
+        //* Given
+        let object_with = |name: &str, schema: RefOr<Schema>| {
+            RefOr::T(Schema::Object(Box::new(
+                Object::new().properties(Map::from_iter([(name.to_string(), schema)])),
+            )))
+        };
+        let components = Components::new().schemas(Map::from_iter([
+            (
+                "Report".to_string(),
+                RefOr::new_ref("#/components/schemas/Error"),
+            ),
+            (
+                "Error".to_string(),
+                object_with(
+                    "causes",
+                    RefOr::T(Schema::Array(
+                        Array::new().items(RefOr::new_ref("#/components/schemas/Cause")),
+                    )),
+                ),
+            ),
+            (
+                "Cause".to_string(),
+                object_with("message", RefOr::T(Schema::Object(Box::new(Object::new())))),
+            ),
+        ]));
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new()
+                .parameters(vec![
+                    Parameter::new_option("format")
+                        .schema(RefOr::new_ref("#/components/schemas/Format")),
+                ])
+                .response(0, Response::json_ref("Validation report", "Report")),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"))
+            .commands(commands)
+            .components(components);
+
+        //* When
+        let opencli = opencli.rewrite_refs("#/components/schemas/", "#/$defs/");
+
+        //* Then
+        let mut refs = Vec::new();
+        collect_refs(
+            &serde_json::to_value(&opencli).expect("should serialize"),
+            &mut refs,
+        );
+        refs.sort();
+        assert_eq!(
+            refs,
+            vec![
+                "#/$defs/Cause".to_string(),
+                "#/$defs/Error".to_string(),
+                "#/$defs/Format".to_string(),
+                "#/$defs/Report".to_string(),
+            ],
+            "every schema reference should use the new base"
+        );
+    }
+
+    #[test]
+    fn rewrite_refs_with_discriminator_mapping_updates_mapping_paths() {
+        //* Given
+        let shape = OneOf::new()
+            .item(RefOr::new_ref("#/components/schemas/Circle"))
+            .item(RefOr::new_ref("#/components/schemas/Square"))
+            .discriminator(
+                Discriminator::new("kind")
+                    .mapping("circle", "#/components/schemas/Circle")
+                    .mapping("square", "#/components/schemas/Square"),
+            );
+        let components = Components::new().schemas(Map::from_iter([(
+            "Shape".to_string(),
+            RefOr::T(Schema::OneOf(shape)),
+        )]));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).components(components);
+
+        //* When
+        let rewritten = opencli
+            .clone()
+            .rewrite_refs("#/components/schemas/", "#/$defs/");
+        let json_schema = opencli.to_json_schema().expect("should project schemas");
+
+        //* Then
+        let value = serde_json::to_value(&rewritten).expect("should serialize");
+        assert_eq!(
+            value.pointer("/components/schemas/Shape/discriminator/mapping"),
+            Some(&serde_json::json!({ "circle": "#/$defs/Circle", "square": "#/$defs/Square" })),
+            "discriminator mapping paths should use the new base"
+        );
+        assert_eq!(
+            json_schema.pointer("/$defs/Shape/discriminator/mapping"),
+            Some(&serde_json::json!({ "circle": "#/$defs/Circle", "square": "#/$defs/Square" })),
+            "JSON Schema projection should rewrite discriminator mapping paths"
+        );
+    }
+
+    #[test]
+    fn exit_code_catalog_groups_exit_code_across_commands() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "build".to_string(),
+            Command::new().responses(Map::from_iter([
+                (
+                    "0".to_string(),
+                    Response::new().description("Build succeeded"),
+                ),
+                ("1".to_string(), Response::new().description("Build failed")),
+            ])),
+        );
+        commands.insert(
+            "test".to_string(),
+            Command::new().responses(Map::from_iter([(
+                "1".to_string(),
+                Response::new().description("Tests failed"),
+            )])),
+        );
+        commands.insert("version".to_string(), Command::new());
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let catalog = opencli.exit_code_catalog();
+
+        //* Then
+        assert_eq!(
+            catalog.len(),
+            2,
+            "catalog should contain exit codes 0 and 1"
+        );
+        assert_eq!(
+            catalog.get("0"),
+            Some(&vec![("build".to_string(), "Build succeeded".to_string())]),
+            "exit code 0 should only be declared by the build command"
+        );
+        let mut code_1 = catalog
+            .get("1")
+            .cloned()
+            .expect("exit code 1 should be cataloged");
+        code_1.sort();
+        assert_eq!(
+            code_1,
+            vec![
+                ("build".to_string(), "Build failed".to_string()),
+                ("test".to_string(), "Tests failed".to_string()),
+            ],
+            "exit code 1 should group both commands with their descriptions"
+        );
+    }
+
+    #[test]
+    fn effective_parameters_with_inherited_root_parameter_includes_it_in_subcommand() {
+        //* Given
+        let commands = Commands::new()
+            .command(
+                "ocs",
+                Command::new().parameters([
+                    Parameter::new_option("config").scope(ParameterScope::Inherited),
+                    Parameter::new_flag("version").scope(ParameterScope::Local),
+                ]),
+            )
+            .command(
+                "/validate",
+                Command::new().parameters([Parameter::new_flag("strict")]),
+            );
+        let opencli = OpenCli::new(Info::new("ocs", "1.0.0")).commands(commands);
+
+        //* When
+        let parameters = opencli.effective_parameters("/validate");
+
+        //* Then
+        let names: Vec<&str> = parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["strict", "config"],
+            "should include the own parameters and the inherited --config only"
+        );
+    }
+
+    #[test]
+    fn effective_parameters_with_nested_path_collects_ancestors_nearest_first() {
+        //* Given
+        let components = Components::new().parameters(Map::from_iter([(
+            "Verbose".to_string(),
+            RefOr::T(Parameter::new_flag("verbose").scope(ParameterScope::Inherited)),
+        )]));
+        let commands = Commands::new()
+            .command(
+                "git",
+                Command::new().parameters([RefOr::new_ref("#/components/parameters/Verbose")]),
+            )
+            .command(
+                "/remote",
+                Command::new().parameters([
+                    Parameter::new_option("remote").scope(ParameterScope::Inherited),
+                    Parameter::new_flag("verbose").scope(ParameterScope::Inherited),
+                ]),
+            )
+            .command(
+                "/remote/add",
+                Command::new().parameters([Parameter::new_argument("url", 1)]),
+            );
+        let opencli = OpenCli::new(Info::new("git", "1.0.0"))
+            .components(components)
+            .commands(commands);
+
+        //* When
+        let parameters = opencli.effective_parameters("/remote/add");
+
+        //* Then
+        let names: Vec<&str> = parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["url", "remote", "verbose"],
+            "should inherit from /remote first and skip the shadowed root --verbose"
+        );
+        assert!(
+            opencli.effective_parameters("/missing").is_empty(),
+            "unknown command should have no effective parameters"
+        );
+    }
+
+    #[test]
+    fn sorted_with_out_of_order_schemas_sorts_components_by_name() {
+        //* Given
+        let schemas = Map::from_iter([
+            (
+                "Zebra".to_string(),
+                RefOr::T(Schema::Object(Box::new(Object::new()))),
+            ),
+            (
+                "Apple".to_string(),
+                RefOr::new_ref("#/components/schemas/Mango"),
+            ),
+            (
+                "Mango".to_string(),
+                RefOr::T(Schema::Object(Box::new(Object::new()))),
+            ),
+        ]);
+        let opencli =
+            OpenCli::new(Info::new("app", "1.0.0")).components(Components::new().schemas(schemas));
+
+        //* When
+        let sorted = opencli.sorted();
+
+        //* Then
+        let schemas = sorted
+            .components
+            .and_then(|components| components.schemas)
+            .expect("should keep the schemas");
+        let names: Vec<&str> = schemas.keys().map(String::as_str).collect();
+        assert_eq!(
+            names,
+            vec!["Apple", "Mango", "Zebra"],
+            "schemas should be sorted alphabetically"
+        );
+        assert_eq!(
+            schemas.get("Apple"),
+            Some(&RefOr::new_ref("#/components/schemas/Mango")),
+            "references should be preserved"
+        );
+    }
+
+    #[test]
+    fn exit_code_catalog_with_no_responses_returns_empty_catalog() {
+        //* Given
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"));
+
+        //* When
+        let catalog = opencli.exit_code_catalog();
+
+        //* Then
+        assert!(
+            catalog.is_empty(),
+            "catalog should be empty without responses"
+        );
+    }
+
+    #[test]
+    fn serialize_with_empty_collections_omits_them() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "build".to_string(),
+            Command::new()
+                .tags(Vec::new())
+                .parameters(Vec::<Parameter>::new())
+                .response(0, Response::new().description("Built").content(Map::new())),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"))
+            .tags(Vec::new())
+            .components(Components::new().schemas(Map::new()))
+            .commands(commands);
+
+        //* When
+        let json = serde_json::to_value(&opencli).expect("specification should serialize");
+
+        //* Then
+        assert!(
+            json.get("tags").is_none(),
+            "empty root tags should be omitted"
+        );
+        assert!(
+            json.get("components").is_none(),
+            "components without any component should be omitted"
+        );
+        let command = &json["commands"]["build"];
+        assert!(
+            command.get("tags").is_none(),
+            "empty command tags should be omitted"
+        );
+        assert!(
+            command.get("parameters").is_none(),
+            "empty parameters should be omitted"
+        );
+        assert!(
+            command["responses"]["0"].get("content").is_none(),
+            "empty response content should be omitted"
+        );
+    }
+
+    #[test]
+    fn from_json_with_malformed_document_returns_deserialization_error() {
+        //* Given
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"));
+        let json = opencli.to_json().expect("specification should serialize");
+
+        //* When
+        let parsed = OpenCli::from_json(&json);
+        let malformed = OpenCli::from_json(r#"{"opencli": "1.0.0"}"#);
+
+        //* Then
+        assert!(
+            parsed.is_ok_and(|parsed| parsed == opencli),
+            "specification should round-trip through JSON"
+        );
+        assert!(
+            matches!(malformed, Err(Error::Deserialization(_))),
+            "a document without info should fail to deserialize, got {malformed:?}"
+        );
+    }
+
+    #[test]
+    fn without_nulls_with_null_and_empty_fields_drops_them() {
+        //* Given
+        let schema = Object::new()
+            .schema_type(SchemaType::String)
+            .default_value(serde_json::Value::Null)
+            .properties(Map::new());
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new()
+                .parameters(vec![
+                    Parameter::new_option("format")
+                        .schema(RefOr::T(Schema::Object(Box::new(schema)))),
+                ])
+                .response(
+                    0,
+                    Response::new()
+                        .description("Valid")
+                        .content(Map::from_iter([(
+                            "text/plain".to_string(),
+                            MediaType::new().example(serde_json::Value::Null),
+                        )])),
+                ),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let pruned = opencli.without_nulls().expect("should serialize");
+
+        //* Then
+        let unpruned = serde_json::to_value(&opencli).expect("should serialize");
+        let format = "/commands/validate/parameters/0/schema";
+        assert_eq!(
+            unpruned.pointer(format),
+            Some(&serde_json::json!({ "type": "string", "default": null, "properties": {} })),
+            "unpruned output should keep the null and empty fields"
+        );
+        assert_eq!(
+            pruned.pointer(format),
+            Some(&serde_json::json!({ "type": "string" })),
+            "null and empty fields should be dropped"
+        );
+        assert_eq!(
+            pruned.pointer("/commands/validate/responses/0"),
+            Some(&serde_json::json!({ "description": "Valid", "content": { "text/plain": {} } })),
+            "null fields of nested objects should be dropped"
+        );
+        assert_eq!(
+            pruned.pointer("/info"),
+            unpruned.pointer("/info"),
+            "fields with values should be kept"
+        );
+    }
+
+    #[test]
+    fn without_nulls_with_empty_command_and_empty_payloads_keeps_them() {
+        //* Given
+        let schema = Object::new()
+            .schema_type(SchemaType::Array)
+            .default_value(serde_json::json!([]))
+            .examples(vec![])
+            .required(vec![])
+            .example(serde_json::json!({ "name": null }));
+        let mut commands = Commands::new();
+        commands.insert("empty".to_string(), Command::new());
+        commands.insert(
+            "validate".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_option("files").schema(RefOr::T(Schema::Object(Box::new(schema)))),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let pruned = opencli.without_nulls().expect("should serialize");
+
+        //* Then
+        assert_eq!(
+            pruned.pointer("/commands/empty"),
+            Some(&serde_json::json!({})),
+            "a command without fields should be kept"
+        );
+        let format = "/commands/validate/parameters/0/schema";
+        assert_eq!(
+            pruned.pointer(&format!("{format}/default")),
+            Some(&serde_json::json!([])),
+            "an empty default should be kept"
+        );
+        assert_eq!(
+            pruned.pointer(&format!("{format}/examples")),
+            Some(&serde_json::json!([])),
+            "empty examples should be kept"
+        );
+        assert_eq!(
+            pruned.pointer(&format!("{format}/required")),
+            None,
+            "an empty required list should be dropped"
+        );
+        assert_eq!(
+            pruned.pointer(&format!("{format}/example")),
+            Some(&serde_json::json!({ "name": null })),
+            "nulls inside an example should be kept"
+        );
+    }
+
+    #[test]
+    fn with_explicit_defaults_with_optional_parameter_emits_required_and_deprecated_false() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_option("format"),
+                Parameter::new_argument("file", 1).deprecated(true),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let default = serde_json::to_value(&opencli).expect("should serialize");
+        let explicit = opencli.with_explicit_defaults().expect("should serialize");
+
+        //* Then
+        assert_eq!(
+            default.pointer("/commands/validate/parameters/0"),
+            Some(&serde_json::json!({ "name": "format", "in": "option" })),
+            "default output should omit required and deprecated"
+        );
+        assert_eq!(
+            explicit.pointer("/commands/validate/parameters/0"),
+            Some(&serde_json::json!({
+                "name": "format",
+                "in": "option",
+                "required": false,
+                "x-deprecated": false
+            })),
+            "explicit output should emit required and deprecated as false"
+        );
+        assert_eq!(
+            explicit.pointer("/commands/validate/parameters/1/required"),
+            Some(&serde_json::json!(true)),
+            "explicit values should be kept"
+        );
+        assert_eq!(
+            explicit.pointer("/commands/validate/parameters/1/x-deprecated"),
+            Some(&serde_json::json!(true)),
+            "explicit values should be kept"
+        );
+    }
+
+    #[test]
+    fn command_tree_with_out_of_order_paths_creates_missing_intermediate_nodes() {
+        //* Given
+        let commands = Commands::new()
+            .command("/remote/add", Command::new().summary("Add a remote"))
+            .command("git", Command::new().summary("The git binary"))
+            .command("/git/status", Command::new().summary("Show the status"));
+        let opencli = OpenCli::new(Info::new("git", "1.0.0")).commands(commands);
+
+        //* When
+        let tree = opencli.command_tree();
+
+        //* Then
+        assert_eq!(tree.name, "git", "the root command should anchor the tree");
+        let remote = tree.child("remote").expect("should have the remote node");
+        assert_eq!(
+            remote.command, None,
+            "the missing intermediate node should have no command"
+        );
+        let add = remote
+            .child("add")
+            .expect("should have the remote/add node");
+        assert_eq!(
+            add.command.and_then(|command| command.summary.as_deref()),
+            Some("Add a remote"),
+            "the leaf node should hold its command"
+        );
+        assert!(
+            tree.child("status")
+                .is_some_and(|status| status.command.is_some()),
+            "paths prefixed with the root name should be nested under the root"
+        );
+    }
+
+    #[test]
+    fn command_tree_with_multiple_roots_anchors_roots_under_title() {
+        //* Given
+        let commands = Commands::new()
+            .command("foo", Command::new())
+            .command("/foo/x", Command::new())
+            .command("bar", Command::new())
+            .command("/bar/y", Command::new());
+        let opencli = OpenCli::new(Info::new("tools", "1.0.0")).commands(commands);
+
+        //* When
+        let tree = opencli.command_tree();
+
+        //* Then
+        assert_eq!(
+            tree.name, "tools",
+            "the tree should be anchored by the title"
+        );
+        assert_eq!(tree.command, None, "the anchor should have no command");
+        let roots = tree
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            roots,
+            ["bar", "foo"],
+            "each root should be a child of the anchor, sorted by name"
+        );
+        assert!(
+            tree.child("foo").and_then(|foo| foo.child("x")).is_some(),
+            "subcommands should be nested under their root"
+        );
+    }
+
+    #[test]
+    fn to_json_schema_with_exclusive_flags_emits_numeric_bounds() {
+        //* Given
+        let ratio = Object::new()
+            .schema_type(SchemaType::Number)
+            .minimum(0.0)
+            .exclusive_minimum(true)
+            .maximum(1.0)
+            .exclusive_maximum(false);
+        let components = Components::new().schemas(Map::from_iter([(
+            "Ratio".to_string(),
+            RefOr::T(Schema::Object(Box::new(ratio))),
+        )]));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).components(components);
+
+        //* When
+        let json_schema = opencli.to_json_schema().expect("should project schemas");
+
+        //* Then
+        assert_eq!(
+            json_schema.pointer("/$defs/Ratio"),
+            Some(&serde_json::json!({
+                "type": "number",
+                "maximum": 1.0,
+                "exclusiveMinimum": 0.0
+            })),
+            "exclusive flags should become 2020-12 numeric bounds"
+        );
+    }
+
+    #[test]
+    fn to_json_schema_with_example_uses_examples_keyword() {
+        //* Given
+        let name = Object::new()
+            .schema_type(SchemaType::String)
+            .example(serde_json::json!("rex"));
+        let age = Object::new()
+            .schema_type(SchemaType::Integer)
+            .example(serde_json::json!(1))
+            .examples(vec![serde_json::json!(3), serde_json::json!(7)]);
+        let components = Components::new().schemas(Map::from_iter([
+            ("Name".to_string(), RefOr::T(Schema::Object(Box::new(name)))),
+            ("Age".to_string(), RefOr::T(Schema::Object(Box::new(age)))),
+        ]));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).components(components);
+
+        //* When
+        let json_schema = opencli.to_json_schema().expect("should project schemas");
+
+        //* Then
+        assert_eq!(
+            json_schema.pointer("/$defs/Name"),
+            Some(&serde_json::json!({ "type": "string", "examples": ["rex"] })),
+            "a lone example should become a single-value examples array"
+        );
+        assert_eq!(
+            json_schema.pointer("/$defs/Age"),
+            Some(&serde_json::json!({ "type": "integer", "examples": [3, 7] })),
+            "examples should take precedence over the example"
+        );
+    }
+
+    #[test]
+    fn install_commands_round_trip_through_json() {
+        //* Given
+        let opencli = OpenCli::new(Info::new("ocs", "1.0.0"))
+            .install_command("brew", "brew install ocs")
+            .install_command("cargo", "cargo install ocs");
+
+        //* When
+        let json = opencli.to_json().expect("should serialize");
+        let parsed = OpenCli::from_json(&json).expect("should deserialize");
+
+        //* Then
+        let value: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
+        assert_eq!(
+            value["x-install"],
+            serde_json::json!({
+                "brew": "brew install ocs",
+                "cargo": "cargo install ocs"
+            }),
+            "install commands should be serialized as x-install"
+        );
+        assert_eq!(parsed, opencli, "install commands should round-trip");
+    }
+}
@@ -1,6 +1,9 @@
 //! Command entity for CLI commands.
 
-use super::{Parameter, Response, extensions::Extensions, map::Map};
+use super::{
+    ExitCode, ExternalDocs, Parameter, ParameterIn, Response, extensions::Extensions, map::Map,
+    schema::RefOr,
+};
 
 /// Represents a CLI command with its parameters and responses.
 ///
@@ -20,21 +23,45 @@ pub struct Command {
     pub operation_id: Option<String>,
 
     /// Alternative names for the command.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
     pub aliases: Option<Vec<String>>,
 
     /// Tags for organizing commands into groups.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
     pub tags: Option<Vec<String>>,
 
     /// Parameters (arguments, flags, options) for the command.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<Vec<Parameter>>,
+    ///
+    /// Parameters can be declared inline or reference a shared parameter component
+    /// (e.g., `#/components/parameters/ConfigFile`). Note that the OpenCLI JSON schema
+    /// only describes inline parameters, so referenced parameters are an extension to it.
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
+    pub parameters: Option<Vec<RefOr<Parameter>>>,
 
-    /// Responses keyed by exit code (e.g., "0", "1", "2").
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Responses keyed by exit code (e.g., "0", "1", "2"), exit code range (e.g., "1-125")
+    /// or "default". See [`ExitCode`].
+    ///
+    /// Responses are serialized in exit code order (`"2"` before `"10"`), whatever the map order.
+    #[serde(
+        skip_serializing_if = "crate::opencli::serialize::is_none_or_empty",
+        serialize_with = "crate::opencli::serialize::responses_by_exit_code"
+    )]
     pub responses: Option<Map<String, Response>>,
 
+    /// External documentation for the command.
+    ///
+    /// The OpenCLI specification only defines `externalDocs` at the root, so it is serialized
+    /// as the `x-externalDocs` extension.
+    #[serde(rename = "x-externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocs>,
+
+    /// Example invocations of the command.
+    #[serde(
+        rename = "x-examples",
+        skip_serializing_if = "crate::opencli::serialize::is_none_or_empty"
+    )]
+    pub examples: Option<Vec<CommandExample>>,
+
     /// Extension properties.
     #[serde(skip_serializing_if = "Option::is_none", flatten)]
     pub extensions: Option<Extensions>,
@@ -51,6 +78,8 @@ impl Command {
             tags: None,
             parameters: None,
             responses: None,
+            external_docs: None,
+            examples: None,
             extensions: None,
         }
     }
@@ -85,9 +114,21 @@ impl Command {
         self
     }
 
+    /// Adds a tag to the command.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.get_or_insert_with(Vec::new).push(tag.into());
+        self
+    }
+
     /// Sets the parameters for the command.
-    pub fn parameters(mut self, parameters: Vec<Parameter>) -> Self {
-        self.parameters = Some(parameters);
+    ///
+    /// Accepts inline [`Parameter`]s as well as [`RefOr`] references to parameter components.
+    pub fn parameters<I, P>(mut self, parameters: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<RefOr<Parameter>>,
+    {
+        self.parameters = Some(parameters.into_iter().map(Into::into).collect());
         self
     }
 
@@ -97,11 +138,105 @@ impl Command {
         self
     }
 
+    /// Adds a response for the given exit code, exit code range or default response.
+    pub fn response(mut self, code: impl Into<ExitCode>, response: Response) -> Self {
+        self.responses
+            .get_or_insert_with(Map::new)
+            .insert(code.into().to_string(), response);
+        self
+    }
+
+    /// Sets the external documentation for the command.
+    pub fn external_docs(mut self, external_docs: ExternalDocs) -> Self {
+        self.external_docs = Some(external_docs);
+        self
+    }
+
+    /// Adds an example invocation of the command.
+    pub fn example(mut self, example: CommandExample) -> Self {
+        self.examples.get_or_insert_with(Vec::new).push(example);
+        self
+    }
+
     /// Sets the extensions for the command.
     pub fn extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = Some(extensions);
         self
     }
+
+    /// Reconciles `required` with the arity minimum of every inline parameter.
+    ///
+    /// See [`Parameter::normalize`]. References to parameter components are left untouched.
+    pub fn normalize(mut self) -> Self {
+        if let Some(parameters) = self.parameters.take() {
+            self.parameters = Some(
+                parameters
+                    .into_iter()
+                    .map(|parameter| match parameter {
+                        RefOr::T(parameter) => RefOr::T(parameter.normalize()),
+                        reference => reference,
+                    })
+                    .collect(),
+            );
+        }
+        self
+    }
+
+    /// Builds a usage line for the command invoked as `command_name` (e.g., `ocs validate`).
+    ///
+    /// Positional arguments come first, ordered by position and written in angle brackets,
+    /// followed by the flags and options in declaration order. Optional parameters are wrapped
+    /// in square brackets and variadic arguments end with `...`, e.g.
+    /// `ocs validate <file> [--strict] [--output <output>]`.
+    ///
+    /// Hidden and environment parameters are left out, as are references to parameter
+    /// components, which cannot be resolved from the command alone.
+    pub fn usage_string(&self, command_name: &str) -> String {
+        let parameters = self
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(|parameter| match parameter {
+                RefOr::T(parameter)
+                    if parameter.hidden != Some(true)
+                        && parameter.in_ != Some(ParameterIn::Environment) =>
+                {
+                    Some(parameter)
+                }
+                _ => None,
+            });
+        let (mut arguments, named): (Vec<_>, Vec<_>) = parameters.partition(|parameter| {
+            parameter.in_ == Some(ParameterIn::Argument)
+                || (parameter.in_.is_none() && parameter.position.is_some())
+        });
+        arguments.sort_by_key(|parameter| parameter.position.unwrap_or(u32::MAX));
+
+        let mut usage = command_name.to_string();
+        for parameter in arguments.into_iter().chain(named) {
+            let required = parameter.required == Some(true)
+                || parameter
+                    .arity
+                    .as_ref()
+                    .and_then(|arity| arity.min)
+                    .is_some_and(|min| min > 0);
+            let mut token = match parameter.in_ {
+                Some(ParameterIn::Argument) => format!("<{}>", parameter.name),
+                Some(ParameterIn::Flag) => format!("--{}", parameter.name),
+                _ if parameter.position.is_some() => format!("<{}>", parameter.name),
+                _ => format!("--{name} <{name}>", name = parameter.name),
+            };
+            if parameter.is_variadic() {
+                token.push_str("...");
+            }
+            usage.push(' ');
+            if required {
+                usage.push_str(&token);
+            } else {
+                usage.push_str(&format!("[{token}]"));
+            }
+        }
+        usage
+    }
 }
 
 impl Default for Command {
@@ -110,9 +245,223 @@ impl Default for Command {
     }
 }
 
+/// An example invocation of a command, e.g. `ocs validate spec.yaml --strict`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CommandExample {
+    /// The full command line of the invocation.
+    pub command: String,
+
+    /// A description of what the invocation does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The output the invocation prints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+impl CommandExample {
+    /// Creates a new example with the given command line.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            description: None,
+            output: None,
+        }
+    }
+
+    /// Sets the description of the example.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the output of the example.
+    pub fn output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+}
+
+/// A node of the command hierarchy built by [`OpenCli::command_tree`](super::OpenCli::command_tree).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandNode<'a> {
+    /// The name of the command, i.e. the last segment of its path.
+    pub name: String,
+
+    /// The command definition, or `None` for intermediate nodes without a command of their own.
+    pub command: Option<&'a Command>,
+
+    /// The subcommands, sorted by name.
+    pub children: Vec<CommandNode<'a>>,
+}
+
+impl<'a> CommandNode<'a> {
+    /// Creates a new node with the given name and no command or children.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            command: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the child node with the given name, if any.
+    pub fn child(&self, name: &str) -> Option<&CommandNode<'a>> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Inserts the command under the given path segments, relative to this node, creating the
+    /// missing intermediate nodes. Children are kept sorted by name, so the tree shape does not
+    /// depend on the order of the commands map.
+    pub(crate) fn insert(&mut self, segments: &[&str], command: &'a Command) {
+        let Some((first, rest)) = segments.split_first() else {
+            self.command = Some(command);
+            return;
+        };
+        let index = match self
+            .children
+            .binary_search_by(|child| child.name.as_str().cmp(first))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.children.insert(index, CommandNode::new(*first));
+                index
+            }
+        };
+        self.children[index].insert(rest, command);
+    }
+}
+
 /// A map of command names to their definitions.
 ///
 /// Commands can be nested to represent subcommands. For example:
 /// - "build" -> Command
 /// - "build.watch" -> Subcommand of build
+///
+/// Chaining and lookup helpers are provided by the [`CommandsExt`] trait.
 pub type Commands = Map<String, Command>;
+
+/// Chaining and lookup helpers for [`Commands`].
+pub trait CommandsExt {
+    /// Adds a command under the given path, replacing any command already registered for it.
+    fn command(self, path: impl Into<String>, command: Command) -> Self;
+
+    /// Returns the command registered under the given path, if any.
+    fn get_command(&self, path: &str) -> Option<&Command>;
+
+    /// Returns an iterator over the command paths, in the map order.
+    fn iter_paths(&self) -> impl Iterator<Item = &str>;
+
+    /// Returns an iterator over the root command names, in the map order.
+    ///
+    /// Root commands are keyed by their binary name, without a leading `/`. Specs documenting
+    /// several binaries have one root per binary, with subcommands keyed by paths nested under
+    /// the root (e.g., `foo` and `/foo/build`).
+    fn roots(&self) -> impl Iterator<Item = &str> {
+        self.iter_paths().filter(|path| !path.starts_with('/'))
+    }
+}
+
+impl CommandsExt for Commands {
+    fn command(mut self, path: impl Into<String>, command: Command) -> Self {
+        self.insert(path.into(), command);
+        self
+    }
+
+    fn get_command(&self, path: &str) -> Option<&Command> {
+        self.get(path)
+    }
+
+    fn iter_paths(&self) -> impl Iterator<Item = &str> {
+        self.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_string_with_validate_command_orders_and_brackets_parameters() {
+        //* Given
+        let command = Command::new().parameters(vec![
+            Parameter::new_flag("strict"),
+            Parameter::new_flag("debug-dump").hidden(true),
+            Parameter::new("output"),
+            Parameter::new_argument("file", 1),
+        ]);
+
+        //* When
+        let usage = command.usage_string("ocs validate");
+
+        //* Then
+        assert_eq!(
+            usage, "ocs validate <file> [--strict] [--output <output>]",
+            "positionals should come first, with optional parameters in brackets and hidden ones left out"
+        );
+    }
+
+    #[test]
+    fn usage_string_with_required_option_and_variadic_argument_marks_them() {
+        //* Given
+        let command = Command::new().parameters(vec![
+            Parameter::new_argument("files", 2)
+                .required(false)
+                .variadic(),
+            Parameter::new_option("config").required(true),
+            Parameter::new_argument("target", 1),
+        ]);
+
+        //* When
+        let usage = command.usage_string("ocs lint");
+
+        //* Then
+        assert_eq!(
+            usage, "ocs lint <target> [<files>...] --config <config>",
+            "required options should be unbracketed and variadic arguments end with ..."
+        );
+    }
+
+    #[test]
+    fn tag_with_existing_tags_appends_tag() {
+        //* Given
+        let command = Command::new().tags(vec!["core".to_string()]);
+
+        //* When
+        let command = command.tag("build").tag("release");
+
+        //* Then
+        assert_eq!(
+            command.tags,
+            Some(vec![
+                "core".to_string(),
+                "build".to_string(),
+                "release".to_string()
+            ]),
+            "single tags should be appended after the existing ones"
+        );
+    }
+
+    #[test]
+    fn serialize_with_responses_orders_them_by_exit_code() {
+        //* Given
+        let command = Command::new()
+            .response(10, Response::new().description("Ten"))
+            .response(2, Response::new().description("Two"))
+            .response(0, Response::new().description("Zero"));
+
+        //* When
+        let json = serde_json::to_string(&command).expect("command should serialize");
+
+        //* Then
+        let position = |code: &str| {
+            json.find(&format!("\"{code}\":"))
+                .unwrap_or_else(|| panic!("response {code} should be emitted in {json}"))
+        };
+        assert!(
+            position("0") < position("2") && position("2") < position("10"),
+            "responses should be emitted in numeric exit code order, got {json}"
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! Components container for reusable definitions.
 
-use super::{Parameter, Response, Schema, map::Map, schema::RefOr};
+use std::fmt;
+
+use super::{Parameter, Response, Schema, map::Map, schema::RefOr, serialize::is_none_or_empty};
 use crate::ToResponse;
 
 /// Reusable component definitions.
@@ -10,15 +12,20 @@ use crate::ToResponse;
 #[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct Components {
     /// Reusable schema definitions.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
     pub schemas: Option<Map<String, RefOr<Schema>>>,
 
     /// Reusable parameter definitions.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
     pub parameters: Option<Map<String, RefOr<Parameter>>>,
 
     /// Reusable response definitions.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// Responses are serialized in exit code order (`"2"` before `"10"`), whatever the map order.
+    #[serde(
+        skip_serializing_if = "crate::opencli::serialize::is_none_or_empty",
+        serialize_with = "crate::opencli::serialize::responses_by_exit_code"
+    )]
     pub responses: Option<Map<String, RefOr<Response>>>,
 }
 
@@ -28,6 +35,13 @@ impl Components {
         Self::default()
     }
 
+    /// Returns whether no schema, parameter or response component is declared.
+    pub fn is_empty(&self) -> bool {
+        is_none_or_empty(&self.schemas)
+            && is_none_or_empty(&self.parameters)
+            && is_none_or_empty(&self.responses)
+    }
+
     /// Sets the schemas.
     pub fn schemas(mut self, schemas: Map<String, RefOr<Schema>>) -> Self {
         self.schemas = Some(schemas);
@@ -71,4 +85,191 @@ impl Components {
         self.responses = Some(responses);
         self
     }
+
+    /// Merges the components of `other` into these components.
+    ///
+    /// Components declared by both with identical definitions are kept once. If a name is
+    /// declared by both with differing definitions, an error is returned and these components
+    /// are left unchanged.
+    pub fn merge(&mut self, other: Components) -> Result<(), MergeComponentsError> {
+        if let Some(name) = find_conflict(&self.schemas, &other.schemas) {
+            return Err(MergeComponentsError::ConflictingSchema(name));
+        }
+        if let Some(name) = find_conflict(&self.parameters, &other.parameters) {
+            return Err(MergeComponentsError::ConflictingParameter(name));
+        }
+        if let Some(name) = find_conflict(&self.responses, &other.responses) {
+            return Err(MergeComponentsError::ConflictingResponse(name));
+        }
+
+        merge_map(&mut self.schemas, other.schemas);
+        merge_map(&mut self.parameters, other.parameters);
+        merge_map(&mut self.responses, other.responses);
+        Ok(())
+    }
+}
+
+/// An error returned by [`Components::merge`] when a component name is declared with
+/// differing definitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeComponentsError {
+    /// The named schema is declared with differing definitions.
+    ConflictingSchema(String),
+    /// The named parameter is declared with differing definitions.
+    ConflictingParameter(String),
+    /// The named response is declared with differing definitions.
+    ConflictingResponse(String),
+}
+
+impl fmt::Display for MergeComponentsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeComponentsError::ConflictingSchema(name) => {
+                write!(f, "schema `{name}` is declared with differing definitions")
+            }
+            MergeComponentsError::ConflictingParameter(name) => {
+                write!(
+                    f,
+                    "parameter `{name}` is declared with differing definitions"
+                )
+            }
+            MergeComponentsError::ConflictingResponse(name) => {
+                write!(
+                    f,
+                    "response `{name}` is declared with differing definitions"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeComponentsError {}
+
+/// Returns the first name declared by both maps with differing definitions.
+fn find_conflict<T: PartialEq>(
+    target: &Option<Map<String, T>>,
+    source: &Option<Map<String, T>>,
+) -> Option<String> {
+    let (Some(target), Some(source)) = (target, source) else {
+        return None;
+    };
+    source
+        .iter()
+        .find(|(name, value)| target.get(*name).is_some_and(|existing| existing != *value))
+        .map(|(name, _)| name.clone())
+}
+
+/// Inserts every entry of `source` into `target`.
+fn merge_map<T>(target: &mut Option<Map<String, T>>, source: Option<Map<String, T>>) {
+    if let Some(source) = source {
+        target.get_or_insert_with(Map::new).extend(source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencli::{Object, SchemaType};
+
+    fn string_schema() -> RefOr<Schema> {
+        RefOr::T(Schema::Object(Box::new(
+            Object::new().schema_type(SchemaType::String),
+        )))
+    }
+
+    fn integer_schema() -> RefOr<Schema> {
+        RefOr::T(Schema::Object(Box::new(
+            Object::new().schema_type(SchemaType::Integer),
+        )))
+    }
+
+    #[test]
+    fn merge_with_identical_duplicate_schema_keeps_it_once() {
+        //* Given
+        let mut components = Components::new().schemas(Map::from_iter([
+            ("Name".to_string(), string_schema()),
+            ("Count".to_string(), integer_schema()),
+        ]));
+        let other = Components::new()
+            .schemas(Map::from_iter([("Name".to_string(), string_schema())]))
+            .responses(Map::from_iter([(
+                "Success".to_string(),
+                RefOr::T(Response::new().description("Success")),
+            )]));
+
+        //* When
+        let result = components.merge(other);
+
+        //* Then
+        assert_eq!(result, Ok(()), "identical duplicates should merge");
+        assert_eq!(
+            components.schemas.as_ref().map(Map::len),
+            Some(2),
+            "the duplicated schema should be kept once"
+        );
+        assert!(
+            components
+                .responses
+                .is_some_and(|responses| responses.contains_key("Success")),
+            "responses should be merged"
+        );
+    }
+
+    #[test]
+    fn merge_with_conflicting_schema_fails_and_keeps_components() {
+        //* Given
+        let mut components =
+            Components::new().schemas(Map::from_iter([("Id".to_string(), string_schema())]));
+        let other = Components::new()
+            .schemas(Map::from_iter([("Id".to_string(), integer_schema())]))
+            .parameters(Map::from_iter([(
+                "Verbose".to_string(),
+                RefOr::T(Parameter::new_flag("verbose")),
+            )]));
+
+        //* When
+        let result = components.merge(other);
+
+        //* Then
+        assert_eq!(
+            result,
+            Err(MergeComponentsError::ConflictingSchema("Id".to_string())),
+            "conflicting definitions should be reported"
+        );
+        assert_eq!(
+            components.schemas,
+            Some(Map::from_iter([("Id".to_string(), string_schema())])),
+            "the existing schema should be kept"
+        );
+        assert_eq!(
+            components.parameters, None,
+            "nothing should be merged on conflict"
+        );
+    }
+
+    #[test]
+    fn merge_with_conflicting_schema_converts_into_crate_error() {
+        //* Given
+        let merge = || -> Result<(), crate::Error> {
+            let mut components =
+                Components::new().schemas(Map::from_iter([("Id".to_string(), string_schema())]));
+            components.merge(
+                Components::new().schemas(Map::from_iter([("Id".to_string(), integer_schema())])),
+            )?;
+            Ok(())
+        };
+
+        //* When
+        let result = merge();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(crate::Error::MergeComponents(MergeComponentsError::ConflictingSchema(ref name)))
+                    if name == "Id"
+            ),
+            "the merge error should convert into the `MergeComponents` variant, got: {result:?}"
+        );
+    }
 }
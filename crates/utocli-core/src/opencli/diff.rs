@@ -0,0 +1,810 @@
+//! Change detection between two versions of an OpenCLI specification.
+
+use std::fmt;
+
+use super::{
+    AdditionalProperties, Command, CommandsExt, Object, OpenCli, Parameter, Schema, map::Map,
+    schema::RefOr,
+};
+
+/// A change between two versions of an OpenCLI specification.
+///
+/// Changes are either breaking, i.e. invocations or consumers of the old version may fail
+/// against the new one (see [`SpecChange::is_breaking`]), or non-breaking.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpecChange {
+    /// A command was added.
+    CommandAdded {
+        /// The path of the command.
+        command: String,
+    },
+    /// A command was removed. Always breaking.
+    CommandRemoved {
+        /// The path of the command.
+        command: String,
+    },
+    /// A parameter was added to a command. Breaking when the parameter is required.
+    ParameterAdded {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+        /// Whether the parameter is required.
+        required: bool,
+    },
+    /// A parameter was removed from a command. Always breaking.
+    ParameterRemoved {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// A parameter became required or optional. Breaking when it became required.
+    ParameterRequiredChanged {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+        /// Whether the parameter is now required.
+        required: bool,
+    },
+    /// The location (`in`) of a parameter changed, e.g. from an option to a flag. Always
+    /// breaking.
+    ParameterLocationChanged {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// The position of a parameter changed. Always breaking.
+    ParameterPositionChanged {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// The values accepted by a parameter changed, i.e. the type, format, enum, constraints,
+    /// structure or references of its schema. Always breaking.
+    ParameterSchemaChanged {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// Only the documentation of a parameter schema changed, e.g. its title, description,
+    /// default or examples. Never breaking.
+    ParameterSchemaDocsChanged {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// A schema component was added.
+    SchemaAdded {
+        /// The name of the schema component.
+        schema: String,
+    },
+    /// A schema component was removed. Always breaking.
+    SchemaRemoved {
+        /// The name of the schema component.
+        schema: String,
+    },
+    /// A property was added to an object schema component. Breaking when the property is
+    /// required.
+    SchemaPropertyAdded {
+        /// The name of the schema component.
+        schema: String,
+        /// The name of the property.
+        property: String,
+        /// Whether the property is required.
+        required: bool,
+    },
+    /// A property was removed from an object schema component. Always breaking.
+    SchemaPropertyRemoved {
+        /// The name of the schema component.
+        schema: String,
+        /// The name of the property.
+        property: String,
+    },
+    /// A property of an object schema component became required or optional. Breaking when
+    /// it became required.
+    SchemaPropertyRequiredChanged {
+        /// The name of the schema component.
+        schema: String,
+        /// The name of the property.
+        property: String,
+        /// Whether the property is now required.
+        required: bool,
+    },
+}
+
+impl SpecChange {
+    /// Returns whether the change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            SpecChange::CommandAdded { .. }
+            | SpecChange::ParameterSchemaDocsChanged { .. }
+            | SpecChange::SchemaAdded { .. } => false,
+            SpecChange::CommandRemoved { .. }
+            | SpecChange::ParameterRemoved { .. }
+            | SpecChange::ParameterLocationChanged { .. }
+            | SpecChange::ParameterPositionChanged { .. }
+            | SpecChange::ParameterSchemaChanged { .. }
+            | SpecChange::SchemaRemoved { .. }
+            | SpecChange::SchemaPropertyRemoved { .. } => true,
+            SpecChange::ParameterAdded { required, .. }
+            | SpecChange::ParameterRequiredChanged { required, .. }
+            | SpecChange::SchemaPropertyAdded { required, .. }
+            | SpecChange::SchemaPropertyRequiredChanged { required, .. } => *required,
+        }
+    }
+}
+
+impl fmt::Display for SpecChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let required = |required: &bool| if *required { "required" } else { "optional" };
+        match self {
+            SpecChange::CommandAdded { command } => write!(f, "command `{command}` was added"),
+            SpecChange::CommandRemoved { command } => {
+                write!(f, "command `{command}` was removed")
+            }
+            SpecChange::ParameterAdded {
+                command,
+                parameter,
+                required: is_required,
+            } => write!(
+                f,
+                "{} parameter `{parameter}` was added to command `{command}`",
+                required(is_required)
+            ),
+            SpecChange::ParameterRemoved { command, parameter } => write!(
+                f,
+                "parameter `{parameter}` was removed from command `{command}`"
+            ),
+            SpecChange::ParameterRequiredChanged {
+                command,
+                parameter,
+                required: is_required,
+            } => write!(
+                f,
+                "parameter `{parameter}` of command `{command}` became {}",
+                required(is_required)
+            ),
+            SpecChange::ParameterLocationChanged { command, parameter } => write!(
+                f,
+                "location of parameter `{parameter}` of command `{command}` changed"
+            ),
+            SpecChange::ParameterPositionChanged { command, parameter } => write!(
+                f,
+                "position of parameter `{parameter}` of command `{command}` changed"
+            ),
+            SpecChange::ParameterSchemaChanged { command, parameter } => write!(
+                f,
+                "schema of parameter `{parameter}` of command `{command}` changed"
+            ),
+            SpecChange::ParameterSchemaDocsChanged { command, parameter } => write!(
+                f,
+                "schema documentation of parameter `{parameter}` of command `{command}` changed"
+            ),
+            SpecChange::SchemaAdded { schema } => write!(f, "schema `{schema}` was added"),
+            SpecChange::SchemaRemoved { schema } => write!(f, "schema `{schema}` was removed"),
+            SpecChange::SchemaPropertyAdded {
+                schema,
+                property,
+                required: is_required,
+            } => write!(
+                f,
+                "{} property `{property}` was added to schema `{schema}`",
+                required(is_required)
+            ),
+            SpecChange::SchemaPropertyRemoved { schema, property } => write!(
+                f,
+                "property `{property}` was removed from schema `{schema}`"
+            ),
+            SpecChange::SchemaPropertyRequiredChanged {
+                schema,
+                property,
+                required: is_required,
+            } => write!(
+                f,
+                "property `{property}` of schema `{schema}` became {}",
+                required(is_required)
+            ),
+        }
+    }
+}
+
+/// The changes between two versions of an OpenCLI specification, as returned by
+/// [`OpenCli::diff`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpecDiff {
+    /// The changes, commands first, then schema components. Each group is sorted by command
+    /// path or schema name, then by parameter or property name, regardless of the map order.
+    pub changes: Vec<SpecChange>,
+}
+
+impl SpecDiff {
+    /// Returns whether the two specifications are identical in the compared aspects.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns whether any change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(SpecChange::is_breaking)
+    }
+
+    /// Returns an iterator over the breaking changes.
+    pub fn breaking(&self) -> impl Iterator<Item = &SpecChange> {
+        self.changes.iter().filter(|change| change.is_breaking())
+    }
+
+    /// Returns an iterator over the non-breaking changes.
+    pub fn non_breaking(&self) -> impl Iterator<Item = &SpecChange> {
+        self.changes.iter().filter(|change| !change.is_breaking())
+    }
+}
+
+impl OpenCli {
+    /// Compares the specification against a newer version of it.
+    ///
+    /// Reports the commands added or removed, the parameters added, removed or whose
+    /// requirement, location, position or schema changed (references to parameter components
+    /// are resolved), and the schema components added or removed and the object schema
+    /// properties added, removed or whose requirement changed.
+    ///
+    /// Parameter schemas are compared on the values they accept: edits limited to titles,
+    /// descriptions, defaults, examples, deprecation or extensions are reported as
+    /// [`SpecChange::ParameterSchemaDocsChanged`], which is not breaking.
+    ///
+    /// A parameter is required when marked `required` or when its arity minimum is at least
+    /// one.
+    pub fn diff(&self, other: &OpenCli) -> SpecDiff {
+        let mut changes = diff_commands(self, other);
+        changes.extend(diff_schemas(self, other));
+        SpecDiff { changes }
+    }
+}
+
+/// Detects the commands and command parameters added, removed or changed.
+fn diff_commands(old: &OpenCli, new: &OpenCli) -> Vec<SpecChange> {
+    let mut changes = Vec::new();
+
+    for (command_path, old_command) in &old.commands {
+        match new.commands.get_command(command_path) {
+            Some(new_command) => changes.extend(diff_parameters(
+                command_path,
+                parameters_by_name(old, old_command),
+                parameters_by_name(new, new_command),
+            )),
+            None => changes.push(SpecChange::CommandRemoved {
+                command: command_path.clone(),
+            }),
+        }
+    }
+    changes.extend(
+        new.commands
+            .iter_paths()
+            .filter(|command_path| old.commands.get_command(command_path).is_none())
+            .map(|command_path| SpecChange::CommandAdded {
+                command: command_path.to_string(),
+            }),
+    );
+
+    sort_changes(&mut changes);
+    changes
+}
+
+/// Detects the parameters added, removed or changed in a command.
+fn diff_parameters(
+    command_path: &str,
+    old: Map<&str, &Parameter>,
+    new: Map<&str, &Parameter>,
+) -> Vec<SpecChange> {
+    let is_required = |parameter: &Parameter| {
+        parameter.required == Some(true)
+            || parameter
+                .arity
+                .as_ref()
+                .and_then(|arity| arity.min)
+                .is_some_and(|min| min > 0)
+    };
+    let mut changes = Vec::new();
+
+    for (name, old_parameter) in &old {
+        match new.get(name) {
+            Some(new_parameter) => {
+                let (command, parameter) = (command_path.to_string(), name.to_string());
+                if is_required(old_parameter) != is_required(new_parameter) {
+                    changes.push(SpecChange::ParameterRequiredChanged {
+                        command: command.clone(),
+                        parameter: parameter.clone(),
+                        required: is_required(new_parameter),
+                    });
+                }
+                if old_parameter.in_ != new_parameter.in_ {
+                    changes.push(SpecChange::ParameterLocationChanged {
+                        command: command.clone(),
+                        parameter: parameter.clone(),
+                    });
+                }
+                if old_parameter.position != new_parameter.position {
+                    changes.push(SpecChange::ParameterPositionChanged {
+                        command: command.clone(),
+                        parameter: parameter.clone(),
+                    });
+                }
+                if old_parameter.schema != new_parameter.schema {
+                    let contract = |parameter: &Parameter| {
+                        parameter.schema.clone().map(|mut schema| {
+                            strip_schema_docs(&mut schema);
+                            schema
+                        })
+                    };
+                    changes.push(if contract(old_parameter) != contract(new_parameter) {
+                        SpecChange::ParameterSchemaChanged { command, parameter }
+                    } else {
+                        SpecChange::ParameterSchemaDocsChanged { command, parameter }
+                    });
+                }
+            }
+            None => changes.push(SpecChange::ParameterRemoved {
+                command: command_path.to_string(),
+                parameter: name.to_string(),
+            }),
+        }
+    }
+    changes.extend(new.iter().filter(|(name, _)| !old.contains_key(*name)).map(
+        |(name, parameter)| SpecChange::ParameterAdded {
+            command: command_path.to_string(),
+            parameter: name.to_string(),
+            required: is_required(parameter),
+        },
+    ));
+
+    changes
+}
+
+/// Clears the documentation of a schema, recursively, leaving the parts that define the accepted
+/// values.
+fn strip_schema_docs(schema: &mut RefOr<Schema>) {
+    let RefOr::T(schema) = schema else {
+        return;
+    };
+    match schema {
+        Schema::OneOf(one_of) => {
+            one_of.title = None;
+            one_of.description = None;
+            one_of.items.iter_mut().for_each(strip_schema_docs);
+        }
+        Schema::AllOf(all_of) => {
+            all_of.title = None;
+            all_of.description = None;
+            all_of.items.iter_mut().for_each(strip_schema_docs);
+        }
+        Schema::AnyOf(any_of) => {
+            any_of.title = None;
+            any_of.description = None;
+            any_of.items.iter_mut().for_each(strip_schema_docs);
+        }
+        Schema::Object(object) => {
+            object.title = None;
+            object.description = None;
+            object.default = None;
+            object.example = None;
+            object.examples = None;
+            object.deprecated = None;
+            object.extensions = None;
+            object
+                .properties
+                .iter_mut()
+                .flat_map(|properties| properties.values_mut())
+                .for_each(strip_schema_docs);
+            if let Some(AdditionalProperties::Schema(schema)) = &mut object.additional_properties {
+                strip_schema_docs(schema);
+            }
+        }
+        Schema::Array(array) => {
+            array.extensions = None;
+            if let Some(items) = &mut array.items {
+                strip_schema_docs(items);
+            }
+        }
+    }
+}
+
+/// Returns the command parameters keyed by name, resolving references to parameter components.
+fn parameters_by_name<'a>(
+    opencli: &'a OpenCli,
+    command: &'a Command,
+) -> Map<&'a str, &'a Parameter> {
+    opencli
+        .command_parameters(command)
+        .map(|parameter| (parameter.name.as_str(), parameter))
+        .collect()
+}
+
+/// Detects the schema components and object schema properties added or removed.
+fn diff_schemas(old: &OpenCli, new: &OpenCli) -> Vec<SpecChange> {
+    let schemas = |opencli: &OpenCli| {
+        opencli
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.clone())
+            .unwrap_or_default()
+    };
+    let (old, new) = (schemas(old), schemas(new));
+    let mut changes = Vec::new();
+
+    for (name, old_schema) in &old {
+        match new.get(name) {
+            Some(new_schema) => changes.extend(diff_properties(name, old_schema, new_schema)),
+            None => changes.push(SpecChange::SchemaRemoved {
+                schema: name.clone(),
+            }),
+        }
+    }
+    changes.extend(
+        new.keys()
+            .filter(|name| !old.contains_key(*name))
+            .map(|name| SpecChange::SchemaAdded {
+                schema: name.clone(),
+            }),
+    );
+
+    sort_changes(&mut changes);
+    changes
+}
+
+/// Sorts the changes by command path or schema name, then by parameter or property name.
+///
+/// The sort is stable, so the changes of a single parameter or property keep their order.
+fn sort_changes(changes: &mut [SpecChange]) {
+    let subject = |change: &SpecChange| -> (String, Option<String>) {
+        match change {
+            SpecChange::CommandAdded { command } | SpecChange::CommandRemoved { command } => {
+                (command.clone(), None)
+            }
+            SpecChange::ParameterAdded {
+                command, parameter, ..
+            }
+            | SpecChange::ParameterRemoved { command, parameter }
+            | SpecChange::ParameterRequiredChanged {
+                command, parameter, ..
+            }
+            | SpecChange::ParameterLocationChanged { command, parameter }
+            | SpecChange::ParameterPositionChanged { command, parameter }
+            | SpecChange::ParameterSchemaChanged { command, parameter }
+            | SpecChange::ParameterSchemaDocsChanged { command, parameter } => {
+                (command.clone(), Some(parameter.clone()))
+            }
+            SpecChange::SchemaAdded { schema } | SpecChange::SchemaRemoved { schema } => {
+                (schema.clone(), None)
+            }
+            SpecChange::SchemaPropertyAdded {
+                schema, property, ..
+            }
+            | SpecChange::SchemaPropertyRemoved { schema, property }
+            | SpecChange::SchemaPropertyRequiredChanged {
+                schema, property, ..
+            } => (schema.clone(), Some(property.clone())),
+        }
+    };
+    changes.sort_by_cached_key(subject);
+}
+
+/// Detects the properties added, removed or whose requirement changed between two versions of
+/// an object schema.
+fn diff_properties(name: &str, old: &RefOr<Schema>, new: &RefOr<Schema>) -> Vec<SpecChange> {
+    let (RefOr::T(Schema::Object(old)), RefOr::T(Schema::Object(new))) = (old, new) else {
+        return Vec::new();
+    };
+    let empty = Map::new();
+    let old_properties = old.properties.as_ref().unwrap_or(&empty);
+    let new_properties = new.properties.as_ref().unwrap_or(&empty);
+    let requires = |object: &Object, property: &String| {
+        object
+            .required
+            .as_ref()
+            .is_some_and(|required| required.contains(property))
+    };
+    let is_required = |property: &String| requires(new, property);
+
+    let removed = old_properties
+        .keys()
+        .filter(|property| !new_properties.contains_key(*property))
+        .map(|property| SpecChange::SchemaPropertyRemoved {
+            schema: name.to_string(),
+            property: property.clone(),
+        });
+    let added = new_properties
+        .keys()
+        .filter(|property| !old_properties.contains_key(*property))
+        .map(|property| SpecChange::SchemaPropertyAdded {
+            schema: name.to_string(),
+            property: property.clone(),
+            required: is_required(property),
+        });
+    let required_changed = old_properties
+        .keys()
+        .filter(|property| new_properties.contains_key(*property))
+        .filter(|property| requires(old, property) != is_required(property))
+        .map(|property| SpecChange::SchemaPropertyRequiredChanged {
+            schema: name.to_string(),
+            property: property.clone(),
+            required: is_required(property),
+        });
+
+    removed.chain(added).chain(required_changed).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencli::{Commands, Info};
+
+    #[test]
+    fn diff_with_new_required_parameter_reports_breaking_change() {
+        //* Given
+        let spec = |parameters: Vec<Parameter>| {
+            let commands =
+                Commands::new().command("/deploy", Command::new().parameters(parameters));
+            OpenCli::new(Info::new("app", "1.0.0")).commands(commands)
+        };
+        let old = spec(vec![Parameter::new_argument("target", 1)]);
+        let new = spec(vec![
+            Parameter::new_argument("target", 1),
+            Parameter::new_option("region").required(true),
+            Parameter::new_flag("dry-run"),
+        ]);
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        assert_eq!(
+            diff.breaking().collect::<Vec<_>>(),
+            [&SpecChange::ParameterAdded {
+                command: "/deploy".to_string(),
+                parameter: "region".to_string(),
+                required: true,
+            }],
+            "the new required parameter should be breaking"
+        );
+        assert_eq!(
+            diff.non_breaking().collect::<Vec<_>>(),
+            [&SpecChange::ParameterAdded {
+                command: "/deploy".to_string(),
+                parameter: "dry-run".to_string(),
+                required: false,
+            }],
+            "the new optional parameter should not be breaking"
+        );
+        assert!(diff.is_breaking(), "the diff should be breaking");
+    }
+
+    #[test]
+    fn diff_with_removed_command_and_schema_property_reports_breaking_changes() {
+        //* Given
+        use crate::opencli::{Components, Object};
+
+        let schema = |properties: &[&str]| {
+            let properties = properties.iter().map(|name| {
+                (
+                    name.to_string(),
+                    RefOr::T(Schema::Object(Box::new(Object::new()))),
+                )
+            });
+            RefOr::T(Schema::Object(Box::new(
+                Object::new().properties(Map::from_iter(properties)),
+            )))
+        };
+        let old = OpenCli::new(Info::new("app", "1.0.0"))
+            .commands(
+                Commands::new()
+                    .command("/lint", Command::new())
+                    .command("/validate", Command::new()),
+            )
+            .components(Components::new().schemas(Map::from_iter([(
+                "Report".to_string(),
+                schema(&["valid", "errors"]),
+            )])));
+        let new = OpenCli::new(Info::new("app", "2.0.0"))
+            .commands(Commands::new().command("/validate", Command::new()))
+            .components(Components::new().schemas(Map::from_iter([(
+                "Report".to_string(),
+                schema(&["valid", "warnings"]),
+            )])));
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        assert_eq!(
+            diff.changes,
+            [
+                SpecChange::CommandRemoved {
+                    command: "/lint".to_string(),
+                },
+                SpecChange::SchemaPropertyRemoved {
+                    schema: "Report".to_string(),
+                    property: "errors".to_string(),
+                },
+                SpecChange::SchemaPropertyAdded {
+                    schema: "Report".to_string(),
+                    property: "warnings".to_string(),
+                    required: false,
+                },
+            ],
+            "removed command and property and added property should be reported"
+        );
+        assert_eq!(
+            diff.breaking().count(),
+            2,
+            "removals should be breaking, the optional property addition should not"
+        );
+    }
+
+    #[test]
+    fn diff_with_changed_parameter_location_position_and_schema_reports_breaking_changes() {
+        //* Given
+        use crate::opencli::{Object, ParameterIn, SchemaType};
+
+        let spec = |parameters: Vec<Parameter>| {
+            let commands =
+                Commands::new().command("/deploy", Command::new().parameters(parameters));
+            OpenCli::new(Info::new("app", "1.0.0")).commands(commands)
+        };
+        let schema = |schema_type| {
+            RefOr::T(Schema::Object(Box::new(
+                Object::new().schema_type(schema_type),
+            )))
+        };
+        let old = spec(vec![
+            Parameter::new_option("verbose"),
+            Parameter::new_argument("target", 1),
+            Parameter::new_option("replicas").schema(schema(SchemaType::String)),
+        ]);
+        let new = spec(vec![
+            Parameter::new_option("verbose").in_(ParameterIn::Flag),
+            Parameter::new_argument("target", 2),
+            Parameter::new_option("replicas").schema(schema(SchemaType::Integer)),
+        ]);
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        let (command, parameter) = ("/deploy".to_string(), |name: &str| name.to_string());
+        assert_eq!(
+            diff.changes,
+            [
+                SpecChange::ParameterSchemaChanged {
+                    command: command.clone(),
+                    parameter: parameter("replicas"),
+                },
+                SpecChange::ParameterPositionChanged {
+                    command: command.clone(),
+                    parameter: parameter("target"),
+                },
+                SpecChange::ParameterLocationChanged {
+                    command,
+                    parameter: parameter("verbose"),
+                },
+            ],
+            "location, position and schema changes should be reported, sorted by parameter name"
+        );
+        assert_eq!(
+            diff.breaking().count(),
+            3,
+            "location, position and schema changes should all be breaking"
+        );
+    }
+
+    #[test]
+    fn diff_with_parameter_arity_minimum_raised_reports_breaking_requirement_change() {
+        //* Given
+        use crate::opencli::Arity;
+
+        let spec = |parameter: Parameter| {
+            let commands =
+                Commands::new().command("/deploy", Command::new().parameters(vec![parameter]));
+            OpenCli::new(Info::new("app", "1.0.0")).commands(commands)
+        };
+        let old = spec(Parameter::new_option("tag").arity(Arity::new().min(0)));
+        let new = spec(Parameter::new_option("tag").arity(Arity::new().min(1)));
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        assert_eq!(
+            diff.breaking().collect::<Vec<_>>(),
+            [&SpecChange::ParameterRequiredChanged {
+                command: "/deploy".to_string(),
+                parameter: "tag".to_string(),
+                required: true,
+            }],
+            "raising the arity minimum above zero should make the parameter required"
+        );
+    }
+
+    #[test]
+    fn diff_with_existing_schema_property_becoming_required_reports_breaking_change() {
+        //* Given
+        use crate::opencli::Components;
+
+        let spec = |required: Vec<String>| {
+            let schema = Object::new()
+                .properties(Map::from_iter([(
+                    "valid".to_string(),
+                    RefOr::T(Schema::Object(Box::new(Object::new()))),
+                )]))
+                .required(required);
+            OpenCli::new(Info::new("app", "1.0.0")).components(Components::new().schemas(
+                Map::from_iter([(
+                    "Report".to_string(),
+                    RefOr::T(Schema::Object(Box::new(schema))),
+                )]),
+            ))
+        };
+        let old = spec(vec![]);
+        let new = spec(vec!["valid".to_string()]);
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        assert_eq!(
+            diff.breaking().collect::<Vec<_>>(),
+            [&SpecChange::SchemaPropertyRequiredChanged {
+                schema: "Report".to_string(),
+                property: "valid".to_string(),
+                required: true,
+            }],
+            "an existing property becoming required should be breaking"
+        );
+        assert!(
+            !new.diff(&old).is_breaking(),
+            "an existing property becoming optional should not be breaking"
+        );
+    }
+
+    #[test]
+    fn diff_with_parameter_schema_docs_changed_reports_non_breaking_change() {
+        //* Given
+        use crate::opencli::SchemaType;
+
+        let spec = |description: &str| {
+            let schema = Object::new()
+                .schema_type(SchemaType::Integer)
+                .minimum(1.0)
+                .description(description)
+                .example(serde_json::json!(3));
+            let parameter = Parameter::new_option("replicas")
+                .schema(RefOr::T(Schema::Object(Box::new(schema))));
+            let commands =
+                Commands::new().command("/deploy", Command::new().parameters(vec![parameter]));
+            OpenCli::new(Info::new("app", "1.0.0")).commands(commands)
+        };
+        let old = spec("Number of replicas");
+        let new = spec("How many replicas to run");
+
+        //* When
+        let diff = old.diff(&new);
+
+        //* Then
+        assert_eq!(
+            diff.changes,
+            [SpecChange::ParameterSchemaDocsChanged {
+                command: "/deploy".to_string(),
+                parameter: "replicas".to_string(),
+            }],
+            "a description edit should be reported as a documentation change"
+        );
+        assert!(
+            !diff.is_breaking(),
+            "a documentation change should not be breaking"
+        );
+    }
+}
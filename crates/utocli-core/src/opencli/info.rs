@@ -1,5 +1,7 @@
 //! Info entity and related metadata types.
 
+use std::{convert::Infallible, str::FromStr};
+
 /// Core metadata identifying the CLI tool.
 ///
 /// The `Info` object provides essential metadata about the CLI application,
@@ -9,10 +11,19 @@ pub struct Info {
     /// The title of the CLI application.
     pub title: String,
 
+    /// A short summary of the CLI application, serialized as the `x-summary` extension.
+    #[serde(rename = "x-summary", skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// A description of the CLI application.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// A URL to the terms of service for the CLI application, serialized as the
+    /// `x-termsOfService` extension.
+    #[serde(rename = "x-termsOfService", skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+
     /// The version of the CLI application.
     pub version: String,
 
@@ -30,19 +41,33 @@ impl Info {
     pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
         Self {
             title: title.into(),
+            summary: None,
             description: None,
+            terms_of_service: None,
             version: version.into(),
             contact: None,
             license: None,
         }
     }
 
+    /// Sets the short summary for the CLI application.
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
     /// Sets the description for the CLI application.
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
 
+    /// Sets the terms of service URL for the CLI application.
+    pub fn terms_of_service(mut self, terms_of_service: impl Into<String>) -> Self {
+        self.terms_of_service = Some(terms_of_service.into());
+        self
+    }
+
     /// Sets the contact information for the CLI application.
     pub fn contact(mut self, contact: Contact) -> Self {
         self.contact = Some(contact);
@@ -99,6 +124,41 @@ impl Contact {
         self.email = Some(email.into());
         self
     }
+
+    /// Parses an author string in the common `Name <email>` format, as found in Cargo
+    /// manifests (e.g., `Jane Doe <jane@example.com>`).
+    ///
+    /// The email part is optional; a string without one sets only the name. Blank parts are
+    /// left unset. Only the first of several `:` separated authors (as in
+    /// `CARGO_PKG_AUTHORS`) is used.
+    pub fn from_author_string(author: &str) -> Self {
+        let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+        let author = author.split(':').next().unwrap_or_default().trim();
+
+        match author
+            .strip_suffix('>')
+            .and_then(|author| author.rsplit_once('<'))
+        {
+            Some((name, email)) => Self {
+                name: non_empty(name),
+                url: None,
+                email: non_empty(email),
+            },
+            None => Self {
+                name: non_empty(author),
+                ..Self::new()
+            },
+        }
+    }
+}
+
+/// Parses an author string, see [`Contact::from_author_string`].
+impl FromStr for Contact {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_author_string(s))
+    }
 }
 
 impl Default for Contact {
@@ -133,3 +193,72 @@ impl License {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_author_string_with_email_splits_name_and_email() {
+        //* Given
+        let author = "Jane Doe <jane@example.com>";
+
+        //* When
+        let contact = Contact::from_author_string(author);
+
+        //* Then
+        assert_eq!(
+            contact,
+            Contact::new().name("Jane Doe").email("jane@example.com"),
+            "the name and the email should be split"
+        );
+    }
+
+    #[test]
+    fn from_author_string_without_email_sets_only_name() {
+        //* Given
+        let author = "  Jane Doe ";
+
+        //* When
+        let contact: Contact = author.parse().expect("parsing should be infallible");
+
+        //* Then
+        assert_eq!(
+            contact,
+            Contact::new().name("Jane Doe"),
+            "only the trimmed name should be set"
+        );
+    }
+
+    #[test]
+    fn from_author_string_with_only_email_sets_only_email() {
+        //* Given
+        let author = "<jane@example.com>";
+
+        //* When
+        let contact = Contact::from_author_string(author);
+
+        //* Then
+        assert_eq!(
+            contact,
+            Contact::new().email("jane@example.com"),
+            "only the email should be set"
+        );
+    }
+
+    #[test]
+    fn from_author_string_with_several_authors_uses_the_first() {
+        //* Given
+        let authors = "Jane Doe <jane@example.com>:John Roe <john@example.com>";
+
+        //* When
+        let contact = Contact::from_author_string(authors);
+
+        //* Then
+        assert_eq!(
+            contact,
+            Contact::new().name("Jane Doe").email("jane@example.com"),
+            "only the first of the `:` separated authors should be used"
+        );
+    }
+}
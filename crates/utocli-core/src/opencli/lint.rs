@@ -0,0 +1,276 @@
+//! Lints for detecting common issues in OpenCLI specifications.
+
+use std::fmt;
+
+use super::{OpenCli, Schema, SchemaType, map::Map, schema::RefOr};
+
+/// A lint warning reported for an OpenCLI specification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lint {
+    /// The same inline enum list is declared by more than one parameter.
+    ///
+    /// The enum should be extracted to a schema component and referenced from each parameter.
+    DuplicateInlineEnum {
+        /// The repeated enum values.
+        values: Vec<serde_json::Value>,
+        /// The parameters declaring the enum, as `<command>.<parameter>` locations.
+        locations: Vec<String>,
+    },
+    /// An `application/x-ndjson` response streams items that are neither objects nor arrays.
+    ///
+    /// Each NDJSON line is a JSON record, so streamed items should be objects or arrays.
+    NonRecordNdjsonItem {
+        /// The response declaring the stream, as a `<command>.<exit code>` location.
+        location: String,
+    },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::DuplicateInlineEnum { values, locations } => {
+                let values = values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "inline enum [{values}] is repeated in {}; consider extracting it to a schema component",
+                    locations.join(", ")
+                )
+            }
+            Lint::NonRecordNdjsonItem { location } => write!(
+                f,
+                "application/x-ndjson content of {location} streams non-record items; NDJSON lines should be objects or arrays"
+            ),
+        }
+    }
+}
+
+impl OpenCli {
+    /// Runs the lints over the specification and returns the reported warnings.
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut lints = lint_duplicate_inline_enums(self);
+        lints.extend(lint_non_record_ndjson_items(self));
+        lints
+    }
+}
+
+/// Detects inline enum lists repeated across command parameters.
+fn lint_duplicate_inline_enums(opencli: &OpenCli) -> Vec<Lint> {
+    // Keyed by the serialized enum values, so identical lists are grouped together
+    let mut enums: Map<String, (Vec<serde_json::Value>, Vec<String>)> = Map::new();
+
+    for (command_name, command) in &opencli.commands {
+        for parameter in command.parameters.iter().flatten() {
+            // Referenced parameter components are declared once, so they can't repeat enums
+            let RefOr::T(parameter) = parameter else {
+                continue;
+            };
+            let Some(RefOr::T(Schema::Object(object))) = &parameter.schema else {
+                continue;
+            };
+            let Some(values) = &object.enum_values else {
+                continue;
+            };
+
+            let key = serde_json::Value::Array(values.clone()).to_string();
+            enums
+                .entry(key)
+                .or_insert_with(|| (values.clone(), Vec::new()))
+                .1
+                .push(format!("{command_name}.{}", parameter.name));
+        }
+    }
+
+    enums
+        .into_iter()
+        .filter(|(_, (_, locations))| locations.len() > 1)
+        .map(|(_, (values, locations))| Lint::DuplicateInlineEnum { values, locations })
+        .collect()
+}
+
+/// Detects NDJSON streams whose items are primitives rather than objects or arrays.
+fn lint_non_record_ndjson_items(opencli: &OpenCli) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    for (command_name, command) in &opencli.commands {
+        for (exit_code, response) in command.responses.iter().flatten() {
+            let Some(media_type) = response
+                .content
+                .as_ref()
+                .and_then(|content| content.get("application/x-ndjson"))
+            else {
+                continue;
+            };
+            let Some(schema) = &media_type.schema else {
+                continue;
+            };
+
+            // Streams are documented as an array of their items (see `MediaType::ndjson`), but
+            // the item schema may also be given directly
+            let item = match resolve_schema(opencli, schema) {
+                Some(Schema::Array(array)) => match &array.items {
+                    Some(items) => resolve_schema(opencli, items),
+                    None => None,
+                },
+                item => item,
+            };
+
+            if let Some(Schema::Object(object)) = item
+                && object.schema_type.as_ref().is_some_and(|schema_type| {
+                    !matches!(schema_type, SchemaType::Object | SchemaType::Array)
+                })
+            {
+                lints.push(Lint::NonRecordNdjsonItem {
+                    location: format!("{command_name}.{exit_code}"),
+                });
+            }
+        }
+    }
+
+    lints
+}
+
+/// Resolves a schema, following references to the specification's schema components.
+fn resolve_schema<'a>(opencli: &'a OpenCli, schema: &'a RefOr<Schema>) -> Option<&'a Schema> {
+    match schema {
+        RefOr::T(schema) => Some(schema),
+        RefOr::Ref(reference) => {
+            let name = reference.ref_path.strip_prefix("#/components/schemas/")?;
+            let schemas = opencli.components.as_ref()?.schemas.as_ref()?;
+            match schemas.get(name)? {
+                RefOr::T(schema) => Some(schema),
+                RefOr::Ref(_) => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencli::{
+        Command, Commands, Info, MediaType, Object, Parameter, Response, SchemaType,
+    };
+
+    fn format_parameter() -> Parameter {
+        Parameter::new_option("format").schema(RefOr::T(Schema::Object(Box::new(
+            Object::new()
+                .schema_type(SchemaType::String)
+                .enum_values(vec!["json".into(), "yaml".into(), "text".into()]),
+        ))))
+    }
+
+    #[test]
+    fn lint_with_enum_repeated_across_commands_reports_duplicate() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new().parameters(vec![format_parameter()]),
+        );
+        commands.insert(
+            "generate".to_string(),
+            Command::new().parameters(vec![format_parameter()]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let lints = opencli.lint();
+
+        //* Then
+        assert_eq!(lints.len(), 1, "should report a single duplicated enum");
+        let Lint::DuplicateInlineEnum { values, locations } = &lints[0] else {
+            panic!("expected a duplicate inline enum lint, got {:?}", lints[0]);
+        };
+        assert_eq!(
+            values,
+            &vec![
+                serde_json::json!("json"),
+                serde_json::json!("yaml"),
+                serde_json::json!("text")
+            ],
+            "should report the repeated enum values"
+        );
+        let mut locations = locations.clone();
+        locations.sort();
+        assert_eq!(
+            locations,
+            vec!["generate.format".to_string(), "validate.format".to_string()],
+            "should report every parameter declaring the enum"
+        );
+    }
+
+    #[test]
+    fn lint_with_enum_referenced_from_components_reports_nothing() {
+        //* Given
+        let format_ref =
+            Parameter::new_option("format").schema(RefOr::new_ref("#/components/schemas/Format"));
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new().parameters(vec![format_ref.clone()]),
+        );
+        commands.insert(
+            "generate".to_string(),
+            Command::new().parameters(vec![format_ref]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let lints = opencli.lint();
+
+        //* Then
+        assert!(lints.is_empty(), "referenced enums should not be reported");
+    }
+
+    fn ndjson_response(item_schema: Schema) -> Response {
+        let mut content = Map::new();
+        content.insert(
+            "application/x-ndjson".to_string(),
+            MediaType::ndjson(item_schema),
+        );
+        Response::new()
+            .description("Streamed records")
+            .content(content)
+    }
+
+    #[test]
+    fn lint_with_ndjson_stream_of_primitives_reports_non_record_items() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "logs".to_string(),
+            Command::new().response(
+                0,
+                ndjson_response(Schema::Object(Box::new(
+                    Object::new().schema_type(SchemaType::String),
+                ))),
+            ),
+        );
+        commands.insert(
+            "events".to_string(),
+            Command::new().response(
+                0,
+                ndjson_response(Schema::Object(Box::new(
+                    Object::new().schema_type(SchemaType::Object),
+                ))),
+            ),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let lints = opencli.lint();
+
+        //* Then
+        assert_eq!(
+            lints,
+            vec![Lint::NonRecordNdjsonItem {
+                location: "logs.0".to_string()
+            }],
+            "only the stream of primitive items should be reported"
+        );
+    }
+}
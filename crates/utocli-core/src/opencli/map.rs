@@ -4,6 +4,13 @@
 //! The concrete type depends on the `preserve_order` feature:
 //! - When `preserve_order` is enabled: `IndexMap` (preserves insertion order)
 //! - When disabled (default): `BTreeMap` (sorted by keys)
+//!
+//! Both types share the API post-processing passes need to mutate a specification in place
+//! (`get_mut`, `iter_mut`, `values_mut` and `entry`), iterating in the map order. Removal is
+//! the exception, as `IndexMap::remove` is deprecated in favor of methods that either keep or
+//! break the insertion order, so use [`remove`] instead.
+
+use std::{borrow::Borrow, hash::Hash};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "preserve_order")] {
@@ -12,3 +19,116 @@ cfg_if::cfg_if! {
         pub use std::collections::BTreeMap as Map;
     }
 }
+
+/// Removes the entry for `key` from the map, keeping the order of the remaining entries.
+///
+/// Returns the removed value, if the key was present.
+pub fn remove<K, V, Q>(map: &mut Map<K, V>, key: &Q) -> Option<V>
+where
+    K: Borrow<Q> + Hash + Ord,
+    Q: Hash + Ord + ?Sized,
+{
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "preserve_order")] {
+            map.shift_remove(key)
+        } else {
+            map.remove(key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> Map<String, u32> {
+        Map::from_iter([
+            ("build".to_string(), 1),
+            ("check".to_string(), 2),
+            ("run".to_string(), 3),
+        ])
+    }
+
+    #[test]
+    fn get_mut_with_existing_key_updates_value_in_place() {
+        //* Given
+        let mut map = commands();
+
+        //* When
+        if let Some(value) = map.get_mut("check") {
+            *value = 20;
+        }
+
+        //* Then
+        assert_eq!(map.get("check"), Some(&20), "value should be updated");
+        assert_eq!(map.len(), 3, "no entry should be added or removed");
+    }
+
+    #[test]
+    fn iter_mut_with_entries_yields_them_in_map_order() {
+        //* Given
+        let mut map = commands();
+
+        //* When
+        let keys: Vec<String> = map
+            .iter_mut()
+            .map(|(key, value)| {
+                *value *= 10;
+                key.clone()
+            })
+            .collect();
+
+        //* Then
+        assert_eq!(
+            keys,
+            vec!["build", "check", "run"],
+            "entries should be yielded in map order"
+        );
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            vec![10, 20, 30],
+            "values should be mutated in place"
+        );
+    }
+
+    #[test]
+    fn remove_with_middle_key_keeps_remaining_order() {
+        //* Given
+        let mut map = commands();
+
+        //* When
+        let removed = remove(&mut map, "check");
+
+        //* Then
+        assert_eq!(removed, Some(2), "should return the removed value");
+        assert_eq!(
+            map.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["build", "run"],
+            "remaining entries should keep their order"
+        );
+        assert_eq!(
+            remove(&mut map, "check"),
+            None,
+            "removing a missing key should return None"
+        );
+    }
+
+    #[test]
+    fn entry_or_insert_with_missing_and_existing_keys_inserts_once() {
+        //* Given
+        let mut map = commands();
+
+        //* When
+        *map.entry("test".to_string()).or_insert(0) += 4;
+        *map.entry("run".to_string()).or_insert(0) += 4;
+
+        //* Then
+        assert_eq!(map.get("test"), Some(&4), "missing key should be inserted");
+        assert_eq!(map.get("run"), Some(&7), "existing value should be kept");
+        assert_eq!(
+            map.keys().last().map(String::as_str),
+            Some("test"),
+            "inserted entry should be last"
+        );
+    }
+}
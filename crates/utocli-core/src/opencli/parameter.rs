@@ -28,14 +28,64 @@ pub struct Parameter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
 
+    /// Whether the parameter is deprecated.
+    #[serde(rename = "x-deprecated", skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+
+    /// Whether the parameter is hidden from help output.
+    ///
+    /// Hidden parameters stay in the specification, so tools can still document or complete
+    /// them, but help renderers should leave them out.
+    #[serde(rename = "x-hidden", skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+
+    /// Names of the parameters that must be given along with this parameter.
+    #[serde(
+        rename = "x-requires",
+        skip_serializing_if = "crate::opencli::serialize::is_none_or_empty"
+    )]
+    pub requires: Option<Vec<String>>,
+
+    /// Names of the parameters that cannot be given along with this parameter.
+    #[serde(
+        rename = "x-conflicts",
+        skip_serializing_if = "crate::opencli::serialize::is_none_or_empty"
+    )]
+    pub conflicts: Option<Vec<String>>,
+
+    /// The placeholder shown for the parameter value in help output (e.g., `FILE` in `<FILE>`).
+    #[serde(rename = "x-value-name", skip_serializing_if = "Option::is_none")]
+    pub value_name: Option<String>,
+
+    /// The help section the parameter is grouped under (e.g., `Networking`).
+    #[serde(rename = "x-section", skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+
+    /// The value used when the parameter is given without one (e.g., `always` for a bare
+    /// `--color` accepting `--color=<when>`).
+    #[serde(rename = "x-default-missing", skip_serializing_if = "Option::is_none")]
+    pub default_missing_value: Option<String>,
+
+    /// How the flag value is passed on the command line (e.g., `--strict` or `--strict=true`).
+    #[serde(rename = "x-flag-style", skip_serializing_if = "Option::is_none")]
+    pub flag_style: Option<FlagStyle>,
+
     /// The scope of the parameter (local or inherited).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<ParameterScope>,
 
+    /// The environment variable the parameter value falls back to.
+    #[serde(rename = "x-env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
     /// The arity (number of values) for the parameter.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arity: Option<Arity>,
 
+    /// Whether the parameter is a variadic trailing argument. See [`Parameter::variadic`].
+    #[serde(rename = "x-variadic", skip_serializing_if = "Option::is_none")]
+    pub variadic: Option<bool>,
+
     /// The schema for the parameter value.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<RefOr<Schema>>,
@@ -55,8 +105,18 @@ impl Parameter {
             alias: None,
             description: None,
             required: None,
+            deprecated: None,
+            hidden: None,
+            requires: None,
+            conflicts: None,
+            value_name: None,
+            section: None,
+            default_missing_value: None,
+            flag_style: None,
             scope: None,
+            env: None,
             arity: None,
+            variadic: None,
             schema: None,
             extensions: None,
         }
@@ -71,8 +131,18 @@ impl Parameter {
             alias: None,
             description: None,
             required: Some(true),
+            deprecated: None,
+            hidden: None,
+            requires: None,
+            conflicts: None,
+            value_name: None,
+            section: None,
+            default_missing_value: None,
+            flag_style: None,
             scope: None,
+            env: None,
             arity: None,
+            variadic: None,
             schema: None,
             extensions: None,
         }
@@ -87,8 +157,18 @@ impl Parameter {
             alias: None,
             description: None,
             required: None,
+            deprecated: None,
+            hidden: None,
+            requires: None,
+            conflicts: None,
+            value_name: None,
+            section: None,
+            default_missing_value: None,
+            flag_style: None,
             scope: None,
+            env: None,
             arity: None,
+            variadic: None,
             schema: None,
             extensions: None,
         }
@@ -103,8 +183,45 @@ impl Parameter {
             alias: None,
             description: None,
             required: None,
+            deprecated: None,
+            hidden: None,
+            requires: None,
+            conflicts: None,
+            value_name: None,
+            section: None,
+            default_missing_value: None,
+            flag_style: None,
             scope: None,
+            env: None,
             arity: None,
+            variadic: None,
+            schema: None,
+            extensions: None,
+        }
+    }
+
+    /// Creates a new environment parameter, sourced solely from the environment variable
+    /// with the given name.
+    pub fn new_environment(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            in_: Some(ParameterIn::Environment),
+            position: None,
+            alias: None,
+            description: None,
+            required: None,
+            deprecated: None,
+            hidden: None,
+            requires: None,
+            conflicts: None,
+            value_name: None,
+            section: None,
+            default_missing_value: None,
+            flag_style: None,
+            scope: None,
+            env: None,
+            arity: None,
+            variadic: None,
             schema: None,
             extensions: None,
         }
@@ -140,12 +257,68 @@ impl Parameter {
         self
     }
 
+    /// Sets whether the parameter is deprecated.
+    pub fn deprecated(mut self, deprecated: bool) -> Self {
+        self.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Sets whether the parameter is hidden from help output.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+
+    /// Sets the names of the parameters that must be given along with this parameter,
+    /// emitted as the `x-requires` extension.
+    pub fn requires(mut self, requires: Vec<String>) -> Self {
+        self.requires = Some(requires);
+        self
+    }
+
+    /// Sets the names of the parameters that cannot be given along with this parameter,
+    /// emitted as the `x-conflicts` extension.
+    pub fn conflicts(mut self, conflicts: Vec<String>) -> Self {
+        self.conflicts = Some(conflicts);
+        self
+    }
+
+    /// Sets the placeholder shown for the parameter value in help output, emitted as the
+    /// `x-value-name` extension.
+    pub fn value_name(mut self, value_name: impl Into<String>) -> Self {
+        self.value_name = Some(value_name.into());
+        self
+    }
+
+    /// Sets the help section the parameter is grouped under, emitted as the `x-section`
+    /// extension.
+    pub fn section(mut self, section: impl Into<String>) -> Self {
+        self.section = Some(section.into());
+        self
+    }
+
+    /// Sets the value used when the parameter is given without one, emitted as the
+    /// `x-default-missing` extension.
+    ///
+    /// The value becomes optional: the arity defaults to zero or one value, unless already set.
+    pub fn default_missing_value(mut self, value: impl Into<String>) -> Self {
+        self.default_missing_value = Some(value.into());
+        self.arity.get_or_insert_with(|| Arity::range(0, 1));
+        self
+    }
+
     /// Sets the parameter scope.
     pub fn scope(mut self, scope: ParameterScope) -> Self {
         self.scope = Some(scope);
         self
     }
 
+    /// Sets the environment variable the parameter value falls back to.
+    pub fn env(mut self, env: impl Into<String>) -> Self {
+        self.env = Some(env.into());
+        self
+    }
+
     /// Sets the arity.
     pub fn arity(mut self, arity: Arity) -> Self {
         self.arity = Some(arity);
@@ -163,6 +336,46 @@ impl Parameter {
         self.extensions = Some(extensions);
         self
     }
+
+    /// Reconciles `required` with the arity minimum.
+    ///
+    /// A required parameter with an arity minimum of zero (or no minimum) gets a minimum of one,
+    /// and a parameter with an arity minimum of at least one is marked as required. Parameters
+    /// without an arity are left untouched.
+    pub fn normalize(mut self) -> Self {
+        if let Some(arity) = &mut self.arity {
+            match (self.required, arity.min) {
+                (Some(true), None | Some(0)) => arity.min = Some(1),
+                (_, Some(min)) if min >= 1 => self.required = Some(true),
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Marks the parameter as a variadic trailing argument (e.g., `cmd file1 file2 ...`),
+    /// occupying its position and every following one.
+    ///
+    /// The arity maximum is left unbounded, and the parameter is marked with `x-variadic` so
+    /// tools can tell it apart from a single positional argument.
+    pub fn variadic(mut self) -> Self {
+        self.arity.get_or_insert_with(|| Arity::new().min(0)).max = None;
+        self.variadic = Some(true);
+        self
+    }
+
+    /// Returns whether the parameter is a variadic trailing argument.
+    ///
+    /// See [`Parameter::variadic`].
+    pub fn is_variadic(&self) -> bool {
+        self.variadic == Some(true)
+    }
+
+    /// Sets how the flag value is passed, emitted as `x-flag-style`.
+    pub fn flag_style(mut self, flag_style: FlagStyle) -> Self {
+        self.flag_style = Some(flag_style);
+        self
+    }
 }
 
 /// The location of the parameter in the command line.
@@ -175,6 +388,19 @@ pub enum ParameterIn {
     Flag,
     /// Named option with value (e.g., --output <file>).
     Option,
+    /// Environment-only setting with no command line counterpart; the parameter name is the
+    /// environment variable (e.g., `APP_TOKEN`).
+    Environment,
+}
+
+/// How a flag is passed on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlagStyle {
+    /// The flag is set by its presence (e.g., `--strict`).
+    Presence,
+    /// The flag takes an explicit boolean value (e.g., `--strict=true`).
+    Value,
 }
 
 /// The scope of the parameter.
@@ -242,3 +468,206 @@ impl Default for Arity {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_parameter_with_env_includes_env_binding() {
+        //* Given
+        let param = Parameter::new_option("config").env("OCS_CONFIG_PATH");
+
+        //* When
+        let value = serde_json::to_value(&param).expect("should serialize parameter");
+
+        //* Then
+        assert_eq!(
+            value.get("x-env"),
+            Some(&serde_json::json!("OCS_CONFIG_PATH")),
+            "env binding should be serialized as the x-env extension"
+        );
+    }
+
+    #[test]
+    fn serialize_parameter_with_deprecated_includes_deprecated_flag() {
+        //* Given
+        let param = Parameter::new_flag("old-flag").deprecated(true);
+
+        //* When
+        let value = serde_json::to_value(&param).expect("should serialize parameter");
+
+        //* Then
+        assert_eq!(
+            value.get("x-deprecated"),
+            Some(&serde_json::json!(true)),
+            "deprecated flag should be serialized as the x-deprecated extension"
+        );
+    }
+
+    #[test]
+    fn normalize_with_required_and_zero_arity_min_bumps_min_to_one() {
+        //* Given
+        let param = Parameter::new_option("files")
+            .required(true)
+            .arity(Arity::range(0, 5));
+
+        //* When
+        let param = param.normalize();
+
+        //* Then
+        assert_eq!(
+            param.arity,
+            Some(Arity::range(1, 5)),
+            "required parameter should accept at least one value"
+        );
+        assert_eq!(param.required, Some(true), "parameter should stay required");
+    }
+
+    #[test]
+    fn normalize_with_arity_min_marks_parameter_required() {
+        //* Given
+        let param = Parameter::new_option("files").arity(Arity::new().min(2));
+
+        //* When
+        let param = param.normalize();
+
+        //* Then
+        assert_eq!(
+            param.required,
+            Some(true),
+            "parameter with a non-zero arity minimum should be required"
+        );
+        assert_eq!(
+            param.arity,
+            Some(Arity::new().min(2)),
+            "arity should be left untouched"
+        );
+    }
+
+    #[test]
+    fn normalize_without_arity_leaves_parameter_untouched() {
+        //* Given
+        let param = Parameter::new_option("output").required(true);
+
+        //* When
+        let normalized = param.clone().normalize();
+
+        //* Then
+        assert_eq!(
+            normalized, param,
+            "parameter without arity should not change"
+        );
+    }
+
+    #[test]
+    fn flag_style_with_presence_sets_flag_style() {
+        //* When
+        let param = Parameter::new_flag("strict").flag_style(FlagStyle::Presence);
+
+        //* Then
+        assert_eq!(
+            param.flag_style,
+            Some(FlagStyle::Presence),
+            "presence flag style should be set"
+        );
+    }
+
+    #[test]
+    fn flag_style_preserves_existing_extensions() {
+        //* Given
+        let mut extensions = Extensions::new();
+        extensions.insert("x-completion".to_string(), serde_json::json!("file"));
+
+        //* When
+        let param = Parameter::new_flag("strict")
+            .extensions(extensions)
+            .flag_style(FlagStyle::Value);
+
+        //* Then
+        assert_eq!(
+            param.flag_style,
+            Some(FlagStyle::Value),
+            "value flag style should be set"
+        );
+        let extensions = param.extensions.expect("should have extensions");
+        assert_eq!(
+            extensions,
+            Extensions::from_iter([("x-completion".to_string(), serde_json::json!("file"))]),
+            "existing extensions should be preserved and not hold the flag style"
+        );
+    }
+
+    #[test]
+    fn serialize_parameter_with_flag_style_round_trips_x_flag_style() {
+        //* Given
+        let param = Parameter::new_flag("strict").flag_style(FlagStyle::Presence);
+
+        //* When
+        let value = serde_json::to_value(&param).expect("should serialize parameter");
+        let parsed: Parameter =
+            serde_json::from_value(value.clone()).expect("should deserialize parameter");
+
+        //* Then
+        assert_eq!(
+            value.get("x-flag-style"),
+            Some(&serde_json::json!("presence")),
+            "flag style should be serialized at the parameter level"
+        );
+        assert_eq!(
+            parsed.flag_style,
+            Some(FlagStyle::Presence),
+            "x-flag-style should deserialize into the flag style field"
+        );
+        assert_eq!(
+            parsed.extensions.unwrap_or_default().get("x-flag-style"),
+            None,
+            "x-flag-style should not be captured as an extension"
+        );
+    }
+
+    #[test]
+    fn variadic_with_argument_sets_unbounded_arity_and_variadic() {
+        //* When
+        let param = Parameter::new_argument("files", 1)
+            .arity(Arity::range(1, 3))
+            .variadic();
+
+        //* Then
+        assert_eq!(
+            param.arity,
+            Some(Arity::new().min(1)),
+            "the arity minimum should be kept and the maximum unbounded"
+        );
+        assert!(param.is_variadic(), "parameter should be variadic");
+        let value = serde_json::to_value(&param).expect("should serialize parameter");
+        assert_eq!(
+            value.get("x-variadic"),
+            Some(&serde_json::json!(true)),
+            "variadic arguments should be serialized with x-variadic"
+        );
+        assert_eq!(
+            param.extensions, None,
+            "the variadic marker should not be stored as an extension"
+        );
+    }
+
+    #[test]
+    fn default_missing_value_with_color_option_sets_optional_value_arity() {
+        //* When
+        let param = Parameter::new_option("color").default_missing_value("always");
+
+        //* Then
+        assert_eq!(
+            param.arity,
+            Some(Arity::range(0, 1)),
+            "the value should become optional"
+        );
+        let value = serde_json::to_value(&param).expect("should serialize parameter");
+        assert_eq!(
+            value.get("x-default-missing"),
+            Some(&serde_json::json!("always")),
+            "the default missing value should be serialized as x-default-missing"
+        );
+    }
+}
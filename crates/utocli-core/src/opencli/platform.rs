@@ -25,6 +25,51 @@ impl Platform {
         self.architectures = Some(architectures);
         self
     }
+
+    /// Returns the desktop platforms, Linux, Darwin and Windows, each supporting the `amd64`
+    /// and `arm64` architectures.
+    pub fn all_desktop() -> Vec<Platform> {
+        [
+            PlatformName::Linux,
+            PlatformName::Darwin,
+            PlatformName::Windows,
+        ]
+        .into_iter()
+        .map(|name| {
+            Platform::new(name).architectures(vec![Architecture::Amd64, Architecture::Arm64])
+        })
+        .collect()
+    }
+
+    /// Creates a Linux platform supporting the `amd64` architecture.
+    pub fn linux_amd64() -> Self {
+        Self::new(PlatformName::Linux).architectures(vec![Architecture::Amd64])
+    }
+
+    /// Creates a Linux platform supporting the `arm64` architecture.
+    pub fn linux_arm64() -> Self {
+        Self::new(PlatformName::Linux).architectures(vec![Architecture::Arm64])
+    }
+
+    /// Creates a Darwin platform supporting the `amd64` architecture.
+    pub fn darwin_amd64() -> Self {
+        Self::new(PlatformName::Darwin).architectures(vec![Architecture::Amd64])
+    }
+
+    /// Creates a Darwin platform supporting the `arm64` architecture.
+    pub fn darwin_arm64() -> Self {
+        Self::new(PlatformName::Darwin).architectures(vec![Architecture::Arm64])
+    }
+
+    /// Creates a Windows platform supporting the `amd64` architecture.
+    pub fn windows_amd64() -> Self {
+        Self::new(PlatformName::Windows).architectures(vec![Architecture::Amd64])
+    }
+
+    /// Creates a Windows platform supporting the `arm64` architecture.
+    pub fn windows_arm64() -> Self {
+        Self::new(PlatformName::Windows).architectures(vec![Architecture::Arm64])
+    }
 }
 
 /// Platform operating system names.
@@ -55,6 +100,24 @@ pub enum PlatformName {
     Aix,
     /// Oracle Solaris
     Solaris,
+    /// A platform without a dedicated variant, serialized as its raw name.
+    ///
+    /// The OpenCLI specification restricts platform names to the variants above, so documents
+    /// using this variant fall outside the specification and fail validation against its JSON
+    /// Schema. Prefer [`PlatformName::from_name`], which maps known names to their variant.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl PlatformName {
+    /// Creates a platform name from a raw name.
+    ///
+    /// The name is lowercased, and names with a dedicated variant (e.g. `"LINUX"`) resolve to
+    /// it. Other names produce [`PlatformName::Other`], which is outside the OpenCLI
+    /// specification.
+    pub fn from_name(name: impl AsRef<str>) -> Self {
+        from_raw_name(name.as_ref(), PlatformName::Other)
+    }
 }
 
 /// CPU architecture types.
@@ -117,4 +180,139 @@ pub enum Architecture {
     Hexagon,
     /// LoongArch 64-bit
     Loongarch64,
+    /// An architecture without a dedicated variant, serialized as its raw name.
+    ///
+    /// The OpenCLI specification restricts architectures to the variants above, so documents
+    /// using this variant fall outside the specification and fail validation against its JSON
+    /// Schema. Prefer [`Architecture::from_name`], which maps known names to their variant.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl Architecture {
+    /// Creates an architecture from a raw name.
+    ///
+    /// The name is lowercased, and names with a dedicated variant (e.g. `"ARM64"`) resolve to
+    /// it. Other names produce [`Architecture::Other`], which is outside the OpenCLI
+    /// specification.
+    pub fn from_name(name: impl AsRef<str>) -> Self {
+        from_raw_name(name.as_ref(), Architecture::Other)
+    }
+}
+
+/// Resolves a raw lowercased name to its dedicated variant, falling back to `other`.
+fn from_raw_name<'de, T: serde::Deserialize<'de>>(name: &str, other: fn(String) -> T) -> T {
+    use serde::de::{IntoDeserializer, value::Error};
+
+    let name = name.to_lowercase();
+    match T::deserialize(IntoDeserializer::<Error>::into_deserializer(name.clone())) {
+        Ok(value) => value,
+        Err(_) => other(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Architecture, Platform, PlatformName};
+
+    #[test]
+    fn platform_with_other_name_and_architecture_serializes_raw_strings() {
+        //* Given
+        let platform = Platform::new(PlatformName::from_name("Haiku"))
+            .architectures(vec![Architecture::Amd64, Architecture::from_name("E2K")]);
+
+        //* When
+        let value = serde_json::to_value(&platform).expect("should serialize platform");
+
+        //* Then
+        assert_eq!(
+            value,
+            serde_json::json!({ "name": "haiku", "architectures": ["amd64", "e2k"] }),
+            "unknown values should serialize as their raw lowercase strings"
+        );
+    }
+
+    #[test]
+    fn from_name_with_known_name_resolves_to_dedicated_variant() {
+        //* When
+        let name = PlatformName::from_name("LINUX");
+        let architectures = [
+            Architecture::from_name("ARM64"),
+            Architecture::from_name("X86_64"),
+        ];
+
+        //* Then
+        assert_eq!(
+            name,
+            PlatformName::Linux,
+            "a known platform name should not produce `Other`"
+        );
+        assert_eq!(
+            architectures,
+            [Architecture::Arm64, Architecture::X86_64],
+            "known architecture names should not produce `Other`"
+        );
+    }
+
+    #[test]
+    fn platform_with_unknown_name_and_architecture_deserializes_to_other() {
+        //* Given
+        let value = serde_json::json!({ "name": "haiku", "architectures": ["x86_64", "e2k"] });
+
+        //* When
+        let platform: Platform =
+            serde_json::from_value(value).expect("should deserialize platform");
+
+        //* Then
+        assert_eq!(
+            platform,
+            Platform::new(PlatformName::Other("haiku".to_string())).architectures(vec![
+                Architecture::X86_64,
+                Architecture::Other("e2k".to_string())
+            ]),
+            "unknown values should deserialize to other while known ones keep their variant"
+        );
+    }
+
+    #[test]
+    fn all_desktop_yields_linux_darwin_and_windows_with_amd64_and_arm64() {
+        //* When
+        let platforms = Platform::all_desktop();
+
+        //* Then
+        let names = platforms
+            .iter()
+            .map(|platform| platform.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                PlatformName::Linux,
+                PlatformName::Darwin,
+                PlatformName::Windows
+            ],
+            "the desktop platforms should be linux, darwin and windows"
+        );
+        for platform in &platforms {
+            assert_eq!(
+                platform.architectures,
+                Some(vec![Architecture::Amd64, Architecture::Arm64]),
+                "{:?} should support amd64 and arm64",
+                platform.name
+            );
+        }
+    }
+
+    #[test]
+    fn linux_arm64_creates_linux_platform_with_single_architecture() {
+        //* When
+        let platform = Platform::linux_arm64();
+
+        //* Then
+        assert_eq!(
+            platform,
+            Platform::new(PlatformName::Linux).architectures(vec![Architecture::Arm64]),
+            "the shortcut should match the explicit builder"
+        );
+    }
 }
@@ -1,11 +1,168 @@
 //! Response and media type entities.
 
-use super::{Schema, map::Map, schema::RefOr};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use super::{
+    Schema,
+    extensions::Extensions,
+    map::Map,
+    schema::{Array, RefOr},
+};
+
+/// An exit code key of a command's responses map.
+///
+/// Besides exact exit codes (e.g., `"0"`), responses can document an inclusive range of exit
+/// codes (e.g., `"1-125"`) or a `"default"` response covering every undocumented exit code.
+///
+/// Exit codes are ordered with exact codes first, then ranges, then the default response.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(into = "String", try_from = "String")]
+pub enum ExitCode {
+    /// A single exit code (e.g., `"0"`).
+    Exact(u8),
+    /// An inclusive range of exit codes (e.g., `"1-125"`).
+    Range(u8, u8),
+    /// Any exit code not documented by another response (`"default"`).
+    Default,
+}
+
+impl ExitCode {
+    /// Returns `true` if the exit code `code` is covered by this key.
+    pub fn contains(&self, code: u8) -> bool {
+        match *self {
+            ExitCode::Exact(exact) => exact == code,
+            ExitCode::Range(start, end) => (start..=end).contains(&code),
+            ExitCode::Default => true,
+        }
+    }
+}
+
+impl fmt::Display for ExitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExitCode::Exact(code) => write!(f, "{code}"),
+            ExitCode::Range(start, end) => write!(f, "{start}-{end}"),
+            ExitCode::Default => f.write_str("default"),
+        }
+    }
+}
+
+impl FromStr for ExitCode {
+    type Err = ParseExitCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseExitCodeError(s.to_string());
+        if s == "default" {
+            return Ok(ExitCode::Default);
+        }
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let start = start.parse::<u8>().map_err(|_| error())?;
+                let end = end.parse::<u8>().map_err(|_| error())?;
+                if start > end {
+                    return Err(error());
+                }
+                Ok(ExitCode::Range(start, end))
+            }
+            None => s.parse::<u8>().map(ExitCode::Exact).map_err(|_| error()),
+        }
+    }
+}
+
+impl From<u8> for ExitCode {
+    fn from(code: u8) -> Self {
+        ExitCode::Exact(code)
+    }
+}
+
+impl From<ExitCode> for String {
+    fn from(code: ExitCode) -> Self {
+        code.to_string()
+    }
+}
+
+impl TryFrom<String> for ExitCode {
+    type Error = ParseExitCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Error returned when parsing an invalid [`ExitCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseExitCodeError(String);
+
+impl fmt::Display for ParseExitCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid exit code `{}`, expected an exit code (e.g., \"0\"), a range (e.g., \"1-125\") or \"default\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseExitCodeError {}
+
+/// A responses map ordered by exit code.
+///
+/// [`IntoResponses::responses`](crate::IntoResponses::responses) returns a `BTreeMap`, whose
+/// string keys sort lexically (`"10"` before `"2"`). This wrapper reorders the entries following
+/// the [`ExitCode`] ordering and serializes them as a map in that order. Keys that are not valid
+/// exit codes keep their lexical order and come last.
+///
+/// The responses of commands and components are serialized in the same order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderedResponses(Vec<(String, RefOr<Response>)>);
+
+impl OrderedResponses {
+    /// Returns an iterator over the exit code keys and responses, in exit code order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &RefOr<Response>)> {
+        self.0.iter().map(|(code, response)| (code, response))
+    }
+}
+
+impl From<BTreeMap<String, RefOr<Response>>> for OrderedResponses {
+    fn from(responses: BTreeMap<String, RefOr<Response>>) -> Self {
+        let mut entries = responses.into_iter().collect::<Vec<_>>();
+        sort_by_exit_code(&mut entries);
+        Self(entries)
+    }
+}
+
+/// Sorts response entries following the [`ExitCode`] ordering.
+///
+/// The sort is stable: keys that are not valid exit codes come last, in their original order.
+pub(crate) fn sort_by_exit_code<K: AsRef<str>, V>(entries: &mut [(K, V)]) {
+    entries.sort_by_key(|(code, _)| {
+        let code = code.as_ref().parse::<ExitCode>().ok();
+        (code.is_none(), code)
+    });
+}
+
+impl IntoIterator for OrderedResponses {
+    type Item = (String, RefOr<Response>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl serde::Serialize for OrderedResponses {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
 
 /// Describes command exit codes and output formats.
 ///
-/// Responses are keyed by exit code strings (e.g., "0", "1", "2") and describe
-/// the expected output format for each exit code.
+/// Responses are keyed by exit code strings (e.g., "0", "1", "2"), exit code ranges
+/// (e.g., "1-125") or "default" (see [`ExitCode`]) and describe the expected output format
+/// for each exit code.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Response {
     /// A description of the response.
@@ -18,8 +175,12 @@ pub struct Response {
     /// - `text/plain` - Plain text output
     /// - `application/json` - JSON formatted output
     /// - `application/yaml` - YAML formatted output
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
     pub content: Option<Map<String, MediaType>>,
+
+    /// Extension properties (e.g., `x-retryable`).
+    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    pub extensions: Option<Extensions>,
 }
 
 impl Response {
@@ -28,9 +189,56 @@ impl Response {
         Self {
             description: None,
             content: None,
+            extensions: None,
         }
     }
 
+    /// Creates a response with `application/json` content referencing the
+    /// `#/components/schemas/{schema_name}` schema component.
+    ///
+    /// ```
+    /// use utocli_core::opencli::{RefOr, Response};
+    ///
+    /// let response = Response::json_ref("Validation result", "ValidationResult");
+    ///
+    /// let content = response.content.expect("should have content");
+    /// assert_eq!(
+    ///     content["application/json"].schema,
+    ///     Some(RefOr::new_ref("#/components/schemas/ValidationResult"))
+    /// );
+    /// ```
+    pub fn json_ref(description: impl Into<String>, schema_name: impl AsRef<str>) -> Self {
+        let schema = RefOr::new_ref(format!("#/components/schemas/{}", schema_name.as_ref()));
+        let mut content = Map::new();
+        content.insert(
+            "application/json".to_string(),
+            MediaType::new().schema(schema),
+        );
+        Self::new().description(description).content(content)
+    }
+
+    /// Creates a response with `text/plain` content showing the given example output.
+    ///
+    /// ```
+    /// use utocli_core::opencli::Response;
+    ///
+    /// let response = Response::text("Validation successful", "✓ No errors found");
+    ///
+    /// let content = response.content.expect("should have content");
+    /// assert_eq!(
+    ///     content["text/plain"].example,
+    ///     Some(serde_json::json!("✓ No errors found"))
+    /// );
+    /// ```
+    pub fn text(description: impl Into<String>, example: impl Into<String>) -> Self {
+        let mut content = Map::new();
+        content.insert(
+            "text/plain".to_string(),
+            MediaType::new().example(serde_json::Value::String(example.into())),
+        );
+        Self::new().description(description).content(content)
+    }
+
     /// Sets the description for the response.
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -42,6 +250,12 @@ impl Response {
         self.content = Some(content);
         self
     }
+
+    /// Sets the extensions for the response.
+    pub fn extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
 }
 
 impl Default for Response {
@@ -71,6 +285,27 @@ impl MediaType {
         }
     }
 
+    /// Creates an `application/x-ndjson` media type streaming records of the given item schema.
+    ///
+    /// NDJSON output is not a single JSON array, so the stream is documented as an array of the
+    /// item schema carrying the `x-stream: true` extension. Records should be objects or arrays;
+    /// [`OpenCli::lint`](super::OpenCli::lint) warns about streams of primitive items.
+    ///
+    /// ```
+    /// use utocli_core::opencli::{MediaType, RefOr};
+    ///
+    /// let media_type = MediaType::ndjson(RefOr::new_ref("#/components/schemas/LogRecord"));
+    /// ```
+    pub fn ndjson(item_schema: impl Into<RefOr<Schema>>) -> Self {
+        let mut extensions = Map::new();
+        extensions.insert("x-stream".to_string(), serde_json::Value::Bool(true));
+        Self::new().schema(RefOr::T(Schema::Array(
+            Array::new()
+                .items(item_schema.into())
+                .extensions(extensions),
+        )))
+    }
+
     /// Sets the schema for the media type.
     pub fn schema(mut self, schema: RefOr<Schema>) -> Self {
         self.schema = Some(schema);
@@ -89,3 +324,162 @@ impl Default for MediaType {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{ExitCode, MediaType, OrderedResponses, Response, Schema};
+    use crate::opencli::{map::Map, schema::RefOr};
+
+    #[test]
+    fn exit_code_from_str_with_exact_range_and_default_round_trips() {
+        //* Given
+        let keys = ["0", "1-125", "default"];
+
+        //* When
+        let codes = keys
+            .iter()
+            .map(|key| key.parse::<ExitCode>())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("exit codes should parse");
+
+        //* Then
+        assert_eq!(
+            codes,
+            vec![
+                ExitCode::Exact(0),
+                ExitCode::Range(1, 125),
+                ExitCode::Default
+            ],
+            "each key should parse to its exit code variant"
+        );
+        let displayed = codes.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(displayed, keys, "exit codes should display as their keys");
+    }
+
+    #[test]
+    fn exit_code_from_str_with_invalid_key_fails() {
+        //* Given
+        let keys = ["", "256", "125-1", "1-", "Default", "-1"];
+
+        //* When
+        let results = keys.map(str::parse::<ExitCode>);
+
+        //* Then
+        for (key, result) in keys.iter().zip(results) {
+            assert!(result.is_err(), "`{key}` should not parse as an exit code");
+        }
+    }
+
+    #[test]
+    fn exit_code_ordering_puts_exact_codes_before_ranges_and_default() {
+        //* Given
+        let mut codes = vec![
+            ExitCode::Default,
+            ExitCode::Range(1, 125),
+            ExitCode::Exact(2),
+            ExitCode::Exact(0),
+        ];
+
+        //* When
+        codes.sort();
+
+        //* Then
+        assert_eq!(
+            codes,
+            vec![
+                ExitCode::Exact(0),
+                ExitCode::Exact(2),
+                ExitCode::Range(1, 125),
+                ExitCode::Default
+            ],
+            "exact codes should sort first and default last"
+        );
+    }
+
+    #[test]
+    fn response_json_ref_and_text_build_single_media_type_content() {
+        //* Given
+        let json_response = Response::json_ref("Validation result", "ValidationResult");
+        let text_response = Response::text("Validation successful", "✓ No errors found");
+
+        //* Then
+        let mut json_content = Map::new();
+        json_content.insert(
+            "application/json".to_string(),
+            MediaType::new().schema(RefOr::new_ref("#/components/schemas/ValidationResult")),
+        );
+        assert_eq!(
+            json_response,
+            Response::new()
+                .description("Validation result")
+                .content(json_content),
+            "json_ref should reference the schema component as application/json"
+        );
+
+        let mut text_content = Map::new();
+        text_content.insert(
+            "text/plain".to_string(),
+            MediaType::new().example(serde_json::json!("✓ No errors found")),
+        );
+        assert_eq!(
+            text_response,
+            Response::new()
+                .description("Validation successful")
+                .content(text_content),
+            "text should hold the example as text/plain"
+        );
+    }
+
+    #[test]
+    fn media_type_ndjson_streams_array_of_item_schema() {
+        //* Given
+        let item = RefOr::new_ref("#/components/schemas/LogRecord");
+
+        //* When
+        let media_type = MediaType::ndjson(item.clone());
+
+        //* Then
+        let Some(RefOr::T(Schema::Array(array))) = &media_type.schema else {
+            panic!(
+                "expected an inline array schema, got {:?}",
+                media_type.schema
+            );
+        };
+        assert_eq!(
+            array.items.as_deref(),
+            Some(&item),
+            "array items should be the record schema"
+        );
+        let json = serde_json::to_value(&media_type).expect("media type should serialize");
+        assert_eq!(
+            json["schema"]["x-stream"],
+            serde_json::json!(true),
+            "the stream should carry the x-stream extension"
+        );
+    }
+
+    #[test]
+    fn ordered_responses_from_map_orders_by_exit_code_with_unknown_keys_last() {
+        //* Given
+        let responses = ["other", "default", "10", "1-125", "2", "0"]
+            .into_iter()
+            .map(|code| (code.to_string(), RefOr::T(Response::new())))
+            .collect::<BTreeMap<_, _>>();
+
+        //* When
+        let ordered = OrderedResponses::from(responses);
+
+        //* Then
+        let codes = ordered
+            .iter()
+            .map(|(code, _)| code.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            ["0", "2", "10", "1-125", "default", "other"],
+            "exact codes should come first in numeric order, then ranges, default and unknown keys"
+        );
+    }
+}
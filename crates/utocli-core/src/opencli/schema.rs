@@ -1,6 +1,6 @@
 //! Schema types and validation.
 
-use super::map::Map;
+use super::{extensions::Extensions, map::Map};
 
 /// A schema definition or a reference to a schema component.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -24,6 +24,44 @@ impl<T> RefOr<T> {
     pub fn new_inline(value: T) -> Self {
         RefOr::T(value)
     }
+
+    /// Returns the reference path, or `None` for an inline definition.
+    pub fn as_ref_path(&self) -> Option<&str> {
+        match self {
+            RefOr::Ref(reference) => Some(&reference.ref_path),
+            RefOr::T(_) => None,
+        }
+    }
+
+    /// Returns the inline definition, or `None` for a reference.
+    pub fn as_value(&self) -> Option<&T> {
+        match self {
+            RefOr::Ref(_) => None,
+            RefOr::T(value) => Some(value),
+        }
+    }
+
+    /// Returns the inline definition mutably, or `None` for a reference.
+    pub fn as_value_mut(&mut self) -> Option<&mut T> {
+        match self {
+            RefOr::Ref(_) => None,
+            RefOr::T(value) => Some(value),
+        }
+    }
+
+    /// Maps the inline definition with `f`, keeping references unchanged.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> RefOr<U> {
+        match self {
+            RefOr::Ref(reference) => RefOr::Ref(reference),
+            RefOr::T(value) => RefOr::T(f(value)),
+        }
+    }
+}
+
+impl<T> From<T> for RefOr<T> {
+    fn from(value: T) -> Self {
+        RefOr::T(value)
+    }
 }
 
 /// A reference to a component.
@@ -38,12 +76,207 @@ pub struct Ref {
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum Schema {
+    /// A schema matching exactly one of several schemas.
+    OneOf(OneOf),
+    /// A schema matching all of several schemas.
+    AllOf(AllOf),
+    /// A schema matching any of several schemas.
+    AnyOf(AnyOf),
     /// An object schema.
     Object(Box<Object>),
     /// An array schema.
     Array(Array),
 }
 
+/// A schema composition matching exactly one of its schemas (`oneOf`).
+///
+/// Used to represent data-carrying enums, where each variant is one of the possible schemas.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct OneOf {
+    /// The schemas of which exactly one must match.
+    #[serde(rename = "oneOf")]
+    pub items: Vec<RefOr<Schema>>,
+
+    /// The title of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A description of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The property telling which of the schemas a value matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<Discriminator>,
+}
+
+impl OneOf {
+    /// Creates a new empty `oneOf` schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a schema to the `oneOf` schemas.
+    pub fn item(mut self, item: impl Into<RefOr<Schema>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the discriminator.
+    pub fn discriminator(mut self, discriminator: Discriminator) -> Self {
+        self.discriminator = Some(discriminator);
+        self
+    }
+}
+
+/// Tells which schema of a `oneOf` composition a value matches, from one of its properties.
+///
+/// Used to document internally tagged enums, where the tag property holds the variant name.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Discriminator {
+    /// The name of the property holding the discriminating value.
+    #[serde(rename = "propertyName")]
+    pub property_name: String,
+
+    /// Maps discriminating values to the schema references they select.
+    #[serde(skip_serializing_if = "crate::opencli::serialize::is_none_or_empty")]
+    pub mapping: Option<Map<String, String>>,
+}
+
+impl Discriminator {
+    /// Creates a new discriminator on the given property.
+    pub fn new(property_name: impl Into<String>) -> Self {
+        Self {
+            property_name: property_name.into(),
+            mapping: None,
+        }
+    }
+
+    /// Maps a discriminating value to a schema reference path.
+    pub fn mapping(mut self, value: impl Into<String>, ref_path: impl Into<String>) -> Self {
+        self.mapping
+            .get_or_insert_with(Map::new)
+            .insert(value.into(), ref_path.into());
+        self
+    }
+}
+
+/// A schema composition matching all of its schemas (`allOf`).
+///
+/// Used to compose schemas, e.g., extending a base schema with additional properties.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct AllOf {
+    /// The schemas that must all match.
+    #[serde(rename = "allOf")]
+    pub items: Vec<RefOr<Schema>>,
+
+    /// The title of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A description of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the value is read-only.
+    #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
+    /// Whether the value is write-only.
+    #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+}
+
+impl AllOf {
+    /// Creates a new empty `allOf` schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a schema to the `allOf` schemas.
+    pub fn item(mut self, item: impl Into<RefOr<Schema>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the read-only flag.
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets the write-only flag.
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        self.write_only = write_only;
+        self
+    }
+}
+
+/// A schema composition matching at least one of its schemas (`anyOf`).
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct AnyOf {
+    /// The schemas of which at least one must match.
+    #[serde(rename = "anyOf")]
+    pub items: Vec<RefOr<Schema>>,
+
+    /// The title of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A description of the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl AnyOf {
+    /// Creates a new empty `anyOf` schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a schema to the `anyOf` schemas.
+    pub fn item(mut self, item: impl Into<RefOr<Schema>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
 /// An object schema definition.
 #[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct Object {
@@ -71,6 +304,15 @@ pub struct Object {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::Value>,
 
+    /// Example values for this schema, as the JSON Schema 2020-12 `examples` keyword.
+    ///
+    /// Takes precedence over [`Object::example`] in the JSON Schema projection
+    /// ([`OpenCli::to_json_schema`]).
+    ///
+    /// [`OpenCli::to_json_schema`]: crate::opencli::OpenCli::to_json_schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<serde_json::Value>>,
+
     /// Properties for object types.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<Map<String, RefOr<Schema>>>,
@@ -103,13 +345,23 @@ pub struct Object {
     #[serde(rename = "multipleOf", skip_serializing_if = "Option::is_none")]
     pub multiple_of: Option<f64>,
 
-    /// Exclusive minimum value for numeric types.
+    /// Exclusive minimum for numeric types, either a flag making `minimum` exclusive or the
+    /// exclusive bound itself (see [`ExclusiveBound`]).
+    ///
+    /// **Breaking change:** this field used to be an `Option<bool>`. Existing flags convert with
+    /// the `From<bool>` impl of [`ExclusiveBound`] (e.g., `Some(true.into())`), and the
+    /// [`Object::exclusive_minimum`] builder still accepts a `bool`.
     #[serde(rename = "exclusiveMinimum", skip_serializing_if = "Option::is_none")]
-    pub exclusive_minimum: Option<bool>,
-
-    /// Exclusive maximum value for numeric types.
+    pub exclusive_minimum: Option<ExclusiveBound>,
+
+    /// Exclusive maximum for numeric types, either a flag making `maximum` exclusive or the
+    /// exclusive bound itself (see [`ExclusiveBound`]).
+    ///
+    /// **Breaking change:** this field used to be an `Option<bool>`. Existing flags convert with
+    /// the `From<bool>` impl of [`ExclusiveBound`] (e.g., `Some(true.into())`), and the
+    /// [`Object::exclusive_maximum`] builder still accepts a `bool`.
     #[serde(rename = "exclusiveMaximum", skip_serializing_if = "Option::is_none")]
-    pub exclusive_maximum: Option<bool>,
+    pub exclusive_maximum: Option<ExclusiveBound>,
 
     /// Maximum number of properties for object types.
     #[serde(rename = "maxProperties", skip_serializing_if = "Option::is_none")]
@@ -139,12 +391,16 @@ pub struct Object {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nullable: Option<bool>,
 
-    /// Whether additional properties are allowed (for object types).
+    /// Whether additional properties are allowed, or the schema they must match (for object types).
     #[serde(
         rename = "additionalProperties",
         skip_serializing_if = "Option::is_none"
     )]
-    pub additional_properties: Option<bool>,
+    pub additional_properties: Option<AdditionalProperties>,
+
+    /// Extension properties.
+    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    pub extensions: Option<Extensions>,
 }
 
 impl Object {
@@ -189,6 +445,12 @@ impl Object {
         self
     }
 
+    /// Sets the example values, serialized as the JSON Schema `examples` array.
+    pub fn examples(mut self, examples: Vec<serde_json::Value>) -> Self {
+        self.examples = Some(examples);
+        self
+    }
+
     /// Sets the title.
     pub fn title(mut self, title: Option<impl Into<String>>) -> Self {
         self.title = title.map(|t| t.into());
@@ -219,9 +481,16 @@ impl Object {
         self
     }
 
-    /// Sets whether additional properties are allowed.
-    pub fn additional_properties(mut self, allowed: Option<bool>) -> Self {
-        self.additional_properties = allowed;
+    /// Sets whether additional properties are allowed, or the schema they must match.
+    ///
+    /// Accepts an [`AdditionalProperties`], built from a `bool` or a [`RefOr<Schema>`]
+    /// constraining the additional property values, optionally wrapped in `Some`, or `None` to
+    /// unset it.
+    pub fn additional_properties(
+        mut self,
+        additional_properties: impl Into<Option<AdditionalProperties>>,
+    ) -> Self {
+        self.additional_properties = additional_properties.into();
         self
     }
 
@@ -273,15 +542,19 @@ impl Object {
         self
     }
 
-    /// Sets the exclusive minimum flag.
-    pub fn exclusive_minimum(mut self, exclusive_minimum: bool) -> Self {
-        self.exclusive_minimum = Some(exclusive_minimum);
+    /// Sets the exclusive minimum.
+    ///
+    /// Accepts a `bool` flag making `minimum` exclusive, or an `f64` exclusive bound.
+    pub fn exclusive_minimum(mut self, exclusive_minimum: impl Into<ExclusiveBound>) -> Self {
+        self.exclusive_minimum = Some(exclusive_minimum.into());
         self
     }
 
-    /// Sets the exclusive maximum flag.
-    pub fn exclusive_maximum(mut self, exclusive_maximum: bool) -> Self {
-        self.exclusive_maximum = Some(exclusive_maximum);
+    /// Sets the exclusive maximum.
+    ///
+    /// Accepts a `bool` flag making `maximum` exclusive, or an `f64` exclusive bound.
+    pub fn exclusive_maximum(mut self, exclusive_maximum: impl Into<ExclusiveBound>) -> Self {
+        self.exclusive_maximum = Some(exclusive_maximum.into());
         self
     }
 
@@ -296,6 +569,73 @@ impl Object {
         self.min_properties = Some(min_properties);
         self
     }
+
+    /// Sets the extensions.
+    pub fn extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+}
+
+/// The `additionalProperties` of an object schema.
+///
+/// Either a boolean allowing (or forbidding) any additional property, or a schema the
+/// additional property values must match (e.g., the values of a map).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum AdditionalProperties {
+    /// Whether additional properties are allowed.
+    Bool(bool),
+    /// The schema additional property values must match.
+    Schema(Box<RefOr<Schema>>),
+}
+
+impl From<bool> for AdditionalProperties {
+    fn from(allowed: bool) -> Self {
+        AdditionalProperties::Bool(allowed)
+    }
+}
+
+impl From<RefOr<Schema>> for AdditionalProperties {
+    fn from(schema: RefOr<Schema>) -> Self {
+        AdditionalProperties::Schema(Box::new(schema))
+    }
+}
+
+/// The `exclusiveMinimum` or `exclusiveMaximum` of an object schema.
+///
+/// Either the legacy flag making the `minimum` (or `maximum`) exclusive, or the exclusive bound
+/// itself, as JSON Schema 2020-12 defines it.
+///
+/// The [`Object::exclusive_minimum`] and [`Object::exclusive_maximum`] fields used to hold a
+/// plain `bool` flag; code setting them directly migrates with `From<bool>`:
+///
+/// ```
+/// use utocli_core::{ExclusiveBound, Object};
+///
+/// let mut object = Object::new().minimum(0.0);
+/// object.exclusive_minimum = Some(true.into());
+/// assert_eq!(object.exclusive_minimum, Some(ExclusiveBound::Flag(true)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum ExclusiveBound {
+    /// Whether the `minimum` (or `maximum`) is exclusive.
+    Flag(bool),
+    /// The exclusive bound value.
+    Value(f64),
+}
+
+impl From<bool> for ExclusiveBound {
+    fn from(exclusive: bool) -> Self {
+        ExclusiveBound::Flag(exclusive)
+    }
+}
+
+impl From<f64> for ExclusiveBound {
+    fn from(bound: f64) -> Self {
+        ExclusiveBound::Value(bound)
+    }
 }
 
 /// An array schema definition.
@@ -316,6 +656,10 @@ pub struct Array {
     /// Minimum number of items in the array.
     #[serde(rename = "minItems", skip_serializing_if = "Option::is_none")]
     pub min_items: Option<usize>,
+
+    /// Extension properties.
+    #[serde(skip_serializing_if = "Option::is_none", flatten)]
+    pub extensions: Option<Extensions>,
 }
 
 impl Array {
@@ -326,6 +670,7 @@ impl Array {
             items: None,
             max_items: None,
             min_items: None,
+            extensions: None,
         }
     }
 
@@ -346,6 +691,12 @@ impl Array {
         self.min_items = Some(min_items);
         self
     }
+
+    /// Sets the extensions.
+    pub fn extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
 }
 
 impl Default for Array {
@@ -414,4 +765,350 @@ pub enum SchemaFormat {
     Float,
     /// Double-precision floating point.
     Double,
+
+    /// Any other format, serialized as its raw string.
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_exclusive_bounds_renders_flag_or_numeric_form() {
+        //* Given
+        let legacy = Object::new()
+            .schema_type(SchemaType::Number)
+            .minimum(0.0)
+            .exclusive_minimum(true);
+        let numeric = Object::new()
+            .schema_type(SchemaType::Number)
+            .exclusive_minimum(0.0);
+
+        //* When
+        let legacy = serde_json::to_value(&legacy).expect("should serialize schema");
+        let numeric = serde_json::to_value(&numeric).expect("should serialize schema");
+
+        //* Then
+        assert_eq!(
+            legacy,
+            serde_json::json!({ "type": "number", "minimum": 0.0, "exclusiveMinimum": true }),
+            "a bool should serialize as the legacy exclusive flag"
+        );
+        assert_eq!(
+            numeric,
+            serde_json::json!({ "type": "number", "exclusiveMinimum": 0.0 }),
+            "an f64 should serialize as the 2020-12 exclusive bound"
+        );
+        let parsed: Object = serde_json::from_value(numeric).expect("should deserialize schema");
+        assert_eq!(
+            parsed.exclusive_minimum,
+            Some(ExclusiveBound::Value(0.0)),
+            "the numeric form should round-trip"
+        );
+    }
+
+    #[test]
+    fn serialize_one_of_renders_one_of_array() {
+        //* Given
+        let schema = Schema::OneOf(
+            OneOf::new()
+                .item(RefOr::new_ref("#/components/schemas/Cat"))
+                .item(Schema::Object(Box::new(
+                    Object::new().schema_type(SchemaType::String),
+                ))),
+        );
+
+        //* When
+        let value = serde_json::to_value(&schema).expect("should serialize schema");
+
+        //* Then
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "oneOf": [
+                    { "$ref": "#/components/schemas/Cat" },
+                    { "type": "string" }
+                ]
+            }),
+            "oneOf should serialize as an array of schemas"
+        );
+    }
+
+    #[test]
+    fn serialize_all_of_with_two_refs_round_trips() {
+        //* Given
+        let schema = Schema::AllOf(
+            AllOf::new()
+                .item(RefOr::new_ref("#/components/schemas/Base"))
+                .item(RefOr::new_ref("#/components/schemas/Extra")),
+        );
+
+        //* When
+        let value = serde_json::to_value(&schema).expect("should serialize schema");
+        let round_trip: Schema =
+            serde_json::from_value(value.clone()).expect("should deserialize schema");
+
+        //* Then
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "allOf": [
+                    { "$ref": "#/components/schemas/Base" },
+                    { "$ref": "#/components/schemas/Extra" }
+                ]
+            }),
+            "allOf should serialize as an array of schemas"
+        );
+        assert_eq!(round_trip, schema, "allOf should round-trip");
+    }
+
+    #[test]
+    fn serialize_any_of_round_trips() {
+        //* Given
+        let schema = Schema::AnyOf(
+            AnyOf::new()
+                .item(RefOr::new_ref("#/components/schemas/Cat"))
+                .item(Schema::Object(Box::new(
+                    Object::new().schema_type(SchemaType::String),
+                )))
+                .description("A cat or its name"),
+        );
+
+        //* When
+        let value = serde_json::to_value(&schema).expect("should serialize schema");
+        let round_trip: Schema =
+            serde_json::from_value(value.clone()).expect("should deserialize schema");
+
+        //* Then
+        assert_eq!(
+            value["anyOf"].as_array().map(Vec::len),
+            Some(2),
+            "anyOf should serialize as an array of schemas"
+        );
+        assert!(
+            matches!(round_trip, Schema::AnyOf(any_of) if any_of.items.len() == 2),
+            "anyOf should deserialize to the anyOf variant"
+        );
+    }
+
+    #[test]
+    fn deserialize_one_of_reads_one_of_variant() {
+        //* When
+        let schema: Schema = serde_json::from_value(serde_json::json!({
+            "oneOf": [{ "$ref": "#/components/schemas/Cat" }]
+        }))
+        .expect("should deserialize schema");
+
+        //* Then
+        assert_eq!(
+            schema,
+            Schema::OneOf(OneOf::new().item(RefOr::new_ref("#/components/schemas/Cat"))),
+            "oneOf should deserialize to the oneOf variant"
+        );
+    }
+
+    #[test]
+    fn serialize_object_with_boolean_additional_properties_renders_bool() {
+        //* Given
+        let object = Object::new()
+            .schema_type(SchemaType::Object)
+            .additional_properties(AdditionalProperties::from(false));
+
+        //* When
+        let value = serde_json::to_value(&object).expect("should serialize object");
+
+        //* Then
+        assert_eq!(
+            value.get("additionalProperties"),
+            Some(&serde_json::json!(false)),
+            "boolean additional properties should serialize as a bool"
+        );
+    }
+
+    #[test]
+    fn additional_properties_with_none_unsets_previous_value() {
+        //* Given
+        let object = Object::new().additional_properties(AdditionalProperties::from(false));
+
+        //* When
+        let object = object.additional_properties(None);
+
+        //* Then
+        assert_eq!(
+            object.additional_properties, None,
+            "`None` should unset the additional properties"
+        );
+    }
+
+    #[test]
+    fn serialize_object_with_schema_additional_properties_renders_schema() {
+        //* Given
+        let object = Object::new()
+            .schema_type(SchemaType::Object)
+            .additional_properties(Some(AdditionalProperties::from(RefOr::new_ref(
+                "#/components/schemas/Pet",
+            ))));
+
+        //* When
+        let value = serde_json::to_value(&object).expect("should serialize object");
+
+        //* Then
+        assert_eq!(
+            value.get("additionalProperties"),
+            Some(&serde_json::json!({ "$ref": "#/components/schemas/Pet" })),
+            "schema additional properties should serialize as the schema"
+        );
+    }
+
+    #[test]
+    fn deserialize_object_with_additional_properties_reads_both_forms() {
+        //* When
+        let boolean: Object =
+            serde_json::from_value(serde_json::json!({ "additionalProperties": true }))
+                .expect("should deserialize object");
+        let schema: Object = serde_json::from_value(serde_json::json!({
+            "additionalProperties": { "type": "string" }
+        }))
+        .expect("should deserialize object");
+
+        //* Then
+        assert_eq!(
+            boolean.additional_properties,
+            Some(AdditionalProperties::Bool(true)),
+            "boolean additional properties should deserialize to the bool form"
+        );
+        assert!(
+            matches!(
+                schema.additional_properties,
+                Some(AdditionalProperties::Schema(ref values))
+                    if matches!(values.as_ref(), RefOr::T(Schema::Object(obj)) if obj.schema_type == Some(SchemaType::String))
+            ),
+            "schema additional properties should deserialize to the schema form"
+        );
+    }
+
+    #[test]
+    fn serialize_known_schema_format_uses_format_name() {
+        //* When
+        let value = serde_json::to_value(SchemaFormat::DateTime).expect("should serialize format");
+
+        //* Then
+        assert_eq!(
+            value,
+            serde_json::json!("date-time"),
+            "known format should serialize to its name"
+        );
+    }
+
+    #[test]
+    fn serialize_custom_schema_format_uses_raw_string() {
+        //* When
+        let value = serde_json::to_value(SchemaFormat::Custom("semver".to_string()))
+            .expect("should serialize format");
+
+        //* Then
+        assert_eq!(
+            value,
+            serde_json::json!("semver"),
+            "custom format should serialize to its raw string"
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_schema_format_falls_back_to_custom() {
+        //* When
+        let known: SchemaFormat =
+            serde_json::from_value(serde_json::json!("uuid")).expect("should deserialize format");
+        let custom: SchemaFormat =
+            serde_json::from_value(serde_json::json!("semver")).expect("should deserialize format");
+
+        //* Then
+        assert_eq!(
+            known,
+            SchemaFormat::Uuid,
+            "known format should deserialize to its variant"
+        );
+        assert_eq!(
+            custom,
+            SchemaFormat::Custom("semver".to_string()),
+            "unknown format should deserialize to a custom format"
+        );
+    }
+
+    #[test]
+    fn ref_or_accessors_with_ref_return_only_ref_path() {
+        //* Given
+        let mut reference: RefOr<u32> = RefOr::new_ref("#/components/schemas/Pet");
+
+        //* When
+        let ref_path = reference.as_ref_path().map(str::to_string);
+        let value = reference.as_value().copied();
+        let value_mut = reference.as_value_mut().is_some();
+
+        //* Then
+        assert_eq!(
+            ref_path.as_deref(),
+            Some("#/components/schemas/Pet"),
+            "the reference path should be returned"
+        );
+        assert_eq!(value, None, "a reference should have no value");
+        assert!(!value_mut, "a reference should have no mutable value");
+    }
+
+    #[test]
+    fn ref_or_accessors_with_value_return_only_value() {
+        //* Given
+        let mut inline = RefOr::new_inline(1u32);
+
+        //* When
+        if let Some(value) = inline.as_value_mut() {
+            *value += 1;
+        }
+
+        //* Then
+        assert_eq!(
+            inline.as_ref_path(),
+            None,
+            "a value should have no reference path"
+        );
+        assert_eq!(
+            inline.as_value(),
+            Some(&2),
+            "the mutated value should be returned"
+        );
+    }
+
+    #[test]
+    fn ref_or_map_with_ref_keeps_reference() {
+        //* Given
+        let reference: RefOr<u32> = RefOr::new_ref("#/components/schemas/Pet");
+
+        //* When
+        let mapped = reference.map(|value| value.to_string());
+
+        //* Then
+        assert_eq!(
+            mapped,
+            RefOr::new_ref("#/components/schemas/Pet"),
+            "the reference should be kept unchanged"
+        );
+    }
+
+    #[test]
+    fn ref_or_map_with_value_maps_value() {
+        //* Given
+        let inline = RefOr::new_inline(7u32);
+
+        //* When
+        let mapped = inline.map(|value| value.to_string());
+
+        //* Then
+        assert_eq!(
+            mapped,
+            RefOr::T("7".to_string()),
+            "the inline value should be mapped"
+        );
+    }
 }
@@ -0,0 +1,37 @@
+//! Serialization helpers shared by the OpenCLI entities.
+
+use super::{map::Map, response::sort_by_exit_code};
+
+/// Returns whether an optional collection is missing or empty.
+///
+/// Used as a `skip_serializing_if` predicate, so empty lists and maps (e.g., `tags: []` or
+/// `content: {}`) are omitted like missing ones.
+pub(crate) fn is_none_or_empty<C>(collection: &Option<C>) -> bool
+where
+    for<'a> &'a C: IntoIterator,
+{
+    collection
+        .as_ref()
+        .is_none_or(|collection| collection.into_iter().next().is_none())
+}
+
+/// Serializes an optional responses map with its entries ordered by exit code.
+///
+/// Used as a `serialize_with` function, so numeric exit codes are emitted in numeric order
+/// (`"2"` before `"10"`) rather than in the map order. See
+/// [`OrderedResponses`](crate::OrderedResponses).
+pub(crate) fn responses_by_exit_code<R, S>(
+    responses: &Option<Map<String, R>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    R: serde::Serialize,
+    S: serde::Serializer,
+{
+    let Some(responses) = responses else {
+        return serializer.serialize_none();
+    };
+    let mut entries = responses.iter().collect::<Vec<_>>();
+    sort_by_exit_code(&mut entries);
+    serializer.collect_map(entries)
+}
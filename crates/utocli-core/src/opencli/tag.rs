@@ -1,5 +1,7 @@
 //! Tag entity for organizing commands.
 
+use super::ExternalDocs;
+
 /// Organizes commands into logical groups.
 ///
 /// Tags allow grouping of commands for better organization and documentation.
@@ -11,6 +13,13 @@ pub struct Tag {
     /// A description for the tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// External documentation for the tag.
+    ///
+    /// The OpenCLI specification only defines `externalDocs` at the root, so it is serialized
+    /// as the `x-externalDocs` extension.
+    #[serde(rename = "x-externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocs>,
 }
 
 impl Tag {
@@ -19,6 +28,7 @@ impl Tag {
         Self {
             name: name.into(),
             description: None,
+            external_docs: None,
         }
     }
 
@@ -27,4 +37,10 @@ impl Tag {
         self.description = Some(description.into());
         self
     }
+
+    /// Sets the external documentation for the tag.
+    pub fn external_docs(mut self, external_docs: ExternalDocs) -> Self {
+        self.external_docs = Some(external_docs);
+        self
+    }
 }
@@ -0,0 +1,714 @@
+//! Validation of OpenCLI specifications.
+
+use std::fmt;
+
+use super::{OpenCli, ParameterIn, RefOr, Schema, map::Map};
+use crate::Error;
+
+/// An error reported when validating an OpenCLI specification.
+///
+/// Unlike [`Lint`](super::Lint) warnings, validation errors describe specifications that
+/// break consumers, such as code generators.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// The same `operationId` is declared by more than one command.
+    DuplicateOperationId {
+        /// The repeated operation id.
+        operation_id: String,
+        /// The paths of the commands declaring the operation id.
+        commands: Vec<String>,
+    },
+    /// A command has no (or an empty) `operationId`.
+    ///
+    /// Only reported by [`OpenCli::validate_strict`].
+    MissingOperationId {
+        /// The path of the command.
+        command: String,
+    },
+    /// A parameter `x-requires` or `x-conflicts` relationship names a parameter the command
+    /// does not have.
+    UnknownRelatedParameter {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter declaring the relationship.
+        parameter: String,
+        /// The unknown parameter name.
+        related: String,
+    },
+    /// A required parameter declares a schema default value, which could never be used.
+    RequiredParameterWithDefault {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// An environment parameter declares a position, which only arguments can have.
+    PositionalEnvironmentParameter {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// A positional parameter declares aliases, although positionals are not named on the
+    /// command line.
+    PositionalParameterWithAlias {
+        /// The path of the command.
+        command: String,
+        /// The name of the parameter.
+        parameter: String,
+    },
+    /// An external documentation link has an empty or malformed URL.
+    ///
+    /// The URL is only checked to be non-empty and to contain a `://` scheme separator.
+    InvalidExternalDocsUrl {
+        /// Where the external documentation is declared, e.g. `externalDocs`,
+        /// `commands.<command>.x-externalDocs` or `tags.<tag>.x-externalDocs`.
+        location: String,
+        /// The invalid URL.
+        url: String,
+    },
+    /// The contact email is malformed.
+    ///
+    /// The email is only checked to contain an `@` separating non-empty parts.
+    InvalidContactEmail {
+        /// The invalid email.
+        email: String,
+    },
+    /// A command references a tag that is not declared in the top-level `tags` list.
+    UndeclaredTag {
+        /// The path of the command.
+        command: String,
+        /// The undeclared tag name.
+        tag: String,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::DuplicateOperationId {
+                operation_id,
+                commands,
+            } => write!(
+                f,
+                "operationId `{operation_id}` is declared by multiple commands: {}",
+                commands.join(", ")
+            ),
+            ValidationError::MissingOperationId { command } => {
+                write!(f, "command `{command}` has no operationId")
+            }
+            ValidationError::UnknownRelatedParameter {
+                command,
+                parameter,
+                related,
+            } => write!(
+                f,
+                "parameter `{parameter}` of command `{command}` relates to unknown parameter `{related}`"
+            ),
+            ValidationError::RequiredParameterWithDefault { command, parameter } => write!(
+                f,
+                "required parameter `{parameter}` of command `{command}` has a default value"
+            ),
+            ValidationError::PositionalEnvironmentParameter { command, parameter } => write!(
+                f,
+                "environment parameter `{parameter}` of command `{command}` has a position"
+            ),
+            ValidationError::PositionalParameterWithAlias { command, parameter } => write!(
+                f,
+                "positional parameter `{parameter}` of command `{command}` has aliases"
+            ),
+            ValidationError::InvalidExternalDocsUrl { location, url } => {
+                write!(
+                    f,
+                    "external documentation `{location}` has an invalid URL `{url}`"
+                )
+            }
+            ValidationError::InvalidContactEmail { email } => {
+                write!(f, "contact email `{email}` is invalid")
+            }
+            ValidationError::UndeclaredTag { command, tag } => {
+                write!(f, "command `{command}` references undeclared tag `{tag}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl OpenCli {
+    /// Validates the specification, returning every error found as an [`Error::Validation`].
+    ///
+    /// Reports duplicate operation ids across commands, parameter relationships naming unknown
+    /// parameters, required parameters with a default value, environment parameters
+    /// declaring a position, positional parameters declaring aliases, external documentation
+    /// links with an invalid URL, a malformed contact email and command tags missing from the
+    /// top-level `tags` list.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.check(false)
+    }
+
+    /// Validates the specification like [`OpenCli::validate`], additionally requiring every
+    /// command to declare a non-empty operation id.
+    pub fn validate_strict(&self) -> Result<(), Error> {
+        self.check(true)
+    }
+
+    fn check(&self, strict: bool) -> Result<(), Error> {
+        let mut errors = check_operation_ids(self, strict);
+        errors.extend(check_related_parameters(self));
+        errors.extend(check_required_defaults(self));
+        errors.extend(check_environment_parameters(self));
+        errors.extend(check_positional_aliases(self));
+        errors.extend(check_external_docs(self));
+        errors.extend(check_contact_email(self));
+        errors.extend(check_command_tags(self));
+        into_result(errors)
+    }
+}
+
+fn into_result(errors: Vec<ValidationError>) -> Result<(), Error> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Validation(errors))
+    }
+}
+
+/// Detects operation ids shared by several commands and, in strict mode, missing ones.
+fn check_operation_ids(opencli: &OpenCli, strict: bool) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let mut operation_ids: Map<String, Vec<String>> = Map::new();
+
+    for (command_path, command) in &opencli.commands {
+        match command.operation_id.as_deref() {
+            Some(operation_id) if !operation_id.is_empty() => operation_ids
+                .entry(operation_id.to_string())
+                .or_default()
+                .push(command_path.clone()),
+            _ if strict => errors.push(ValidationError::MissingOperationId {
+                command: command_path.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    errors.extend(
+        operation_ids
+            .into_iter()
+            .filter(|(_, commands)| commands.len() > 1)
+            .map(
+                |(operation_id, commands)| ValidationError::DuplicateOperationId {
+                    operation_id,
+                    commands,
+                },
+            ),
+    );
+    errors
+}
+
+/// Detects `x-requires` and `x-conflicts` relationships naming parameters that are not
+/// available to the command, including those inherited from its ancestors.
+fn check_related_parameters(opencli: &OpenCli) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (command_path, command) in &opencli.commands {
+        let available = opencli.effective_parameters(command_path);
+        for parameter in opencli.command_parameters(command) {
+            let related = parameter
+                .requires
+                .iter()
+                .chain(&parameter.conflicts)
+                .flatten();
+            errors.extend(
+                related
+                    .filter(|related| !available.iter().any(|p| &p.name == *related))
+                    .map(|related| ValidationError::UnknownRelatedParameter {
+                        command: command_path.clone(),
+                        parameter: parameter.name.clone(),
+                        related: related.clone(),
+                    }),
+            );
+        }
+    }
+
+    errors
+}
+
+/// Detects required parameters whose schema declares a (non-null) default value.
+fn check_required_defaults(opencli: &OpenCli) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (command_path, command) in &opencli.commands {
+        errors.extend(
+            opencli
+                .command_parameters(command)
+                .filter(|parameter| parameter.required == Some(true))
+                .filter(|parameter| match &parameter.schema {
+                    Some(RefOr::T(Schema::Object(object))) => object
+                        .default
+                        .as_ref()
+                        .is_some_and(|default| !default.is_null()),
+                    _ => false,
+                })
+                .map(|parameter| ValidationError::RequiredParameterWithDefault {
+                    command: command_path.clone(),
+                    parameter: parameter.name.clone(),
+                }),
+        );
+    }
+
+    errors
+}
+
+/// Detects environment parameters declaring a position.
+fn check_environment_parameters(opencli: &OpenCli) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (command_path, command) in &opencli.commands {
+        errors.extend(
+            opencli
+                .command_parameters(command)
+                .filter(|parameter| parameter.in_ == Some(ParameterIn::Environment))
+                .filter(|parameter| parameter.position.is_some())
+                .map(
+                    |parameter| ValidationError::PositionalEnvironmentParameter {
+                        command: command_path.clone(),
+                        parameter: parameter.name.clone(),
+                    },
+                ),
+        );
+    }
+
+    errors
+}
+
+/// Detects positional parameters declaring a non-empty alias list.
+fn check_positional_aliases(opencli: &OpenCli) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (command_path, command) in &opencli.commands {
+        errors.extend(
+            opencli
+                .command_parameters(command)
+                .filter(|parameter| parameter.position.is_some())
+                .filter(|parameter| parameter.alias.as_ref().is_some_and(|a| !a.is_empty()))
+                .map(|parameter| ValidationError::PositionalParameterWithAlias {
+                    command: command_path.clone(),
+                    parameter: parameter.name.clone(),
+                }),
+        );
+    }
+
+    errors
+}
+
+/// Detects external documentation links whose URL is empty or lacks a scheme.
+fn check_external_docs(opencli: &OpenCli) -> Vec<ValidationError> {
+    let root = opencli
+        .external_docs
+        .iter()
+        .map(|docs| ("externalDocs".to_string(), docs));
+    let commands = opencli
+        .commands
+        .iter()
+        .filter_map(|(command_path, command)| {
+            let docs = command.external_docs.as_ref()?;
+            Some((format!("commands.{command_path}.x-externalDocs"), docs))
+        });
+    let tags = opencli.tags.iter().flatten().filter_map(|tag| {
+        let docs = tag.external_docs.as_ref()?;
+        Some((format!("tags.{}.x-externalDocs", tag.name), docs))
+    });
+
+    root.chain(commands)
+        .chain(tags)
+        .filter(|(_, docs)| !is_valid_url(&docs.url))
+        .map(|(location, docs)| ValidationError::InvalidExternalDocsUrl {
+            location,
+            url: docs.url.clone(),
+        })
+        .collect()
+}
+
+/// Detects a contact email without an `@` separating non-empty parts.
+fn check_contact_email(opencli: &OpenCli) -> Vec<ValidationError> {
+    let email = opencli
+        .info
+        .contact
+        .as_ref()
+        .and_then(|contact| contact.email.as_ref());
+
+    email
+        .filter(|email| {
+            !email
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty())
+        })
+        .map(|email| ValidationError::InvalidContactEmail {
+            email: email.clone(),
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Detects command tags that are not declared in the top-level `tags` list.
+fn check_command_tags(opencli: &OpenCli) -> Vec<ValidationError> {
+    let declared = opencli.tags.iter().flatten().map(|tag| tag.name.as_str());
+    let declared: Vec<&str> = declared.collect();
+
+    opencli
+        .commands
+        .iter()
+        .flat_map(|(command_path, command)| {
+            command
+                .tags
+                .iter()
+                .flatten()
+                .filter(|tag| !declared.contains(&tag.as_str()))
+                .map(|tag| ValidationError::UndeclaredTag {
+                    command: command_path.clone(),
+                    tag: tag.clone(),
+                })
+        })
+        .collect()
+}
+
+/// Lightweight URL check: a non-empty scheme followed by `://` and a non-empty remainder.
+fn is_valid_url(url: &str) -> bool {
+    url.trim()
+        .split_once("://")
+        .is_some_and(|(scheme, rest)| !scheme.is_empty() && !rest.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opencli::{
+        Command, Commands, Contact, ExternalDocs, Info, Object, Parameter, SchemaType, Tag,
+    };
+
+    #[test]
+    fn validate_with_shared_operation_id_reports_duplicate() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert("run".to_string(), Command::new().operation_id("run"));
+        commands.insert("exec".to_string(), Command::new().operation_id("run"));
+        commands.insert("build".to_string(), Command::new().operation_id("build"));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        let Err(Error::Validation(errors)) = result else {
+            panic!("duplicate operation ids should be reported, got {result:?}");
+        };
+        assert_eq!(errors.len(), 1, "should report the duplicated id once");
+        let ValidationError::DuplicateOperationId {
+            operation_id,
+            commands,
+        } = &errors[0]
+        else {
+            panic!(
+                "expected a duplicate operation id error, got {:?}",
+                errors[0]
+            );
+        };
+        assert_eq!(operation_id, "run", "should report the repeated id");
+        let mut commands = commands.clone();
+        commands.sort();
+        assert_eq!(
+            commands,
+            vec!["exec".to_string(), "run".to_string()],
+            "should report every command declaring the id"
+        );
+    }
+
+    #[test]
+    fn validate_with_unique_operation_ids_succeeds() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert("run".to_string(), Command::new().operation_id("run"));
+        commands.insert("build".to_string(), Command::new().operation_id("build"));
+        commands.insert("help".to_string(), Command::new());
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            result.is_ok(),
+            "unique and missing operation ids should be valid, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_strict_with_missing_operation_id_reports_command() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert("run".to_string(), Command::new().operation_id("run"));
+        commands.insert("help".to_string(), Command::new().operation_id(""));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate_strict();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::MissingOperationId {
+                    command: "help".to_string()
+                }]
+            ),
+            "strict mode should report the command without operation id, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_positional_environment_parameter_reports_parameter() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "deploy".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_environment("DEPLOY_TOKEN"),
+                Parameter::new_environment("DEPLOY_REGION").position(1),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::PositionalEnvironmentParameter {
+                    command: "deploy".to_string(),
+                    parameter: "DEPLOY_REGION".to_string(),
+                }]
+            ),
+            "only the positional environment parameter should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_referenced_positional_environment_parameter_reports_parameter() {
+        //* Given
+        use crate::opencli::{Components, Map};
+
+        let components = Components::new().parameters(Map::from_iter([(
+            "DeployRegion".to_string(),
+            RefOr::T(Parameter::new_environment("DEPLOY_REGION").position(1)),
+        )]));
+        let mut commands = Commands::new();
+        commands.insert(
+            "deploy".to_string(),
+            Command::new().parameters([RefOr::new_ref("#/components/parameters/DeployRegion")]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"))
+            .components(components)
+            .commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::PositionalEnvironmentParameter {
+                    command: "deploy".to_string(),
+                    parameter: "DEPLOY_REGION".to_string(),
+                }]
+            ),
+            "the referenced positional environment parameter should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_aliased_positional_parameter_reports_parameter() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "copy".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_argument("source", 1).alias(vec!["s".to_string()]),
+                Parameter::new_argument("target", 2).alias(Vec::new()),
+                Parameter::new_option("mode").alias(vec!["m".to_string()]),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::PositionalParameterWithAlias {
+                    command: "copy".to_string(),
+                    parameter: "source".to_string(),
+                }]
+            ),
+            "only the aliased positional parameter should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_unknown_conflicting_parameter_reports_relationship() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "export".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_flag("json").conflicts(vec!["yaml".to_string()]),
+                Parameter::new_flag("yaml").conflicts(vec!["json".to_string()]),
+                Parameter::new_option("output").requires(vec!["format".to_string()]),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::UnknownRelatedParameter {
+                    command: "export".to_string(),
+                    parameter: "output".to_string(),
+                    related: "format".to_string(),
+                }]
+            ),
+            "only the relationship to the missing parameter should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_required_defaulted_argument_reports_parameter() {
+        //* Given
+        let defaulted = || {
+            RefOr::T(Schema::Object(Box::new(
+                Object::new()
+                    .schema_type(SchemaType::String)
+                    .default_value(serde_json::json!("opencli.yaml")),
+            )))
+        };
+        let mut commands = Commands::new();
+        commands.insert(
+            "validate".to_string(),
+            Command::new().parameters(vec![
+                Parameter::new_argument("file", 1).schema(defaulted()),
+                Parameter::new_option("output").schema(defaulted()),
+            ]),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0")).commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::RequiredParameterWithDefault {
+                    command: "validate".to_string(),
+                    parameter: "file".to_string(),
+                }]
+            ),
+            "only the required defaulted argument should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_empty_external_docs_url_reports_location() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert(
+            "run".to_string(),
+            Command::new().external_docs(ExternalDocs::new("")),
+        );
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"))
+            .external_docs(ExternalDocs::new("https://example.com/docs"))
+            .tags(vec![
+                Tag::new("build").external_docs(ExternalDocs::new("example.com/build")),
+            ])
+            .commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[
+                    ValidationError::InvalidExternalDocsUrl {
+                        location: "commands.run.x-externalDocs".to_string(),
+                        url: String::new(),
+                    },
+                    ValidationError::InvalidExternalDocsUrl {
+                        location: "tags.build.x-externalDocs".to_string(),
+                        url: "example.com/build".to_string(),
+                    },
+                ]
+            ),
+            "empty and scheme-less external docs URLs should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_undeclared_command_tag_reports_tag() {
+        //* Given
+        let mut commands = Commands::new();
+        commands.insert("build".to_string(), Command::new().tag("core"));
+        commands.insert("run".to_string(), Command::new().tag("core").tag("cor"));
+        let opencli = OpenCli::new(Info::new("app", "1.0.0"))
+            .tags(vec![Tag::new("core")])
+            .commands(commands);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[ValidationError::UndeclaredTag {
+                    command: "run".to_string(),
+                    tag: "cor".to_string(),
+                }]
+            ),
+            "only the undeclared tag should be reported, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn validate_with_contact_email_missing_at_reports_email() {
+        //* Given
+        let info = Info::new("app", "1.0.0").contact(Contact::new().email("jane.example.com"));
+        let opencli = OpenCli::new(info);
+
+        //* When
+        let result = opencli.validate();
+
+        //* Then
+        assert!(
+            matches!(
+                result,
+                Err(Error::Validation(ref errors)) if errors == &[
+                    ValidationError::InvalidContactEmail {
+                        email: "jane.example.com".to_string(),
+                    },
+                ]
+            ),
+            "the email without an `@` should be reported, got {result:?}"
+        );
+    }
+}
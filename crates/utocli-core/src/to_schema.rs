@@ -25,6 +25,25 @@ pub trait ToSchema {
     ///
     /// The name is used for referencing this schema in the OpenCLI document.
     fn schema_name() -> &'static str;
+
+    /// Whether the schema is inlined wherever it is referenced instead of being registered as
+    /// a component.
+    ///
+    /// Set by the `#[schema(inline)]` container attribute. The `OpenCli` derive replaces the
+    /// references to such schemas with the schema itself (see [`OpenCli::inline_schema`]).
+    ///
+    /// [`OpenCli::inline_schema`]: crate::opencli::OpenCli::inline_schema
+    fn inline() -> bool {
+        false
+    }
+
+    /// The names of the schema components referenced by the schema through `$ref`.
+    ///
+    /// Only direct references are listed, in order of appearance and without duplicates;
+    /// transitive dependencies are gathered by following the references of each listed schema.
+    fn schema_references() -> Vec<&'static str> {
+        Vec::new()
+    }
 }
 
 // Implement ToSchema for primitive types
@@ -7,50 +7,83 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    Ident, ItemFn, Lit, Result as SynResult, Token,
+    Ident, ItemFn, Lit, LitStr, Result as SynResult, Token,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
 };
 
-use crate::{diagnostics::Diagnostics, doc_comment::parse_doc_comments};
+use crate::{
+    diagnostics::Diagnostics,
+    doc_comment::{parse_doc_comments, split_summary_description},
+    opencli::ExternalDocsDef,
+    parse_utils,
+};
 
 /// Parsed command attributes from `#[command(...)]`.
 #[derive(Default)]
 struct CommandAttributes {
     name: Option<String>,
+    /// Suffix stripped from the function name when no name is given, `_command` by default
+    strip_suffix: Option<String>,
+    parent: Option<String>,
+    /// Set when the command is marked as the root command of a binary
+    root: Option<Ident>,
     summary: Option<String>,
     description: Option<String>,
+    /// Set when a single-paragraph doc comment becomes the description instead of the summary
+    about_as_description: Option<Ident>,
     operation_id: Option<String>,
     aliases: Vec<String>,
     tags: Vec<String>,
     parameters: Vec<ParameterDef>,
     responses: Vec<ResponseDef>,
+    external_docs: Option<ExternalDocsDef>,
+    examples: Vec<ExampleDef>,
     extensions: Vec<(String, String)>,
+    /// Set when the command links back to its function through the `x-source` extension
+    emit_source: Option<Ident>,
 }
 
 #[derive(Clone, Default)]
 struct ParameterDef {
     name: String,
+    component_ref: Option<String>,
     in_: Option<String>, // None means use default (option)
     position: Option<u32>,
     description: Option<String>,
     required: bool,
+    deprecated: bool,
+    hidden: bool,
     scope: String,
+    /// Set when the parameter is declared `global`, shorthand for the inherited scope
+    global: Option<Ident>,
     schema_type: String,
     schema_format: Option<String>,
+    schema_ref: Option<String>,
+    /// Schema type whose component holds the allowed values
+    possible_values_from: Option<syn::TypePath>,
+    pattern: Option<String>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
     enum_values: Vec<String>,
     default_value: Option<String>,
     example: Option<String>,
     arity_min: Option<u32>,
     arity_max: Option<u32>,
     alias: Vec<String>,
+    requires: Vec<String>,
+    conflicts: Vec<String>,
+    value_name: Option<String>,
+    section: Option<String>,
+    default_missing_value: Option<String>,
+    flag_style: Option<TokenStream>,
     extensions: Vec<(String, String)>,
 }
 
 impl Parse for ParameterDef {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: name, in, position, description, required, scope, schema_type, schema_format, enum_values, default, example, arity_min, arity_max, alias, extend";
+        const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: name, ref, in, position, description, required, deprecated, hidden, scope, global, schema_type, schema_format, schema_ref, possible_values_from, pattern, min_length, max_length, enum_values, default, example, arity_min, arity_max, alias, requires, conflicts, value_name, section, default_missing_value, flag_style, extend";
 
         let mut param = ParameterDef {
             required: false,                   // default
@@ -62,24 +95,34 @@ impl Parse for ParameterDef {
         // Parse parameter tuple: (name = "file", in = "argument", ...)
         let content;
         syn::parenthesized!(content in input);
+        let mut in_span = None;
+        // The first attribute other than `ref`, which a component reference cannot be combined with
+        let mut inline_attribute = None;
 
         while !content.is_empty() {
-            // Check for the 'in' keyword first (it's a Rust keyword)
+            let attribute_span = content.span();
+            // Check for the 'in' and 'ref' keywords first (they are Rust keywords)
             let attribute_name = if content.peek(Token![in]) {
                 content.parse::<Token![in]>()?;
                 "in".to_string()
+            } else if content.peek(Token![ref]) {
+                content.parse::<Token![ref]>()?;
+                "ref".to_string()
             } else {
                 let ident = content.parse::<Ident>().map_err(|error| -> syn::Error {
                     Diagnostics::with_span(
                         error.span(),
                         format!("{EXPECTED_ATTRIBUTE}, {error}"),
                     )
-                    .help("Valid parameter attributes: name, in, position, description, required, alias, extend")
+                    .help("Valid parameter attributes: name, ref, in, position, description, required, alias, extend")
                     .note("Example: (name = \"file\", in = \"argument\", position = 1)")
                     .into()
                 })?;
                 ident.to_string()
             };
+            if attribute_name != "ref" && inline_attribute.is_none() {
+                inline_attribute = Some((attribute_name.clone(), attribute_span));
+            }
 
             match attribute_name.as_str() {
                 "name" => {
@@ -93,9 +136,17 @@ impl Parse for ParameterDef {
                     content.parse::<Token![=]>()?;
                     let lit: Lit = content.parse()?;
                     if let Lit::Str(s) = lit {
+                        in_span = Some(s.span());
                         param.in_ = Some(s.value());
                     }
                 }
+                "ref" => {
+                    content.parse::<Token![=]>()?;
+                    let lit: Lit = content.parse()?;
+                    if let Lit::Str(s) = lit {
+                        param.component_ref = Some(s.value());
+                    }
+                }
                 "position" => {
                     content.parse::<Token![=]>()?;
                     let lit: Lit = content.parse()?;
@@ -117,6 +168,36 @@ impl Parse for ParameterDef {
                         param.required = b.value();
                     }
                 }
+                "deprecated" => {
+                    // Parse deprecated: deprecated or deprecated = true
+                    if content.peek(Token![=]) {
+                        content.parse::<Token![=]>()?;
+                        let lit: Lit = content.parse()?;
+                        let Lit::Bool(b) = lit else {
+                            return Err(Diagnostics::with_span(
+                                lit.span(),
+                                "expected `deprecated` or `deprecated = <bool>`",
+                            )
+                            .help("Deprecation notes are not supported, document the note in the parameter description")
+                            .into());
+                        };
+                        param.deprecated = b.value();
+                    } else {
+                        param.deprecated = true;
+                    }
+                }
+                "hidden" => {
+                    // Parse hidden: hidden or hidden = true
+                    if content.peek(Token![=]) {
+                        content.parse::<Token![=]>()?;
+                        let lit: Lit = content.parse()?;
+                        if let Lit::Bool(b) = lit {
+                            param.hidden = b.value();
+                        }
+                    } else {
+                        param.hidden = true;
+                    }
+                }
                 "scope" => {
                     content.parse::<Token![=]>()?;
                     let lit: Lit = content.parse()?;
@@ -124,6 +205,20 @@ impl Parse for ParameterDef {
                         param.scope = s.value();
                     }
                 }
+                "global" => {
+                    // Parse global: global or global = true (clap's global args are inherited)
+                    let global = if content.peek(Token![=]) {
+                        content.parse::<Token![=]>()?;
+                        let lit: Lit = content.parse()?;
+                        matches!(lit, Lit::Bool(b) if b.value())
+                    } else {
+                        true
+                    };
+                    if global {
+                        param.scope = "inherited".to_string();
+                        param.global = Some(Ident::new("global", attribute_span));
+                    }
+                }
                 "schema_type" => {
                     content.parse::<Token![=]>()?;
                     let lit: Lit = content.parse()?;
@@ -138,6 +233,38 @@ impl Parse for ParameterDef {
                         param.schema_format = Some(s.value());
                     }
                 }
+                "schema_ref" => {
+                    content.parse::<Token![=]>()?;
+                    let lit: Lit = content.parse()?;
+                    if let Lit::Str(s) = lit {
+                        param.schema_ref = Some(s.value());
+                    }
+                }
+                "possible_values_from" => {
+                    content.parse::<Token![=]>()?;
+                    param.possible_values_from = Some(content.parse()?);
+                }
+                "pattern" => {
+                    content.parse::<Token![=]>()?;
+                    let lit: Lit = content.parse()?;
+                    if let Lit::Str(s) = lit {
+                        param.pattern = Some(s.value());
+                    }
+                }
+                "min_length" => {
+                    content.parse::<Token![=]>()?;
+                    let lit: Lit = content.parse()?;
+                    if let Lit::Int(i) = lit {
+                        param.min_length = Some(i.base10_parse()?);
+                    }
+                }
+                "max_length" => {
+                    content.parse::<Token![=]>()?;
+                    let lit: Lit = content.parse()?;
+                    if let Lit::Int(i) = lit {
+                        param.max_length = Some(i.base10_parse()?);
+                    }
+                }
                 "default" => {
                     content.parse::<Token![=]>()?;
                     let lit: Lit = content.parse()?;
@@ -168,6 +295,37 @@ impl Parse for ParameterDef {
                         }
                     }
                 }
+                "value_name" => {
+                    content.parse::<Token![=]>()?;
+                    param.value_name = Some(content.parse::<LitStr>()?.value());
+                }
+                "section" => {
+                    content.parse::<Token![=]>()?;
+                    param.section = Some(content.parse::<LitStr>()?.value());
+                }
+                "default_missing_value" => {
+                    content.parse::<Token![=]>()?;
+                    param.default_missing_value = Some(content.parse::<LitStr>()?.value());
+                }
+                "flag_style" => {
+                    content.parse::<Token![=]>()?;
+                    param.flag_style = Some(crate::parameter::flag_style_tokens(
+                        &content.parse::<LitStr>()?,
+                    )?);
+                }
+                "requires" | "conflicts" => {
+                    // Parse related parameter names: requires("format") or conflicts("yaml")
+                    let names_content;
+                    syn::parenthesized!(names_content in content);
+                    let items: Punctuated<LitStr, Comma> = names_content
+                        .parse_terminated(|input| input.parse::<LitStr>(), Token![,])?;
+                    let names = items.iter().map(LitStr::value);
+                    if attribute_name == "requires" {
+                        param.requires.extend(names);
+                    } else {
+                        param.conflicts.extend(names);
+                    }
+                }
                 "enum_values" => {
                     // Parse enum_values("json", "yaml", "text")
                     let enum_content;
@@ -203,24 +361,9 @@ impl Parse for ParameterDef {
                 }
                 "extend" => {
                     // Parse extensions: extend(x_completion = "file")
-                    let ext_content;
-                    syn::parenthesized!(ext_content in content);
-                    while !ext_content.is_empty() {
-                        let key: Ident = ext_content.parse()?;
-                        ext_content.parse::<Token![=]>()?;
-                        let value: Lit = ext_content.parse()?;
-                        if let Lit::Str(s) = value {
-                            let ext_key = if key.to_string().starts_with("x_") {
-                                key.to_string().replace('_', "-")
-                            } else {
-                                format!("x-{}", key.to_string().replace('_', "-"))
-                            };
-                            param.extensions.push((ext_key, s.value()));
-                        }
-                        if !ext_content.is_empty() {
-                            ext_content.parse::<Token![,]>()?;
-                        }
-                    }
+                    param
+                        .extensions
+                        .extend(parse_utils::parse_extensions(&content)?);
                 }
                 _ => {
                     return Err(Diagnostics::with_span(content.span(), EXPECTED_ATTRIBUTE)
@@ -236,6 +379,43 @@ impl Parse for ParameterDef {
             }
         }
 
+        if param.component_ref.is_some()
+            && let Some((attribute, span)) = inline_attribute
+        {
+            return Err(Diagnostics::with_span(
+                span,
+                format!("`{attribute}` cannot be combined with `ref`"),
+            )
+            .help("Remove the attribute, or declare the parameter inline instead of referencing a component")
+            .note("A `ref` parameter is entirely defined by the referenced parameter component")
+            .into());
+        }
+
+        if let Some(global) = &param.global
+            && param.position.is_some()
+        {
+            return Err(Diagnostics::with_span(
+                global.span(),
+                "global parameters cannot be positional",
+            )
+            .help("Remove `position` or declare the parameter as a flag or option")
+            .note("Global parameters are inherited by subcommands, which have their own positional arguments")
+            .into());
+        }
+
+        if let Some(in_span) = in_span
+            && param.in_.as_deref() == Some("environment")
+            && param.position.is_some()
+        {
+            return Err(Diagnostics::with_span(
+                in_span,
+                "environment parameters cannot be positional",
+            )
+            .help("Remove `position` or declare the parameter as an argument")
+            .note("Environment parameters are read from the environment variable named after the parameter")
+            .into());
+        }
+
         Ok(param)
     }
 }
@@ -272,11 +452,10 @@ impl Parse for ResponseDef {
 
             match attribute_name {
                 "status" => {
+                    // Accepts integer (`status = 1`) and string (`status = "1-125"`) literals
                     content.parse::<Token![=]>()?;
-                    let lit: Lit = content.parse()?;
-                    if let Lit::Str(s) = lit {
-                        response.status = s.value();
-                    }
+                    let status: crate::response::ResponseStatus = content.parse()?;
+                    response.status = status.value().unwrap_or_default();
                 }
                 "description" => {
                     content.parse::<Token![=]>()?;
@@ -311,18 +490,83 @@ impl Parse for ResponseDef {
     }
 }
 
+/// An example invocation: `(command = "...", description = "...", output = "...")`.
+#[derive(Clone, Default)]
+struct ExampleDef {
+    command: String,
+    description: Option<String>,
+    output: Option<String>,
+}
+
+impl Parse for ExampleDef {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected attribute, expected any of: command, description, output";
+
+        let content;
+        let paren = syn::parenthesized!(content in input);
+
+        let mut command: Option<String> = None;
+        let mut example = ExampleDef::default();
+
+        while !content.is_empty() {
+            let ident = content.parse::<Ident>().map_err(|error| -> syn::Error {
+                Diagnostics::with_span(error.span(), format!("{EXPECTED_ATTRIBUTE}, {error}"))
+                    .help("Valid example attributes: command, description, output")
+                    .into()
+            })?;
+
+            match &*ident.to_string() {
+                "command" => {
+                    content.parse::<Token![=]>()?;
+                    command = Some(content.parse::<LitStr>()?.value());
+                }
+                "description" => {
+                    content.parse::<Token![=]>()?;
+                    example.description = Some(content.parse::<LitStr>()?.value());
+                }
+                "output" => {
+                    content.parse::<Token![=]>()?;
+                    example.output = Some(content.parse::<LitStr>()?.value());
+                }
+                _ => {
+                    return Err(Diagnostics::with_span(ident.span(), EXPECTED_ATTRIBUTE)
+                        .help("Valid example attributes: command, description, output")
+                        .into());
+                }
+            }
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        let Some(command) = command else {
+            return Err(Diagnostics::with_span(
+                paren.span.join(),
+                "missing expected `command` example attribute",
+            )
+            .help("Give the invocation: (command = \"ocs validate spec.yaml --strict\")")
+            .into());
+        };
+
+        Ok(ExampleDef { command, ..example })
+    }
+}
+
 #[derive(Clone, Default)]
 struct ContentDef {
     media_type: String,
     schema_ref: Option<String>,
     example: Option<String>,
+    /// The file the example was read from, tracked for rebuilds
+    example_file: Option<LitStr>,
     inline_props: Vec<(String, String)>, // (property_name, property_type)
 }
 
 impl Parse for ContentDef {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: media_type, schema, example, inline_properties";
+        const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: media_type, schema, example, example_file, inline_properties";
         let mut content = ContentDef::default();
 
         // Parse content tuple: (media_type = "application/json", schema = "...", example = "...")
@@ -335,7 +579,7 @@ impl Parse for ContentDef {
                 .map_err(|error| -> syn::Error {
                     Diagnostics::with_span(error.span(), format!("{EXPECTED_ATTRIBUTE}, {error}"))
                     .help(
-                        "Valid content attributes: media_type, schema, example, inline_properties",
+                        "Valid content attributes: media_type, schema, example, example_file, inline_properties",
                     )
                     .note("Example: (media_type = \"application/json\", schema = \"OutputSchema\")")
                     .into()
@@ -364,6 +608,12 @@ impl Parse for ContentDef {
                         content.example = Some(s.value());
                     }
                 }
+                "example_file" => {
+                    content_inner.parse::<Token![=]>()?;
+                    let path: LitStr = content_inner.parse()?;
+                    content.example = Some(parse_utils::read_example_file(&path)?);
+                    content.example_file = Some(path);
+                }
                 "inline_properties" => {
                     // Parse inline_properties(("prop1", "type1"), ("prop2", "type2"))
                     let props_content;
@@ -424,7 +674,7 @@ impl Parse for ContentDef {
 /// Parser for command attributes
 impl Parse for CommandAttributes {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: name, summary, description, operation_id, aliases, tags, parameters, responses, extend";
+        const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: name, strip_suffix, parent, root, summary, description, about_as_description, operation_id, aliases, tags, parameters, responses, external_docs, examples, extend, emit_source";
         let mut attrs = CommandAttributes::default();
 
         while !input.is_empty() {
@@ -433,7 +683,7 @@ impl Parse for CommandAttributes {
                     error.span(),
                     format!("{EXPECTED_ATTRIBUTE}, {error}"),
                 )
-                .help("Valid command attributes: name, summary, description, operation_id, aliases, tags, parameters, responses, extend")
+                .help("Valid command attributes: name, strip_suffix, parent, root, summary, description, about_as_description, operation_id, aliases, tags, parameters, responses, external_docs, examples, extend, emit_source")
                 .note("Example: #[command(name = \"build\", summary = \"Build the project\")]")
                 .into()
             })?;
@@ -444,9 +694,30 @@ impl Parse for CommandAttributes {
                     input.parse::<Token![=]>()?;
                     let lit: Lit = input.parse()?;
                     if let Lit::Str(s) = lit {
+                        validate_command_name(&s.value(), s.span())?;
                         attrs.name = Some(s.value());
                     }
                 }
+                "strip_suffix" => {
+                    input.parse::<Token![=]>()?;
+                    attrs.strip_suffix = Some(input.parse::<LitStr>()?.value());
+                }
+                "parent" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: Lit = input.parse()?;
+                    if let Lit::Str(s) = lit {
+                        attrs.parent = Some(s.value());
+                    }
+                }
+                "root" => {
+                    attrs.root = Some(ident.clone());
+                }
+                "emit_source" => {
+                    attrs.emit_source = Some(ident.clone());
+                }
+                "about_as_description" => {
+                    attrs.about_as_description = Some(ident.clone());
+                }
                 "summary" => {
                     input.parse::<Token![=]>()?;
                     let lit: Lit = input.parse()?;
@@ -492,26 +763,23 @@ impl Parse for CommandAttributes {
                         }
                     }
                 }
-                "extend" => {
-                    // Parse extensions: extend(x_cli_category = "validation")
+                "external_docs" => {
+                    // Parse external docs: external_docs(url = "...", description = "...")
+                    attrs.external_docs = Some(input.parse()?);
+                }
+                "examples" => {
+                    // Parse examples: examples((command = "...", description = "..."), ...)
                     let content;
                     syn::parenthesized!(content in input);
-                    while !content.is_empty() {
-                        let key: Ident = content.parse()?;
-                        content.parse::<Token![=]>()?;
-                        let value: Lit = content.parse()?;
-                        if let Lit::Str(s) = value {
-                            let ext_key = if key.to_string().starts_with("x_") {
-                                key.to_string().replace('_', "-")
-                            } else {
-                                format!("x-{}", key.to_string().replace('_', "-"))
-                            };
-                            attrs.extensions.push((ext_key, s.value()));
-                        }
-                        if !content.is_empty() {
-                            content.parse::<Token![,]>()?;
-                        }
-                    }
+                    let examples: Punctuated<ExampleDef, Comma> =
+                        Punctuated::parse_terminated(&content)?;
+                    attrs.examples.extend(examples);
+                }
+                "extend" => {
+                    // Parse extensions: extend(x_cli_category = "validation")
+                    attrs
+                        .extensions
+                        .extend(parse_utils::parse_extensions(input)?);
                 }
                 "parameters" => {
                     // Parse parameters: parameters(...)
@@ -542,6 +810,31 @@ impl Parse for CommandAttributes {
             }
         }
 
+        // Root commands are keyed by their binary name, so they can't be nested or use a path
+        if let Some(root) = &attrs.root {
+            if attrs.parent.is_some() {
+                return Err(Diagnostics::with_span(
+                    root.span(),
+                    "A root command cannot have a parent",
+                )
+                .help("Remove either `root` or `parent = \"...\"` from the command")
+                .into());
+            }
+            if attrs
+                .name
+                .as_ref()
+                .is_some_and(|name| name.starts_with('/'))
+            {
+                return Err(Diagnostics::with_span(
+                    root.span(),
+                    "A root command name cannot start with `/`",
+                )
+                .help("Use the binary name as the root command name (e.g., `name = \"foo\"`)")
+                .note("Subcommand paths start with `/`, e.g., `/foo/build`")
+                .into());
+            }
+        }
+
         Ok(attrs)
     }
 }
@@ -569,6 +862,12 @@ fn generate_parameters_tokens(parameters: &[ParameterDef]) -> TokenStream {
     let param_builders: Vec<TokenStream> = parameters
         .iter()
         .map(|param| {
+            // A reference to a shared parameter component replaces the inline definition
+            if let Some(component_ref) = &param.component_ref {
+                let ref_path = format!("#/components/parameters/{}", component_ref);
+                return quote! { RefOr::new_ref(#ref_path) };
+            }
+
             let name = &param.name;
             let in_ = &param.in_;
             let scope = &param.scope;
@@ -586,6 +885,18 @@ fn generate_parameters_tokens(parameters: &[ParameterDef]) -> TokenStream {
                 quote! {}
             };
 
+            let deprecated_tokens = if param.deprecated {
+                quote! { .deprecated(true) }
+            } else {
+                quote! {}
+            };
+
+            let hidden_tokens = if param.hidden {
+                quote! { .hidden(true) }
+            } else {
+                quote! {}
+            };
+
             let position_tokens = if let Some(pos) = param.position {
                 quote! { .position(#pos) }
             } else {
@@ -599,19 +910,67 @@ fn generate_parameters_tokens(parameters: &[ParameterDef]) -> TokenStream {
                 quote! {}
             };
 
+            let requires_tokens = if !param.requires.is_empty() {
+                let requires = &param.requires;
+                quote! { .requires(vec![#(#requires.to_string()),*]) }
+            } else {
+                quote! {}
+            };
+
+            let conflicts_tokens = if !param.conflicts.is_empty() {
+                let conflicts = &param.conflicts;
+                quote! { .conflicts(vec![#(#conflicts.to_string()),*]) }
+            } else {
+                quote! {}
+            };
+
+            let value_name_tokens = if let Some(value_name) = &param.value_name {
+                quote! { .value_name(#value_name) }
+            } else {
+                quote! {}
+            };
+
+            let section_tokens = if let Some(section) = &param.section {
+                quote! { .section(#section) }
+            } else {
+                quote! {}
+            };
+
+            let default_missing_value_tokens =
+                if let Some(default_missing_value) = &param.default_missing_value {
+                    quote! { .default_missing_value(#default_missing_value) }
+                } else {
+                    quote! {}
+                };
+
+            let flag_style_tokens = if let Some(flag_style) = &param.flag_style {
+                quote! { param = param.flag_style(#flag_style); }
+            } else {
+                quote! {}
+            };
+
             let schema_format_tokens = if let Some(format) = &param.schema_format {
                 // Convert format string to enum variant (e.g., "path" -> "Path")
-                let format_ident = syn::Ident::new(
-                    &(format
-                        .chars()
-                        .next()
-                        .unwrap()
-                        .to_uppercase()
-                        .collect::<String>()
-                        + &format[1..]),
-                    proc_macro2::Span::call_site(),
-                );
-                quote! { .format(SchemaFormat::#format_ident) }
+                let format = crate::schema::schema_format_tokens(format);
+                quote! { .format(#format) }
+            } else {
+                quote! {}
+            };
+
+            let pattern_tokens = if let Some(pattern) = &param.pattern {
+                quote! { .pattern(#pattern) }
+            } else {
+                quote! {}
+            };
+
+            let min_length_tokens = if let Some(min_length) = param.min_length {
+                quote! { .min_length(#min_length) }
+            } else {
+                quote! {}
+            };
+
+            let max_length_tokens = if let Some(max_length) = param.max_length {
+                quote! { .max_length(#max_length) }
             } else {
                 quote! {}
             };
@@ -735,30 +1094,59 @@ fn generate_parameters_tokens(parameters: &[ParameterDef]) -> TokenStream {
                 quote! {}
             };
 
-            quote! {
-                {
-                    let schema = Schema::Object(Box::new(
+            // A schema reference takes precedence over the inline schema attributes
+            let schema_tokens = if let Some(schema_ref) = &param.schema_ref {
+                let ref_path = format!("#/components/schemas/{}", schema_ref);
+                quote! { RefOr::new_ref(#ref_path) }
+            } else if let Some(values_type) = &param.possible_values_from {
+                // The enum values are only known at runtime, reference the component
+                quote! {
+                    RefOr::new_ref(format!(
+                        "#/components/schemas/{}",
+                        <#values_type as ::utocli::ToSchema>::schema_name()
+                    ))
+                }
+            } else {
+                quote! {
+                    RefOr::T(Schema::Object(Box::new(
                         Object::new()
                             .schema_type(SchemaType::#schema_type_ident)
                             #schema_format_tokens
+                            #pattern_tokens
+                            #min_length_tokens
+                            #max_length_tokens
                             #enum_tokens
                             #default_tokens
                             #example_tokens
-                    ));
+                    )))
+                }
+            };
+
+            quote! {
+                {
+                    let schema = #schema_tokens;
 
                     let mut param = Parameter::new(#name)
                         #in_tokens
                         .scope(ParameterScope::#scope_ident)
-                        .schema(RefOr::T(schema))
+                        .schema(schema)
                         #description_tokens
                         #required_tokens
+                        #deprecated_tokens
+                        #hidden_tokens
                         #position_tokens
-                        #aliases_tokens;
+                        #aliases_tokens
+                        #requires_tokens
+                        #conflicts_tokens
+                        #value_name_tokens
+                        #section_tokens
+                        #default_missing_value_tokens;
 
                     #arity_tokens
                     #extensions_tokens
+                    #flag_style_tokens
 
-                    param
+                    RefOr::T(param)
                 }
             }
         })
@@ -971,8 +1359,14 @@ fn generate_responses_tokens(responses: &[ResponseDef]) -> TokenStream {
                     quote! {}
                 };
 
+                let example_file_tokens = content
+                    .example_file
+                    .as_ref()
+                    .map(parse_utils::track_example_file);
+
                 quote! {
                     {
+                        #example_file_tokens
                         let mut media_type = MediaType::new();
                         #schema_tokens
                         #example_tokens
@@ -1030,21 +1424,60 @@ pub fn command(args: TokenStream, input: ItemFn) -> Result<TokenStream, Diagnost
     let fn_generics = &input.sig.generics;
     let fn_attrs = &input.attrs;
 
-    // Parse doc comments
+    // Parse doc comments. Without an explicit summary, the first paragraph becomes the summary
+    // and the remaining paragraphs the description. With `about_as_description`, a single
+    // paragraph (clap's `about` without a `long_about`) becomes the description instead, and
+    // no summary is emitted.
     let doc_comments = parse_doc_comments(fn_attrs);
-    let description = attributes.description.clone().or(doc_comments);
+    let (summary, doc_description) = match (&attributes.summary, doc_comments) {
+        (Some(summary), doc_comments) => (Some(summary.clone()), doc_comments),
+        (None, Some(doc_comments)) => match split_summary_description(&doc_comments) {
+            (about, None) if attributes.about_as_description.is_some() => (None, Some(about)),
+            (summary, description) => (Some(summary), description),
+        },
+        (None, None) => (Some(String::new()), None),
+    };
+    let description = attributes.description.clone().or(doc_description);
+
+    let command_name = match &attributes.name {
+        Some(name) => name.clone(),
+        None => {
+            let suffix = attributes.strip_suffix.as_deref().unwrap_or("_command");
+            let fn_name_str = fn_name.to_string();
+            let name = fn_name_str
+                .strip_suffix(suffix)
+                .unwrap_or(&fn_name_str)
+                .to_string();
+            validate_command_name(&name, fn_name.span()).map_err(|diagnostics| {
+                diagnostics
+                    .help("Set the command name explicitly with `name = \"...\"`")
+                    .note(format!(
+                        "The name is derived from the function name without the `{suffix}` suffix, which can be changed with `strip_suffix = \"...\"`"
+                    ))
+            })?;
+            name
+        }
+    };
 
-    let command_name = attributes
-        .name
-        .clone()
-        .unwrap_or_else(|| fn_name.to_string().trim_end_matches("_command").to_string());
+    // Subcommands declaring a parent are keyed by the full `/`-separated path, like every
+    // subcommand key (e.g., `/remote` + `add` -> `/remote/add`, and root `ocs` + `add` ->
+    // `/ocs/add`)
+    let command_path = match &attributes.parent {
+        Some(parent) => format!(
+            "/{}/{}",
+            parent.trim_matches('/'),
+            command_name.trim_start_matches('/')
+        ),
+        None => command_name,
+    };
 
-    let summary = attributes.summary.clone().unwrap_or_default();
     let operation_id = attributes.operation_id.clone();
     let aliases = &attributes.aliases;
     let tags = &attributes.tags;
     let extensions = &attributes.extensions;
 
+    let summary_tokens = summary.map(|summary| quote! { command = command.summary(#summary); });
+
     let description_tokens = if let Some(desc) = description {
         quote! { command = command.description(#desc); }
     } else {
@@ -1073,9 +1506,37 @@ pub fn command(args: TokenStream, input: ItemFn) -> Result<TokenStream, Diagnost
         quote! {}
     };
 
-    let extensions_tokens = if !extensions.is_empty() {
-        let ext_keys: Vec<_> = extensions.iter().map(|(k, _)| k).collect();
-        let ext_values: Vec<_> = extensions.iter().map(|(_, v)| v).collect();
+    let external_docs_tokens = attributes
+        .external_docs
+        .as_ref()
+        .map(|external_docs| quote! { command = command.external_docs(#external_docs); });
+
+    let examples_tokens = attributes.examples.iter().map(|example| {
+        let command = &example.command;
+        let description = example
+            .description
+            .as_ref()
+            .map(|description| quote! { .description(#description) });
+        let output = example
+            .output
+            .as_ref()
+            .map(|output| quote! { .output(#output) });
+        quote! {
+            command = command.example(
+                ::utocli::opencli::CommandExample::new(#command) #description #output
+            );
+        }
+    });
+
+    let extensions_tokens = if !extensions.is_empty() || attributes.emit_source.is_some() {
+        let mut ext_keys: Vec<_> = extensions.iter().map(|(k, _)| quote! { #k }).collect();
+        let mut ext_values: Vec<_> = extensions.iter().map(|(_, v)| quote! { #v }).collect();
+        if attributes.emit_source.is_some() {
+            // The generated impl lives next to the function, so `module_path!()` is its module
+            let fn_name_str = fn_name.to_string();
+            ext_keys.push(quote! { "x-source" });
+            ext_values.push(quote! { concat!(module_path!(), "::", #fn_name_str) });
+        }
         quote! {
             {
                 let mut exts = ::utocli::Map::new();
@@ -1115,18 +1576,20 @@ pub fn command(args: TokenStream, input: ItemFn) -> Result<TokenStream, Diagnost
 
         impl ::utocli::CommandPath for #struct_name {
             fn path() -> &'static str {
-                #command_name
+                #command_path
             }
 
             fn command() -> ::utocli::opencli::Command {
-                use ::utocli::opencli::{Command, Parameter, ParameterScope, RefOr, Schema, Object, SchemaType, SchemaFormat, Response, MediaType, Map};
+                use ::utocli::opencli::{Command, Parameter, ParameterScope, RefOr, Schema, Object, SchemaType, Response, MediaType, Map};
 
                 let mut command = Command::new();
-                command = command.summary(#summary);
+                #summary_tokens
                 #description_tokens
                 #operation_id_tokens
                 #aliases_tokens
                 #tags_tokens
+                #external_docs_tokens
+                #(#examples_tokens)*
                 #extensions_tokens
                 #parameters_tokens
                 #responses_tokens
@@ -1136,3 +1599,203 @@ pub fn command(args: TokenStream, input: ItemFn) -> Result<TokenStream, Diagnost
         }
     })
 }
+
+/// Validates that each segment of a command name (or `/`-separated path) is a valid CLI name:
+/// lowercase ASCII letters, digits and `-`, starting with a letter or a digit.
+fn validate_command_name(name: &str, span: proc_macro2::Span) -> Result<(), Diagnostics> {
+    let is_valid_segment = |segment: &str| {
+        segment
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    };
+    let path = name.strip_prefix('/').unwrap_or(name);
+    if path.split('/').all(is_valid_segment) {
+        return Ok(());
+    }
+    Err(Diagnostics::with_span(
+        span,
+        format!(
+            "invalid command name `{name}`, expected lowercase letters, digits and `-`, starting with a letter or a digit"
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{CommandAttributes, ParameterDef, ResponseDef};
+
+    #[test]
+    fn parameter_def_with_global_uses_inherited_scope() {
+        //* Given
+        let tokens = quote! { (name = "verbose", in = "flag", global = true) };
+
+        //* When
+        let param = syn::parse2::<ParameterDef>(tokens).expect("should parse parameter");
+
+        //* Then
+        assert_eq!(
+            param.scope, "inherited",
+            "global parameter should be inherited"
+        );
+    }
+
+    #[test]
+    fn parameter_def_with_deprecation_note_fails() {
+        //* Given
+        let tokens = quote! { (name = "old-flag", in = "flag", deprecated = "use --new-flag") };
+
+        //* When
+        let result = syn::parse2::<ParameterDef>(tokens);
+
+        //* Then
+        let error = result.err().expect("deprecation note should be rejected");
+        assert!(
+            error.to_string().contains("deprecated = <bool>"),
+            "error should explain the accepted deprecated forms"
+        );
+    }
+
+    #[test]
+    fn parameter_def_with_ref_and_inline_attribute_fails() {
+        //* Given
+        let tokens = quote! { (ref = "ConfigFile", description = "Path to the config file") };
+
+        //* When
+        let result = syn::parse2::<ParameterDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("attributes next to a ref should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("`description` cannot be combined with `ref`"),
+            "error should name the attribute combined with the ref"
+        );
+    }
+
+    #[test]
+    fn parameter_def_with_global_and_position_fails() {
+        //* Given
+        let tokens = quote! { (name = "file", in = "argument", position = 1, global) };
+
+        //* When
+        let result = syn::parse2::<ParameterDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("global positional parameter should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("global parameters cannot be positional"),
+            "error should explain that globals cannot be positional"
+        );
+    }
+
+    #[test]
+    fn parameter_def_with_environment_and_position_fails() {
+        //* Given
+        let tokens = quote! { (name = "APP_TOKEN", in = "environment", position = 1) };
+
+        //* When
+        let result = syn::parse2::<ParameterDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("positional environment parameter should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("environment parameters cannot be positional"),
+            "error should explain that environment parameters cannot be positional"
+        );
+    }
+
+    #[test]
+    fn response_def_with_reversed_exit_code_range_fails() {
+        //* Given
+        let tokens = quote! { (status = "125-1", description = "Failure") };
+
+        //* When
+        let result = syn::parse2::<ResponseDef>(tokens);
+
+        //* Then
+        let error = result.err().expect("reversed range should be rejected");
+        assert!(
+            error.to_string().contains("invalid exit code `125-1`"),
+            "error should name the invalid exit code"
+        );
+    }
+
+    #[test]
+    fn command_attributes_with_invalid_name_fails() {
+        //* Given
+        let tokens = quote! { name = "list items" };
+
+        //* When
+        let result = syn::parse2::<CommandAttributes>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("name with whitespace should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("invalid command name `list items`"),
+            "error should name the invalid command name"
+        );
+    }
+
+    #[test]
+    fn command_with_invalid_derived_name_fails() {
+        //* Given
+        let args = quote! {};
+        let input = syn::parse_quote! { fn subcommand_handler() {} };
+
+        //* When
+        let result = super::command(args, input);
+
+        //* Then
+        let Err(diagnostics) = result else {
+            panic!("derived name with `_` should be rejected");
+        };
+        assert!(
+            diagnostics
+                .to_string()
+                .contains("invalid command name `subcommand_handler`"),
+            "error should name the derived command name"
+        );
+    }
+
+    #[test]
+    fn command_with_repeated_strip_suffix_strips_it_once() {
+        //* Given
+        let args = quote! { strip_suffix = "_cmd" };
+        let input = syn::parse_quote! { fn list_cmd_cmd() {} };
+
+        //* When
+        let result = super::command(args, input);
+
+        //* Then
+        let Err(diagnostics) = result else {
+            panic!("derived name `list_cmd` should be rejected");
+        };
+        assert!(
+            diagnostics
+                .to_string()
+                .contains("invalid command name `list_cmd`"),
+            "only the last suffix occurrence should be stripped"
+        );
+    }
+}
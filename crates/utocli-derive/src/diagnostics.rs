@@ -133,7 +133,36 @@ impl Diagnostics {
 
 impl From<syn::Error> for Diagnostics {
     fn from(value: syn::Error) -> Self {
-        Self::with_span(value.span(), value.to_string())
+        let mut diagnostics = Self {
+            diagnostics: Vec::new(),
+        };
+
+        // Recover the suggestions combined by `From<Diagnostics> for syn::Error`, so the help
+        // and notes survive a round trip through `syn::Result`
+        for error in value {
+            let message = error.to_string();
+            let suggestion = if let Some(help) = message.strip_prefix("help: ") {
+                Some(Suggestion::Help(help.to_string().into()))
+            } else {
+                message
+                    .strip_prefix("note: ")
+                    .map(|note| Suggestion::Note(note.to_string().into()))
+            };
+
+            match (suggestion, diagnostics.diagnostics.last_mut()) {
+                (Some(suggestion), Some(last)) => {
+                    last.suggestions.push(suggestion);
+                    last.suggestions.sort();
+                }
+                _ => diagnostics.diagnostics.push(DiangosticsInner {
+                    span: error.span(),
+                    message: message.into(),
+                    suggestions: Vec::new(),
+                }),
+            }
+        }
+
+        diagnostics
     }
 }
 
@@ -230,4 +259,27 @@ mod tests {
             "help should come before note in diagnostic output"
         );
     }
+
+    #[test]
+    fn from_syn_error_with_converted_diagnostics_keeps_help_and_note() {
+        //* Given
+        let error: syn::Error = Diagnostics::new("expected a list of architectures")
+            .help("use architectures(amd64, arm64)")
+            .note("a platform may support several architectures")
+            .into();
+
+        //* When
+        let tokens = Diagnostics::from(error).into_token_stream();
+
+        //* Then
+        let expected_tokens = quote::quote!(::core::compile_error!(
+            "expected a list of architectures\n\nhelp = use architectures(amd64, arm64)\nnote = a platform may support several architectures"
+        ););
+
+        assert_eq!(
+            tokens.to_string(),
+            expected_tokens.to_string(),
+            "help and note should survive the conversion through syn::Error"
+        );
+    }
 }
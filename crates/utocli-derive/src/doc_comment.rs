@@ -4,9 +4,13 @@ use syn::Attribute;
 
 /// Extract description from doc comment attributes.
 ///
-/// Combines multiple doc comment lines into a single description string.
+/// Combines multiple doc comment lines into a single description string. The indentation
+/// shared by all non-blank lines (including the space rustdoc adds after `///`) is stripped,
+/// trailing whitespace is removed and runs of blank lines collapse into a single paragraph
+/// break.
 pub fn parse_doc_comments(attrs: &[Attribute]) -> Option<String> {
-    let mut docs = Vec::new();
+    let mut lines = Vec::new();
+    let mut has_docs = false;
 
     for attr in attrs {
         if attr.path().is_ident("doc")
@@ -14,19 +18,70 @@ pub fn parse_doc_comments(attrs: &[Attribute]) -> Option<String> {
             && let syn::Expr::Lit(expr_lit) = &meta.value
             && let syn::Lit::Str(lit_str) = &expr_lit.lit
         {
-            let comment = lit_str.value();
-
-            // Remove leading space that rustdoc adds
-            let trimmed = comment.strip_prefix(' ').unwrap_or(&comment);
-            docs.push(trimmed.to_string());
+            has_docs = true;
+            // Block doc comments (`/** ... */`) hold several lines in a single attribute
+            lines.extend(
+                lit_str
+                    .value()
+                    .split('\n')
+                    .map(str::trim_end)
+                    .map(String::from),
+            );
         }
     }
 
-    if docs.is_empty() {
+    if !has_docs {
         return None;
     }
 
-    Some(docs.join("\n").trim().to_string())
+    Some(normalize_lines(&lines))
+}
+
+/// Strip the common indentation of the lines and collapse blank line runs.
+///
+/// Only ASCII spaces and tabs count as indentation, so other whitespace (e.g., a leading
+/// non-breaking space) is kept as content.
+fn normalize_lines(lines: &[String]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+
+    let mut docs = String::new();
+    let mut pending_break = false;
+    for line in lines {
+        if line.is_empty() {
+            pending_break = !docs.is_empty();
+            continue;
+        }
+
+        if !docs.is_empty() {
+            docs.push_str(if pending_break { "\n\n" } else { "\n" });
+        }
+        docs.push_str(&line[indent..]);
+        pending_break = false;
+    }
+
+    docs
+}
+
+/// Split a doc comment into a summary and a description.
+///
+/// Following rustdoc conventions, the first paragraph becomes the summary and the remaining
+/// paragraphs become the description. A single-paragraph doc comment has no description.
+pub fn split_summary_description(docs: &str) -> (String, Option<String>) {
+    match docs.split_once("\n\n") {
+        Some((summary, description)) => {
+            let description = description.trim();
+            (
+                summary.trim().to_string(),
+                (!description.is_empty()).then(|| description.to_string()),
+            )
+        }
+        None => (docs.trim().to_string(), None),
+    }
 }
 
 #[cfg(test)]
@@ -84,4 +139,94 @@ mod tests {
             "should return None when no doc comments are present"
         );
     }
+
+    #[test]
+    fn split_summary_description_with_multiple_paragraphs_splits_first_paragraph() {
+        //* When
+        let result =
+            split_summary_description("Build the project\n\nCompiles all sources.\n\nRuns checks.");
+
+        //* Then
+        assert_eq!(
+            result,
+            (
+                "Build the project".to_string(),
+                Some("Compiles all sources.\n\nRuns checks.".to_string())
+            ),
+            "first paragraph should be the summary and the rest the description"
+        );
+    }
+
+    #[test]
+    fn split_summary_description_with_single_line_returns_summary_only() {
+        //* When
+        let result = split_summary_description("Build the project");
+
+        //* Then
+        assert_eq!(
+            result,
+            ("Build the project".to_string(), None),
+            "single line should be the summary with no description"
+        );
+    }
+
+    #[test]
+    fn parse_doc_comments_with_multiple_paragraphs_preserves_single_breaks() {
+        //* Given
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = " Build the project."] },
+            parse_quote! { #[doc = ""] },
+            parse_quote! { #[doc = "   "] },
+            parse_quote! { #[doc = " Compiles all sources.  "] },
+            parse_quote! { #[doc = " Runs checks."] },
+            parse_quote! { #[doc = ""] },
+        ];
+
+        //* When
+        let result = parse_doc_comments(&attrs);
+
+        //* Then
+        assert_eq!(
+            result,
+            Some("Build the project.\n\nCompiles all sources.\nRuns checks.".to_string()),
+            "blank line runs should collapse into a paragraph break and trailing whitespace be removed"
+        );
+    }
+
+    #[test]
+    fn parse_doc_comments_with_indented_lines_strips_common_indentation() {
+        //* Given
+        let attrs: Vec<Attribute> = vec![parse_quote! {
+            #[doc = "\n    Example usage:\n\n        app build --release\n    "]
+        }];
+
+        //* When
+        let result = parse_doc_comments(&attrs);
+
+        //* Then
+        assert_eq!(
+            result,
+            Some("Example usage:\n\n    app build --release".to_string()),
+            "common indentation should be stripped while keeping relative indentation"
+        );
+    }
+
+    #[test]
+    fn parse_doc_comments_with_non_ascii_leading_space_keeps_it() {
+        //* Given
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = "\u{a0}Line 1"] },
+            parse_quote! { #[doc = " Line 2"] },
+        ];
+
+        //* When
+        let result = parse_doc_comments(&attrs);
+
+        //* Then
+        assert_eq!(
+            result,
+            Some("\u{a0}Line 1\n Line 2".to_string()),
+            "should only strip ASCII indentation"
+        );
+    }
 }
@@ -55,8 +55,8 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{ToTokens, quote};
-use syn::{DeriveInput, Member, parse_macro_input};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{DeriveInput, Member, parse_macro_input, spanned::Spanned};
 
 mod command;
 mod diagnostics;
@@ -80,6 +80,8 @@ enum AnyValue {
     #[allow(dead_code)]
     String(TokenStream2),
     Json(TokenStream2),
+    /// A call to a function returning a `Serialize` value (e.g., `my_module::example_fn()`)
+    Serialize(TokenStream2),
     DefaultTrait {
         struct_ident: syn::Ident,
         field_ident: Member,
@@ -92,7 +94,8 @@ impl AnyValue {
     /// Accepts:
     /// - Literals: `42`, `"string"`, `true`, `-1`
     /// - Macro invocations: `json!(...)`, `serde_json::json!(...)`, or any custom macro
-    /// - Function calls: `my_module::example_fn` (will be called as `example_fn()`)
+    /// - Function references: `my_module::example_fn` (will be called as `example_fn()` and its
+    ///   `Serialize` return value converted with `serde_json::to_value`)
     fn parse_any(input: syn::parse::ParseStream) -> syn::Result<Self> {
         // Parse as expression to handle all cases uniformly
         let expr = input.parse::<syn::Expr>().map_err(|error| {
@@ -109,7 +112,7 @@ impl AnyValue {
         match &expr {
             syn::Expr::Path(_) => {
                 // Function reference - add () to call it
-                Ok(AnyValue::Json(quote! { #expr() }))
+                Ok(AnyValue::Serialize(quote! { #expr() }))
             }
             _ => {
                 // Literal, macro, or other expression - use as-is
@@ -134,6 +137,13 @@ impl AnyValue {
         }
     }
 
+    /// The serialized `Default::default()` value of the given type.
+    ///
+    /// The call is spanned to the type, so a type not implementing `Default` is reported on it.
+    fn new_type_default(ty: &syn::Type) -> Self {
+        Self::Serialize(quote_spanned! {ty.span()=> <#ty as ::core::default::Default>::default() })
+    }
+
     #[allow(dead_code)]
     fn new_default_trait(struct_ident: syn::Ident, field_ident: Member) -> Self {
         Self::DefaultTrait {
@@ -150,6 +160,9 @@ impl ToTokens for AnyValue {
             Self::Json(json) => tokens.extend(quote! {
                 serde_json::json!(#json)
             }),
+            Self::Serialize(call) => tokens.extend(quote! {
+                serde_json::to_value(#call).unwrap()
+            }),
             Self::String(string) => string.to_tokens(tokens),
             Self::DefaultTrait {
                 struct_ident,
@@ -165,7 +178,7 @@ impl ToTokens for AnyValue {
 /// Matches utoipa-gen/src/lib.rs lines 26012-26177
 mod parse_utils {
     use proc_macro2::TokenStream;
-    use quote::ToTokens;
+    use quote::{ToTokens, quote};
     use syn::{Token, parse::ParseStream};
 
     pub fn parse_next<T: FnOnce() -> Result<R, syn::Error>, R: Sized>(
@@ -189,6 +202,87 @@ mod parse_utils {
         // and function references uniformly
         Ok(input.parse::<syn::Expr>()?.to_token_stream())
     }
+
+    /// Parse extensions: `extend(x_cli_category = "validation", performance = "fast")`.
+    ///
+    /// Keys are converted to kebab-case and prefixed with `x-` when missing
+    /// (e.g., `x_cli_category` -> `x-cli-category`, `performance` -> `x-performance`).
+    pub fn parse_extensions(input: ParseStream) -> syn::Result<Vec<(String, String)>> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let mut extensions = Vec::new();
+        while !content.is_empty() {
+            let key: syn::Ident = content.parse()?;
+            content.parse::<Token![=]>()?;
+            let value: syn::Lit = content.parse()?;
+            if let syn::Lit::Str(s) = value {
+                let key = key.to_string().replace('_', "-");
+                let ext_key = if key.starts_with("x-") {
+                    key
+                } else {
+                    format!("x-{key}")
+                };
+                extensions.push((ext_key, s.value()));
+            }
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(extensions)
+    }
+
+    /// Read an example file at macro expansion time, returning its contents as a JSON string.
+    ///
+    /// The path is resolved relative to `CARGO_MANIFEST_DIR`. JSON files (`.json`) are embedded
+    /// as their JSON value, while any other file is embedded as a string. The generated code
+    /// must include [`track_example_file`] so that editing the file triggers a rebuild.
+    pub fn read_example_file(path: &syn::LitStr) -> syn::Result<String> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| syn::Error::new(path.span(), "CARGO_MANIFEST_DIR is not set"))?;
+        let file_path = std::path::Path::new(&manifest_dir).join(path.value());
+
+        let contents = std::fs::read_to_string(&file_path).map_err(|error| {
+            syn::Error::new(
+                path.span(),
+                format!(
+                    "Failed to read example file `{}`: {error}",
+                    file_path.display()
+                ),
+            )
+        })?;
+
+        if file_path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str::<serde_json::Value>(&contents)
+                .map(|value| value.to_string())
+                .map_err(|error| {
+                    syn::Error::new(
+                        path.span(),
+                        format!(
+                            "Invalid JSON in example file `{}`: {error}",
+                            file_path.display()
+                        ),
+                    )
+                })
+        } else {
+            Ok(serde_json::Value::String(contents).to_string())
+        }
+    }
+
+    /// Generate an item including an example file read by [`read_example_file`].
+    ///
+    /// The contents are unused, but `include_str!` makes the compiler track the file, so the
+    /// crate is rebuilt (and the example read again) when the file changes.
+    pub fn track_example_file(path: &syn::LitStr) -> TokenStream {
+        quote! {
+            const _: &str = ::core::include_str!(::core::concat!(
+                ::core::env!("CARGO_MANIFEST_DIR"),
+                "/",
+                #path
+            ));
+        }
+    }
 }
 
 /// Derive macro for generating OpenCLI schema definitions.
@@ -214,27 +308,65 @@ mod parse_utils {
 /// ## Container attributes (`#[schema(...)]`)
 ///
 /// * `description = "..."` - Override the description from doc comments
-/// * `example = ...` - Provide an example value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression)
+/// * `example = ...` - Provide an example value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression).
+///   A path to a function (e.g., `example = my_module::example_user`) is called and its return
+///   value, which must implement `Serialize`, is used as the example
+/// * `examples(...)` - Provide several example values, serialized as the JSON Schema `examples`
+///   array (e.g., `examples(json!({"name": "rex"}), json!({"name": "tom"}))`). It is emitted
+///   alongside `example`, and takes precedence over it in the JSON Schema 2020-12 projection
 /// * `title = "..."` - Set a custom title for the schema
+/// * `title_from_name` - Set the title to the schema name (including generics) when no `title` is given
 /// * `rename_all = "..."` - Rename all fields (e.g., "camelCase", "snake_case")
+/// * `rename_all_fields = "..."` - Rename the fields of all enum struct variants, like serde's
+///   `rename_all_fields`; a variant-level `#[serde(rename_all = "...")]` takes precedence
+/// * `discriminator = "..."` - Document an internally tagged enum (`#[serde(tag = "...")]`, with
+///   the same tag) as a `oneOf` with a `discriminator` on the tag property. Newtype variants of
+///   a schema component are referenced and mapped from their variant name
+/// * `additional_properties = ...` - Either a boolean allowing (or forbidding) additional properties,
+///   or a type whose schema the additional property values must match (e.g., `additional_properties = String`)
+/// * `read_only`, `write_only` - Mark every field as read-only (e.g., for types only used in
+///   responses) or write-only (e.g., for types only used in requests). Fields whose schema is
+///   a reference, an array or a composition are wrapped in an `allOf` carrying the flag
 /// * `no_recursion` - Break recursion in case of looping schema tree (e.g., `Pet` -> `Owner` -> `Pet`).
 ///   When set on a container, it applies to all fields.
+/// * `numeric_exclusive_bounds` - Emit the field exclusive bounds in the JSON Schema 2020-12
+///   form: `minimum = 0, exclusive_minimum = true` becomes `exclusiveMinimum: 0` instead of
+///   `minimum: 0` and `exclusiveMinimum: true` (and likewise for maximums)
+/// * `inline` - Always inline the schema: when listed in the `OpenCli` derive
+///   `components(schemas(...))`, the type is not registered as a component and every reference
+///   to it is replaced by its schema.
+/// * `min_length = N`, `max_length = N`, `pattern = "..."` - Constrain the string schema generated
+///   for unit-variant enums and unit structs
 ///
 /// ## Field attributes (`#[schema(...)]`)
 ///
 /// * `description = "..."` - Override field description
 /// * `example = ...` - Provide an example value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression)
+/// * `example_from_default` - Use the serialized `Default::default()` value of the field type as
+///   the example. Unlike `default`, it does not set the schema default value. The field type
+///   must implement `Default` and `Serialize`
+/// * `examples(...)` - Provide several example values, serialized as the JSON Schema `examples`
+///   array (e.g., `examples("rex", "tom")`)
 /// * `format = "..."` - Specify the schema format
 /// * `rename = "..."` - Rename this specific field
 /// * `inline` - Inline the schema instead of using a reference
 /// * `skip` - Skip this field from the schema
+/// * `value_type = ...` - Document the field as another type (e.g., `String`, `Vec<i32>`).
+///   `value_type = Object` documents the field as a free-form object
+/// * `as_map` - Document a `Vec<(K, V)>` field as an object whose property values are `V`,
+///   instead of an array of `[key, value]` pairs. Other field types are a compile error
+/// * `result_as_tagged` - Document a `Result<T, E>` field as serde serializes it, a `oneOf` of
+///   objects with a single `Ok` or `Err` property, instead of an `anyOf` of the `T` and `E`
+///   schemas (or their shared schema when `T` and `E` are the same type)
 /// * `no_recursion` - Break recursion for this specific field. Use this to prevent infinite
 ///   loops in recursive data structures.
 ///
 /// # Recursion handling
 ///
 /// For recursive data structures, use the `no_recursion` attribute to break the cycle.
-/// Without it, the macro will recurse infinitely and cause a runtime panic.
+/// Without it, the macro will recurse infinitely and cause a runtime panic. Inlining a field
+/// that refers back to its own container (e.g., `#[schema(inline)] parent: Option<Box<Self>>`)
+/// without `no_recursion` is rejected at compile time.
 ///
 /// ```ignore
 /// #[derive(ToSchema)]
@@ -286,15 +418,51 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// # Attributes
 ///
+/// ## Container attributes (`#[param(...)]`)
+///
+/// * `rename_all = "..."` - Rename all parameter names (e.g., `"kebab-case"` turns `output_dir`
+///   into `output-dir`). Fields with `long` or `rename` keep their explicit name
+///
 /// ## Field attributes (`#[param(...)]`)
 ///
-/// * `alias = "..."` - Alternative short name for the parameter (e.g., "v" for verbose)
-/// * `description = "..."` - Parameter description (overrides doc comments)
+/// * `long = "..."` - Parameter name (defaults to the field name). `rename = "..."` is accepted
+///   as a synonym
+/// * `short = '...'` - Short flag, listed first among the aliases (e.g., `short = 'v'` for verbose)
+/// * `alias = "..."` - Alternative name for the parameter. Repeatable, or given as a list
+///   (`alias("a", "all")`); `visible_alias` is accepted as a synonym, since OpenCLI aliases have
+///   no visibility
+/// * `description = "..."` - Parameter description (overrides `help` and doc comments)
+/// * `help = "..."` - Parameter description following clap's `help` (overrides doc comments)
 /// * `example = ...` - Example value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression)
 /// * `default = ...` - Default value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression)
+/// * `default_value = "..."` - Default value as a string, following clap's `default_value`
+/// * `default_value_t = ...` - Typed default value expression, following clap's `default_value_t`
+///   (e.g., `default_value_t = 3`), serialized with `serde_json::json!`
 /// * `scope = "local"|"inherited"` - Parameter scope (local to command or inherited by subcommands)
-/// * `position = N` - Position for positional arguments
+/// * `env = "..."` - Environment variable the parameter value falls back to, emitted as `x-env`
+/// * `position = N` - Position for positional arguments; a positional `Vec<T>` field is a
+///   variadic trailing argument with unbounded arity, marked with `x-variadic: true`
 /// * `in = "argument"|"flag"|"option"` - Explicitly set parameter type
+/// * `deprecated` (or `deprecated = true`) - Mark the parameter as deprecated, emitted as
+///   `x-deprecated`; deprecation notes (`deprecated = "..."`) are rejected
+/// * `hidden` (or clap's `hide = true`) - Hide the parameter from help output; it stays in the spec
+/// * `requires = "..."` - Parameter that must be given along with this one, emitted as `x-requires`.
+///   Repeatable, or given as a list (`requires("a", "b")`); fields are referenced by field name,
+///   as in clap, and resolved to their parameter names
+/// * `conflicts_with = "..."` - Parameter that cannot be given along with this one, emitted as
+///   `x-conflicts`. Repeatable, or given as a list (`conflicts_with("a", "b")`)
+/// * `value_name = "..."` - Placeholder shown for the value in help output (e.g., `FILE`),
+///   following clap's `value_name`, emitted as `x-value-name`
+/// * `section = "..."` (or clap's `help_heading = "..."`) - Help section the parameter is
+///   grouped under, emitted as `x-section`
+/// * `default_missing_value = "..."` - Value used when the parameter is given without one,
+///   following clap's `default_missing_value` (e.g., `always` for `--color[=<when>]`), emitted
+///   as `x-default-missing`. The arity becomes zero or one value
+/// * `flag_style = "..."` - How a flag is given, `presence` (e.g., `--strict`, clap's `SetTrue`
+///   action) or `value` (e.g., `--strict=true`), serialized as `x-flag-style`
+/// * `possible_values_from = Type` - Reference the schema component of a `ToSchema` enum holding
+///   the allowed values, generating a `$ref` to `#/components/schemas/{Type}` instead of
+///   repeating them with `enum_values`
 /// * `skip` - Skip this field
 ///
 /// # Serde compatibility
@@ -336,12 +504,18 @@ pub fn derive_to_parameter(input: TokenStream) -> TokenStream {
 ///
 /// * `description = "..."` - Response description (overrides doc comments)
 /// * `status = "..."` - Exit status code (e.g., "0", "1")
+/// * `extend(...)` - Response metadata as extensions: `extend(x_retryable = "true", category = "io")`
+///   (keys are prefixed with `x-` when missing)
 ///
 /// ## Field attributes (`#[content(...)]`)
 ///
-/// * `media_type = "..."` - Media type for this content (e.g., "application/json", "text/plain")
+/// * `media_type = "..."` - Media type for this content (e.g., "application/json", "text/plain").
+///   Defaults to `application/json` when a `schema` is given, and to `text/plain` for
+///   example-only content
 /// * `schema = "..."` - Schema reference name (e.g., "ValidationResult")
 /// * `example = "..."` - Example value for this media type
+/// * `example_file = "..."` - Load the example from a file, relative to `CARGO_MANIFEST_DIR`.
+///   JSON files (`.json`) are embedded as their JSON value, other files as text
 #[proc_macro_derive(ToResponse, attributes(response, content))]
 pub fn derive_to_response(input: TokenStream) -> TokenStream {
     let DeriveInput {
@@ -401,10 +575,21 @@ pub fn derive_to_response(input: TokenStream) -> TokenStream {
 ///
 /// ## Container/Variant attributes (`#[response(...)]`)
 ///
-/// * `status = "..."` - Exit status code (required, e.g., "0", "1", "2")
+/// * `status = "..."` - Exit status code (required, e.g., "0", "1", "2"), inclusive exit code
+///   range (e.g., "1-125") or "default" for every undocumented exit code
 /// * `description = "..."` - Response description (overrides doc comments)
 /// * `content_type = "..."` - Media type (e.g., "application/json", "text/plain")
 /// * `example = ...` - Example value (accepts literals, `json!(...)`, `serde_json::json!(...)`, or any expression)
+/// * `extend(...)` - Response metadata as extensions: `extend(x_retryable = "true", category = "io")`
+///   (keys are prefixed with `x-` when missing)
+/// * `default_descriptions` - Container-level flag (standalone `#[response(default_descriptions)]`)
+///   filling the description of responses without description or doc comment from common CLI
+///   exit codes (e.g., "0" -> "Success", "1" -> "General error", "2" -> "Misuse of command")
+///
+/// Named structs and struct variants document their fields as an inline object schema under
+/// the `content_type` (default `application/json`) content, built as `ToSchema` builds a
+/// struct schema: `#[serde(...)]` and `#[schema(...)]` attributes (e.g., `rename_all`, `skip`)
+/// apply to the container and its fields.
 ///
 /// ## Field attributes (unnamed fields only)
 ///
@@ -420,7 +605,7 @@ pub fn derive_to_response(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_derive(
     IntoResponses,
-    attributes(response, to_schema, ref_response, to_response, content)
+    attributes(response, to_schema, ref_response, to_response, content, schema)
 )]
 pub fn derive_into_responses(input: TokenStream) -> TokenStream {
     let DeriveInput {
@@ -478,7 +663,19 @@ pub fn derive_into_responses(input: TokenStream) -> TokenStream {
 ///
 /// * `title = "..."` - CLI application title (required)
 /// * `version = "..."` - Application version (required)
+/// * `summary = "..."` - Short application summary, serialized as `x-summary` (optional)
 /// * `description = "..."` - Application description (optional, can use doc comments)
+/// * `terms_of_service = "..."` - URL to the terms of service, serialized as `x-termsOfService`
+///   (optional)
+/// * `author = "..."` - Application author, in the `Name <email>` format or a bare name. Sets
+///   the contact name and email when no `contact(...)` is given (optional)
+///
+/// Like clap's `#[command(version)]`, `version`, `description` and `author` can be given as
+/// bare flags to use the package metadata from `Cargo.toml` (`CARGO_PKG_VERSION`,
+/// `CARGO_PKG_DESCRIPTION` and the first author of `CARGO_PKG_AUTHORS`):
+/// `info(title = "app", version, author)`. The flags are read from `info(...)` only; clap's
+/// own `#[command(...)]` attributes are not inspected, and clap's `about` maps to
+/// `description` here.
 ///
 /// ## `commands(...)` - Command definitions
 ///
@@ -502,6 +699,32 @@ pub fn derive_into_responses(input: TokenStream) -> TokenStream {
 ///     (name = "validation")
 /// )
 /// ```
+///
+/// Tags accept `external_docs(url = "...", description = "...")`, like the root
+/// `external_docs(...)` attribute, serialized as the `x-externalDocs` extension:
+/// `(name = "core", external_docs(url = "https://example.com/docs/core"))`.
+///
+/// ## `platforms(...)` - Supported platforms
+///
+/// List of platform names, or tuples naming the platform and its architectures as a list or
+/// an array of strings. Unknown names are rejected; wrap intentional custom values in
+/// `other("...")` (e.g., `other("haiku")`). Custom values that name a known platform or
+/// architecture resolve to it; the others fall outside the OpenCLI specification, which
+/// restricts both to a fixed set of values:
+/// ```ignore
+/// platforms(
+///     linux,
+///     (name = "darwin", architectures(amd64, arm64)),
+///     (name = "windows", architectures = ["amd64"])
+/// )
+/// ```
+///
+/// ## `install(...)` - Install commands
+///
+/// Install commands keyed by package manager, emitted as the `x-install` extension:
+/// ```ignore
+/// install(brew = "brew install ocs", cargo = "cargo install ocs")
+/// ```
 #[proc_macro_derive(OpenCli, attributes(opencli))]
 pub fn derive_opencli(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -545,15 +768,37 @@ pub fn derive_opencli(input: TokenStream) -> TokenStream {
 ///
 /// # Attributes
 ///
-/// * `name = "..."` - Command name (defaults to function name without "_command" suffix)
-/// * `summary = "..."` - Short command summary
-/// * `description = "..."` - Detailed description (overrides doc comments)
+/// * `name = "..."` - Command name (defaults to function name without "_command" suffix).
+///   Names must be made of lowercase letters, digits and `-`, starting with a letter or a digit
+///   (e.g., `list-items`); names failing this rule, explicit or derived, are a compile error
+/// * `strip_suffix = "..."` - Suffix stripped once from the function name when no `name` is
+///   given (e.g., `strip_suffix = "_cmd"` names `list_cmd` as `list`)
+/// * `parent = "..."` - Parent command path (e.g., `parent = "/remote"`). The command is keyed
+///   by the parent path joined with its name (e.g., `/remote/add`), for multi-level CLIs. The
+///   key always starts with `/`, so a root name parent (e.g., `parent = "ocs"`) keys the
+///   command as `/ocs/add`
+/// * `root` - Mark the command as the root command of a binary. Specs documenting several
+///   binaries declare one root per binary (e.g., `foo` and `bar`), with subcommands nested
+///   under them through `parent` (e.g., `parent = "/foo"`). Root names cannot start with `/`
+/// * `summary = "..."` - Short command summary (defaults to the first paragraph of the doc comment)
+/// * `description = "..."` - Detailed description (overrides doc comments). Without an explicit
+///   `summary`, defaults to the doc comment paragraphs following the first one
+/// * `about_as_description` - Use a single-paragraph doc comment (clap's `about` without a
+///   `long_about`) as the description instead of the summary, and emit no summary
 /// * `operation_id = "..."` - Unique operation identifier
 /// * `aliases(...)` - Command aliases as a list: `aliases("val", "check")`
 /// * `tags(...)` - Associated tags as a list: `tags("core", "validation")`
 /// * `parameters(...)` - Parameter definitions (see below)
 /// * `responses(...)` - Response definitions (see below)
+/// * `external_docs(...)` - Link to the command documentation, serialized as `x-externalDocs`:
+///   `external_docs(url = "https://example.com/docs/validate", description = "Validate guide")`
+/// * `examples(...)` - Example invocations, serialized as `x-examples`:
+///   `examples((command = "ocs validate spec.yaml --strict", description = "Validate strictly"))`.
+///   Each example takes a `command` and an optional `description` and `output`
 /// * `extend(...)` - OpenAPI extensions: `extend(x_cli_category = "validation")`
+/// * `emit_source` - Add an `x-source` extension holding the fully-qualified path of the
+///   function (e.g., `my_cli::commands::validate_command`), for tools navigating from the
+///   specification to the code
 ///
 /// ## Parameter Definitions
 ///
@@ -571,6 +816,51 @@ pub fn derive_opencli(input: TokenStream) -> TokenStream {
 /// )
 /// ```
 ///
+/// Deprecated parameters are marked with `deprecated` (or `deprecated = true`), emitted as
+/// `x-deprecated`: `(name = "old-flag", in = "flag", deprecated)`. Parameters hidden from help
+/// output are marked with `hidden` (or `hidden = true`), emitted as `x-hidden`:
+/// `(name = "debug-dump", in = "flag", hidden)`.
+///
+/// Relationships between parameters are declared with `requires(...)` and `conflicts(...)`,
+/// emitted as the `x-requires` and `x-conflicts` extensions:
+/// `(name = "json", in = "flag", conflicts("yaml"))`.
+///
+/// The placeholder shown for a parameter value in help output is set with `value_name`,
+/// emitted as the `x-value-name` extension: `(name = "output", value_name = "FILE")`.
+/// Parameters are grouped under help sections with `section`, emitted as the `x-section`
+/// extension: `(name = "proxy", in = "option", section = "Networking")`.
+///
+/// Parameters taking an optional value (e.g., `--color[=<when>]`) declare the value used when
+/// it is missing with `default_missing_value`, emitted as the `x-default-missing` extension
+/// with an arity of zero or one value (unless `arity_min`/`arity_max` are given):
+/// `(name = "color", in = "option", default_missing_value = "always")`.
+///
+/// Flags distinguish presence flags from flags taking an explicit boolean with `flag_style`,
+/// `presence` or `value`, serialized as `x-flag-style`:
+/// `(name = "strict", in = "flag", flag_style = "presence")`.
+///
+/// The allowed values of a parameter can be taken from a `ToSchema` enum with
+/// `possible_values_from = Type`, generating a `$ref` to `#/components/schemas/{Type}`:
+/// `(name = "format", in = "option", possible_values_from = OutputFormat)`.
+///
+/// String parameters can be constrained with `pattern = "..."`, `min_length = N` and
+/// `max_length = N`, which are applied to the generated schema alongside `schema_format`.
+///
+/// `global` (or `global = true`) is shorthand for `scope = "inherited"`, mirroring clap's global
+/// arguments: `(name = "verbose", in = "flag", global)`. Global parameters cannot be positional.
+///
+/// A parameter can also reference a shared parameter component registered in the
+/// `OpenCli` derive `components(parameters(...))`, generating a `$ref` to
+/// `#/components/parameters/{name}` instead of an inline definition. The component defines the
+/// whole parameter, so `ref` cannot be combined with other attributes:
+///
+/// ```ignore
+/// parameters(
+///     (ref = "ConfigFile"),
+///     (name = "strict", in = "flag")
+/// )
+/// ```
+///
 /// ## Response Definitions
 ///
 /// ```ignore
@@ -585,6 +875,12 @@ pub fn derive_opencli(input: TokenStream) -> TokenStream {
 ///     )
 /// )
 /// ```
+///
+/// The status also accepts integer literals (`status = 1`), normalized to the `"1"` key.
+///
+/// Large examples can be loaded from a file with `example_file = "examples/success.json"`,
+/// resolved relative to `CARGO_MANIFEST_DIR`. JSON files are embedded as their JSON value,
+/// other files as text.
 #[proc_macro_attribute]
 pub fn command(args: TokenStream, input: TokenStream) -> TokenStream {
     let args: proc_macro2::TokenStream = args.into();
@@ -1,8 +1,13 @@
 //! OpenCli generation for OpenCli derive macro.
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DeriveInput, Lit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    DeriveInput, Lit, LitStr,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::{Bracket, Paren},
+};
 
 use crate::{
     diagnostics::{Diagnostics, ToTokensDiagnostics},
@@ -13,8 +18,11 @@ use crate::{
 #[derive(Default)]
 struct OpenCliAttributes {
     info_title: Option<String>,
-    info_version: Option<String>,
-    info_description: Option<String>,
+    info_version: Option<InfoValue>,
+    info_summary: Option<String>,
+    info_description: Option<InfoValue>,
+    info_terms_of_service: Option<String>,
+    info_author: Option<InfoValue>,
     info_contact: Option<ContactDef>,
     info_license: Option<LicenseDef>,
     external_docs: Option<ExternalDocsDef>,
@@ -25,12 +33,32 @@ struct OpenCliAttributes {
     tags: Vec<TagDef>,
     platforms: Vec<PlatformDef>,
     environment: Vec<EnvVarDef>,
+    /// Install commands keyed by package manager: `install(brew = "brew install ocs")`
+    install: Vec<(String, String)>,
+}
+
+/// An `info(...)` value: a literal, or a package metadata value when given as a bare flag.
+#[derive(Clone)]
+enum InfoValue {
+    Literal(String),
+    /// `env!` of a Cargo package environment variable, expanded at the call site
+    PackageEnv(&'static str),
+}
+
+impl ToTokens for InfoValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Literal(value) => value.to_tokens(tokens),
+            Self::PackageEnv(var) => tokens.extend(quote! { ::core::env!(#var) }),
+        }
+    }
 }
 
 #[derive(Clone)]
 struct TagDef {
     name: String,
     description: Option<String>,
+    external_docs: Option<ExternalDocsDef>,
 }
 
 #[derive(Clone)]
@@ -46,16 +74,361 @@ struct LicenseDef {
     url: Option<String>,
 }
 
+/// External documentation: `external_docs(url = "...", description = "...")`.
+///
+/// Shared by the `OpenCli` derive (root and tags) and the `command` macro.
 #[derive(Clone)]
-struct ExternalDocsDef {
+pub(crate) struct ExternalDocsDef {
     url: String,
     description: Option<String>,
 }
 
 #[derive(Clone)]
 struct PlatformDef {
-    name: String,
-    architectures: Vec<String>,
+    name: PlatformValue,
+    architectures: Vec<PlatformValue>,
+}
+
+/// A platform name or architecture: a known value, or a custom one given as `other("...")`.
+///
+/// Known values are validated so typos are reported, while `other("...")` intentionally
+/// documents values utocli has no variant for.
+#[derive(Clone, Debug, PartialEq)]
+enum PlatformValue {
+    Known(String),
+    Other(String),
+}
+
+impl PlatformValue {
+    /// Parses an identifier, a string literal or `other("...")`, validating known values.
+    fn parse(input: ParseStream, validate: fn(&str, Span) -> syn::Result<()>) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            validate(&lit.value(), lit.span())?;
+            return Ok(Self::Known(lit.value()));
+        }
+
+        let ident = input.parse::<syn::Ident>().map_err(|error| -> syn::Error {
+            Diagnostics::with_span(error.span(), "expected a platform name or architecture")
+                .help("Use a name like linux or amd64, or other(\"...\") for a custom value")
+                .note("Example: platforms(linux, (name = \"darwin\", architectures(arm64)))")
+                .into()
+        })?;
+        if ident == "other" && input.peek(Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let lit = content.parse::<LitStr>()?;
+            return Ok(Self::Other(lit.value().to_lowercase()));
+        }
+
+        validate(&ident.to_string(), ident.span())?;
+        Ok(Self::Known(ident.to_string()))
+    }
+}
+
+impl Parse for ExternalDocsDef {
+    /// Parses the parenthesized attributes following `external_docs`.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected external_docs attribute, expected any of: url, description";
+
+        let content;
+        let paren = syn::parenthesized!(content in input);
+
+        let mut url: Option<String> = None;
+        let mut description: Option<String> = None;
+
+        while !content.is_empty() {
+            let ident = content
+                .parse::<syn::Ident>()
+                .map_err(|error| -> syn::Error {
+                    Diagnostics::with_span(error.span(), format!("{EXPECTED_ATTRIBUTE}, {error}"))
+                        .help("Valid external_docs attributes: url, description")
+                        .into()
+                })?;
+
+            if ident == "url" {
+                content.parse::<syn::Token![=]>()?;
+                url = Some(content.parse::<LitStr>()?.value());
+            } else if ident == "description" {
+                content.parse::<syn::Token![=]>()?;
+                description = Some(content.parse::<LitStr>()?.value());
+            } else {
+                return Err(Diagnostics::with_span(ident.span(), EXPECTED_ATTRIBUTE)
+                    .help("Valid external_docs attributes: url, description")
+                    .into());
+            }
+
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        let Some(url) = url else {
+            return Err(Diagnostics::with_span(
+                paren.span.join(),
+                "missing expected `url` external_docs attribute",
+            )
+            .help("Link the documentation: external_docs(url = \"https://example.com/docs\")")
+            .into());
+        };
+
+        Ok(Self { url, description })
+    }
+}
+
+impl ToTokens for ExternalDocsDef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let url = &self.url;
+        let desc_tokens = self
+            .description
+            .as_ref()
+            .map(|desc| quote! { .description(#desc) });
+        tokens.extend(quote! {
+            ::utocli::ExternalDocs::new(#url) #desc_tokens
+        });
+    }
+}
+
+impl Parse for PlatformDef {
+    /// Parses a platform: either a bare name (`linux`) or a tuple with architectures
+    /// (`(name = "linux", architectures(amd64, arm64))`).
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected platform attribute, expected any of: name, architectures";
+
+        if !input.peek(Paren) {
+            // Simple platform name: linux, darwin, windows
+            return Ok(Self {
+                name: PlatformValue::parse(input, validate_platform_name)?,
+                architectures: Vec::new(),
+            });
+        }
+
+        // Platform with architectures: (name = "linux", architectures(amd64, arm64))
+        let content;
+        let paren = syn::parenthesized!(content in input);
+
+        let mut name: Option<PlatformValue> = None;
+        let mut architectures: Vec<PlatformValue> = Vec::new();
+
+        while !content.is_empty() {
+            let ident = content
+                .parse::<syn::Ident>()
+                .map_err(|error| -> syn::Error {
+                    Diagnostics::with_span(error.span(), format!("{EXPECTED_ATTRIBUTE}, {error}"))
+                        .help("Valid platform attributes: name, architectures")
+                        .note("Example: (name = \"linux\", architectures(amd64, arm64))")
+                        .into()
+                })?;
+
+            if ident == "name" {
+                content.parse::<syn::Token![=]>()?;
+                name = Some(PlatformValue::parse(&content, validate_platform_name)?);
+            } else if ident == "architectures" {
+                architectures.extend(parse_architectures(&content, &ident)?);
+            } else {
+                return Err(Diagnostics::with_span(ident.span(), EXPECTED_ATTRIBUTE)
+                    .help("Valid platform attributes: name, architectures")
+                    .note("Example: (name = \"linux\", architectures(amd64, arm64))")
+                    .into());
+            }
+
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        let Some(name) = name else {
+            return Err(Diagnostics::with_span(
+                paren.span.join(),
+                "missing expected `name` platform attribute",
+            )
+            .help("Name the platform: (name = \"linux\", architectures(amd64))")
+            .into());
+        };
+
+        Ok(Self {
+            name,
+            architectures,
+        })
+    }
+}
+
+/// Parses the architectures of a platform, given as a list (`architectures(amd64, arm64)`)
+/// or as an array of strings (`architectures = ["amd64", "arm64"]`).
+fn parse_architectures(input: ParseStream, ident: &syn::Ident) -> syn::Result<Vec<PlatformValue>> {
+    let expected_list = || -> syn::Error {
+        Diagnostics::with_span(ident.span(), "expected a list of architectures")
+            .help("Use architectures(amd64, arm64) or architectures = [\"amd64\", \"arm64\"]")
+            .note("A platform may support several architectures, so a single value is not accepted")
+            .into()
+    };
+    let parse_architecture =
+        |input: ParseStream| PlatformValue::parse(input, validate_architecture);
+
+    let content;
+    if input.peek(Paren) {
+        syn::parenthesized!(content in input);
+    } else {
+        if !input.peek(syn::Token![=]) {
+            return Err(expected_list());
+        }
+        input.parse::<syn::Token![=]>()?;
+        if !input.peek(Bracket) {
+            return Err(expected_list());
+        }
+        syn::bracketed!(content in input);
+    }
+
+    let architectures = Punctuated::<PlatformValue, syn::Token![,]>::parse_terminated_with(
+        &content,
+        parse_architecture,
+    )?;
+    Ok(architectures.into_iter().collect())
+}
+
+/// Platform names accepted by `platforms(...)`, including the `macos` alias of `darwin`.
+const PLATFORM_NAMES: &[&str] = &[
+    "linux",
+    "darwin",
+    "macos",
+    "windows",
+    "freebsd",
+    "netbsd",
+    "openbsd",
+    "dragonfly",
+    "solaris",
+    "aix",
+    "android",
+    "ios",
+];
+
+/// Architectures accepted by `architectures(...)`, including the `x86_64`, `aarch64` and
+/// `i386` aliases.
+const ARCHITECTURES: &[&str] = &[
+    "amd64",
+    "x86_64",
+    "arm64",
+    "aarch64",
+    "x86",
+    "i386",
+    "386",
+    "arm",
+    "armv5te",
+    "armv7",
+    "thumbv7",
+    "ppc64",
+    "ppc64le",
+    "powerpc",
+    "powerpc64",
+    "powerpc64le",
+    "mips",
+    "mipsel",
+    "mips64",
+    "mips64el",
+    "s390x",
+    "riscv64",
+    "riscv32",
+    "wasm32",
+    "wasm64",
+    "sparc64",
+    "hexagon",
+    "loongarch64",
+];
+
+/// Rejects platform names that don't map to a `PlatformName`, rather than silently
+/// defaulting them.
+fn validate_platform_name(name: &str, span: Span) -> syn::Result<()> {
+    if PLATFORM_NAMES.contains(&name) {
+        return Ok(());
+    }
+    Err(
+        Diagnostics::with_span(span, format!("unknown platform name `{name}`"))
+            .help(format!(
+                "Valid platform names: {}",
+                PLATFORM_NAMES.join(", ")
+            ))
+            .into(),
+    )
+}
+
+/// Rejects architectures that don't map to an `Architecture`, rather than silently
+/// defaulting them.
+fn validate_architecture(arch: &str, span: Span) -> syn::Result<()> {
+    if ARCHITECTURES.contains(&arch) {
+        return Ok(());
+    }
+    Err(
+        Diagnostics::with_span(span, format!("unknown architecture `{arch}`"))
+            .help(format!("Valid architectures: {}", ARCHITECTURES.join(", ")))
+            .into(),
+    )
+}
+
+/// Maps a platform name to its `PlatformName` variant.
+fn platform_name_tokens(name: &PlatformValue) -> TokenStream {
+    let name = match name {
+        PlatformValue::Known(name) => name.as_str(),
+        PlatformValue::Other(name) => {
+            return quote! { ::utocli::PlatformName::from_name(#name) };
+        }
+    };
+    let variant = match name {
+        "linux" => quote! { Linux },
+        "darwin" => quote! { Darwin },
+        "macos" => quote! { Macos },
+        "windows" => quote! { Windows },
+        "freebsd" => quote! { Freebsd },
+        "netbsd" => quote! { Netbsd },
+        "openbsd" => quote! { Openbsd },
+        "dragonfly" => quote! { Dragonfly },
+        "solaris" => quote! { Solaris },
+        "aix" => quote! { Aix },
+        "android" => quote! { Android },
+        "ios" => quote! { Ios },
+        _ => unreachable!("platform names are validated while parsing"),
+    };
+    quote! { ::utocli::PlatformName::#variant }
+}
+
+/// Maps an architecture to its `Architecture` variant.
+fn architecture_tokens(arch: &PlatformValue) -> TokenStream {
+    let arch = match arch {
+        PlatformValue::Known(arch) => arch.as_str(),
+        PlatformValue::Other(arch) => {
+            return quote! { ::utocli::Architecture::from_name(#arch) };
+        }
+    };
+    let variant = match arch {
+        "amd64" | "x86_64" => quote! { Amd64 },
+        "arm64" | "aarch64" => quote! { Arm64 },
+        "x86" | "i386" => quote! { X86 },
+        "386" => quote! { I386 },
+        "arm" => quote! { Arm },
+        "armv5te" => quote! { Armv5te },
+        "armv7" => quote! { Armv7 },
+        "thumbv7" => quote! { Thumbv7 },
+        "ppc64" => quote! { Ppc64 },
+        "ppc64le" => quote! { Ppc64le },
+        "powerpc" => quote! { Powerpc },
+        "powerpc64" => quote! { Powerpc64 },
+        "powerpc64le" => quote! { Powerpc64le },
+        "mips" => quote! { Mips },
+        "mipsel" => quote! { Mipsel },
+        "mips64" => quote! { Mips64 },
+        "mips64el" => quote! { Mips64el },
+        "s390x" => quote! { S390x },
+        "riscv64" => quote! { Riscv64 },
+        "riscv32" => quote! { Riscv32 },
+        "wasm32" => quote! { Wasm32 },
+        "wasm64" => quote! { Wasm64 },
+        "sparc64" => quote! { Sparc64 },
+        "hexagon" => quote! { Hexagon },
+        "loongarch64" => quote! { Loongarch64 },
+        _ => unreachable!("architectures are validated while parsing"),
+    };
+    quote! { ::utocli::Architecture::#variant }
 }
 
 #[allow(dead_code)]
@@ -151,6 +524,25 @@ impl OpenCliAttributes {
                                         });
                                     }
                                 }
+                            } else if content.is_empty() || content.peek(syn::Token![,]) {
+                                // Bare flag reading the value from the package metadata
+                                if ident == "version" {
+                                    result.info_version =
+                                        Some(InfoValue::PackageEnv("CARGO_PKG_VERSION"));
+                                } else if ident == "description" {
+                                    result.info_description =
+                                        Some(InfoValue::PackageEnv("CARGO_PKG_DESCRIPTION"));
+                                } else if ident == "author" {
+                                    result.info_author =
+                                        Some(InfoValue::PackageEnv("CARGO_PKG_AUTHORS"));
+                                } else {
+                                    return Err(syn::Error::new(
+                                        ident.span(),
+                                        format!(
+                                            "`{ident}` requires a value, only version, description and author can be used as bare flags"
+                                        ),
+                                    ));
+                                }
                             } else {
                                 // Simple key = value
                                 let _: syn::Token![=] = content.parse()?;
@@ -163,11 +555,23 @@ impl OpenCliAttributes {
                                 } else if ident == "version"
                                     && let Lit::Str(ref s) = lit
                                 {
-                                    result.info_version = Some(s.value());
+                                    result.info_version = Some(InfoValue::Literal(s.value()));
                                 } else if ident == "description"
                                     && let Lit::Str(ref s) = lit
                                 {
-                                    result.info_description = Some(s.value());
+                                    result.info_description = Some(InfoValue::Literal(s.value()));
+                                } else if ident == "summary"
+                                    && let Lit::Str(ref s) = lit
+                                {
+                                    result.info_summary = Some(s.value());
+                                } else if ident == "terms_of_service"
+                                    && let Lit::Str(ref s) = lit
+                                {
+                                    result.info_terms_of_service = Some(s.value());
+                                } else if ident == "author"
+                                    && let Lit::Str(ref s) = lit
+                                {
+                                    result.info_author = Some(InfoValue::Literal(s.value()));
                                 }
                             }
 
@@ -242,9 +646,18 @@ impl OpenCliAttributes {
 
                             let mut tag_name: Option<String> = None;
                             let mut tag_desc: Option<String> = None;
+                            let mut tag_external_docs: Option<ExternalDocsDef> = None;
 
                             while !tag_content.is_empty() {
                                 let ident: syn::Ident = tag_content.parse()?;
+                                if ident == "external_docs" {
+                                    tag_external_docs = Some(tag_content.parse()?);
+                                    if !tag_content.is_empty() {
+                                        let _: syn::Token![,] = tag_content.parse()?;
+                                    }
+                                    continue;
+                                }
+
                                 let _: syn::Token![=] = tag_content.parse()?;
                                 let lit: Lit = tag_content.parse()?;
 
@@ -267,6 +680,7 @@ impl OpenCliAttributes {
                                 result.tags.push(TagDef {
                                     name,
                                     description: tag_desc,
+                                    external_docs: tag_external_docs,
                                 });
                             }
 
@@ -280,57 +694,7 @@ impl OpenCliAttributes {
                         syn::parenthesized!(content in meta.input);
 
                         while !content.is_empty() {
-                            // Check if it's a nested object with architectures or just a platform name
-                            if content.peek(syn::token::Paren) {
-                                // Platform with architectures: (name = "linux", architectures(amd64, arm64))
-                                let platform_content;
-                                syn::parenthesized!(platform_content in content);
-
-                                let mut platform_name: Option<String> = None;
-                                let mut architectures: Vec<String> = Vec::new();
-
-                                while !platform_content.is_empty() {
-                                    let ident: syn::Ident = platform_content.parse()?;
-
-                                    if ident == "name" {
-                                        let _: syn::Token![=] = platform_content.parse()?;
-                                        let lit: Lit = platform_content.parse()?;
-                                        if let Lit::Str(ref s) = lit {
-                                            platform_name = Some(s.value());
-                                        }
-                                    } else if ident == "architectures" {
-                                        let arch_content;
-                                        syn::parenthesized!(arch_content in platform_content);
-
-                                        while !arch_content.is_empty() {
-                                            let arch: syn::Ident = arch_content.parse()?;
-                                            architectures.push(arch.to_string());
-
-                                            if !arch_content.is_empty() {
-                                                let _: syn::Token![,] = arch_content.parse()?;
-                                            }
-                                        }
-                                    }
-
-                                    if !platform_content.is_empty() {
-                                        let _: syn::Token![,] = platform_content.parse()?;
-                                    }
-                                }
-
-                                if let Some(name) = platform_name {
-                                    result.platforms.push(PlatformDef {
-                                        name,
-                                        architectures,
-                                    });
-                                }
-                            } else {
-                                // Simple platform name: linux, darwin, windows
-                                let ident: syn::Ident = content.parse()?;
-                                result.platforms.push(PlatformDef {
-                                    name: ident.to_string(),
-                                    architectures: Vec::new(),
-                                });
-                            }
+                            result.platforms.push(content.parse()?);
 
                             if !content.is_empty() {
                                 let _: syn::Token![,] = content.parse()?;
@@ -338,38 +702,19 @@ impl OpenCliAttributes {
                         }
                     } else if meta.path.is_ident("external_docs") {
                         // Parse external docs
-                        let content;
-                        syn::parenthesized!(content in meta.input);
-
-                        let mut url: Option<String> = None;
-                        let mut description: Option<String> = None;
-
-                        while !content.is_empty() {
-                            let ident: syn::Ident = content.parse()?;
-                            let _: syn::Token![=] = content.parse()?;
-                            let lit: Lit = content.parse()?;
-
-                            if ident == "url"
-                                && let Lit::Str(ref s) = lit
-                            {
-                                url = Some(s.value());
-                            } else if ident == "description"
-                                && let Lit::Str(ref s) = lit
-                            {
-                                description = Some(s.value());
-                            }
-
-                            if !content.is_empty() {
-                                let _: syn::Token![,] = content.parse()?;
-                            }
-                        }
-
-                        if let Some(url_str) = url {
-                            result.external_docs = Some(ExternalDocsDef {
-                                url: url_str,
-                                description,
-                            });
-                        }
+                        result.external_docs = Some(meta.input.parse()?);
+                    } else if meta.path.is_ident("install") {
+                        // Parse install commands: install(brew = "brew install ocs")
+                        meta.parse_nested_meta(|install| {
+                            let manager = install
+                                .path
+                                .get_ident()
+                                .ok_or_else(|| install.error("expected a package manager name"))?
+                                .to_string();
+                            let command: syn::LitStr = install.value()?.parse()?;
+                            result.install.push((manager, command.value()));
+                            Ok(())
+                        })?;
                     } else if meta.path.is_ident("environment") {
                         // Parse environment variables
                         let content;
@@ -451,12 +796,12 @@ impl ToTokensDiagnostics for OpenCli {
             .attributes
             .info_version
             .clone()
-            .unwrap_or_else(|| "1.0.0".to_string());
+            .unwrap_or_else(|| InfoValue::Literal("1.0.0".to_string()));
         let info_description = self
             .attributes
             .info_description
             .clone()
-            .or_else(|| parse_doc_comments(&self.input.attrs));
+            .or_else(|| parse_doc_comments(&self.input.attrs).map(InfoValue::Literal));
 
         let info_desc_tokens = if let Some(desc) = info_description {
             quote! { .description(#desc) }
@@ -464,6 +809,20 @@ impl ToTokensDiagnostics for OpenCli {
             quote! {}
         };
 
+        let info_summary_tokens = if let Some(summary) = &self.attributes.info_summary {
+            quote! { .summary(#summary) }
+        } else {
+            quote! {}
+        };
+
+        let info_terms_of_service_tokens =
+            if let Some(terms_of_service) = &self.attributes.info_terms_of_service {
+                quote! { .terms_of_service(#terms_of_service) }
+            } else {
+                quote! {}
+            };
+
+        // An explicit contact takes precedence over the one derived from the author
         let info_contact_tokens = if let Some(contact) = &self.attributes.info_contact {
             let contact_builder = {
                 let mut tokens = quote! { ::utocli::Contact::new() };
@@ -479,6 +838,8 @@ impl ToTokensDiagnostics for OpenCli {
                 tokens
             };
             quote! { .contact(#contact_builder) }
+        } else if let Some(author) = &self.attributes.info_author {
+            quote! { .contact(::utocli::Contact::from_author_string(#author)) }
         } else {
             quote! {}
         };
@@ -587,6 +948,17 @@ impl ToTokensDiagnostics for OpenCli {
                 }
             };
 
+        // Schemas marked `#[schema(inline)]` replace their references instead of being registered
+        let inline_schemas_tokens = schemas.iter().map(|schema| {
+            quote! {
+                let opencli = if <#schema as ::utocli::ToSchema>::inline() {
+                    opencli.inline_schema(#schema::schema_name(), #schema::schema())
+                } else {
+                    opencli
+                };
+            }
+        });
+
         // Generate tags
         let tags = &self.attributes.tags;
         let tags_tokens = if tags.is_empty() {
@@ -599,8 +971,12 @@ impl ToTokensDiagnostics for OpenCli {
                 } else {
                     quote! {}
                 };
+                let external_docs_tokens = tag
+                    .external_docs
+                    .as_ref()
+                    .map(|external_docs| quote! { .external_docs(#external_docs) });
                 quote! {
-                    ::utocli::Tag::new(#name) #desc_tokens
+                    ::utocli::Tag::new(#name) #desc_tokens #external_docs_tokens
                 }
             });
 
@@ -615,32 +991,11 @@ impl ToTokensDiagnostics for OpenCli {
             quote! {}
         } else {
             let platform_defs = platforms.iter().map(|platform_def| {
-                // Convert string name to PlatformName enum
-                let platform_enum = match platform_def.name.as_str() {
-                    "linux" => quote! { ::utocli::PlatformName::Linux },
-                    "darwin" => quote! { ::utocli::PlatformName::Darwin },
-                    "windows" => quote! { ::utocli::PlatformName::Windows },
-                    "freebsd" => quote! { ::utocli::PlatformName::Freebsd },
-                    "netbsd" => quote! { ::utocli::PlatformName::Netbsd },
-                    "openbsd" => quote! { ::utocli::PlatformName::Openbsd },
-                    "dragonfly" => quote! { ::utocli::PlatformName::Dragonfly },
-                    "solaris" => quote! { ::utocli::PlatformName::Solaris },
-                    "android" => quote! { ::utocli::PlatformName::Android },
-                    "ios" => quote! { ::utocli::PlatformName::Ios },
-                    _ => quote! { ::utocli::PlatformName::Linux }, // Default
-                };
+                let platform_enum = platform_name_tokens(&platform_def.name);
 
                 // Add architectures if present
                 let arch_tokens = if !platform_def.architectures.is_empty() {
-                    let archs = platform_def.architectures.iter().map(|arch| {
-                        match arch.as_str() {
-                            "amd64" | "x86_64" => quote! { ::utocli::Architecture::Amd64 },
-                            "arm64" | "aarch64" => quote! { ::utocli::Architecture::Arm64 },
-                            "x86" | "i386" => quote! { ::utocli::Architecture::X86 },
-                            "arm" => quote! { ::utocli::Architecture::Arm },
-                            _ => quote! { ::utocli::Architecture::Amd64 }, // Default
-                        }
-                    });
+                    let archs = platform_def.architectures.iter().map(architecture_tokens);
                     quote! { .architectures(vec![#(#archs),*]) }
                 } else {
                     quote! {}
@@ -678,16 +1033,17 @@ impl ToTokensDiagnostics for OpenCli {
             }
         };
 
+        // Generate install commands
+        let install_tokens = self
+            .attributes
+            .install
+            .iter()
+            .map(|(manager, command)| quote! { .install_command(#manager, #command) });
+
         // Generate external docs
         let external_docs_tokens = if let Some(ext_docs) = &self.attributes.external_docs {
-            let url = &ext_docs.url;
-            let desc_tokens = if let Some(desc) = &ext_docs.description {
-                quote! { .description(#desc) }
-            } else {
-                quote! {}
-            };
             quote! {
-                .external_docs(::utocli::ExternalDocs::new(#url) #desc_tokens)
+                .external_docs(#ext_docs)
             }
         } else {
             quote! {}
@@ -697,17 +1053,24 @@ impl ToTokensDiagnostics for OpenCli {
             impl #impl_generics ::utocli::OpenCli for #name #ty_generics #where_clause {
                 fn opencli() -> ::utocli::opencli::OpenCli {
                     let info = ::utocli::Info::new(#info_title, #info_version)
+                        #info_summary_tokens
                         #info_desc_tokens
+                        #info_terms_of_service_tokens
                         #info_contact_tokens
                         #info_license_tokens;
 
-                    ::utocli::opencli::OpenCli::new(info)
+                    let opencli = ::utocli::opencli::OpenCli::new(info)
                         .commands(#commands_tokens)
                         #components_tokens
                         #tags_tokens
                         #platforms_tokens
                         #environment_tokens
-                        #external_docs_tokens
+                        #(#install_tokens)*
+                        #external_docs_tokens;
+
+                    #(#inline_schemas_tokens)*
+
+                    opencli
                 }
             }
         });
@@ -715,3 +1078,192 @@ impl ToTokensDiagnostics for OpenCli {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{ExternalDocsDef, OpenCliAttributes, PlatformDef, PlatformValue};
+
+    #[test]
+    fn opencli_attributes_with_bare_info_version_expands_package_version_env() {
+        //* Given
+        let attrs: Vec<syn::Attribute> =
+            vec![syn::parse_quote! { #[opencli(info(title = "app", version))] }];
+
+        //* When
+        let Ok(attributes) = OpenCliAttributes::parse(&attrs) else {
+            panic!("should parse attributes");
+        };
+
+        //* Then
+        let version = attributes.info_version.expect("should set the version");
+        assert_eq!(
+            quote! { #version }.to_string(),
+            quote! { ::core::env!("CARGO_PKG_VERSION") }.to_string(),
+            "bare version should reference the package version at the call site"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_architecture_array_parses_architectures() {
+        //* Given
+        let tokens = quote! { (name = "linux", architectures = ["amd64", "arm64"]) };
+
+        //* When
+        let platform = syn::parse2::<PlatformDef>(tokens).expect("should parse platform");
+
+        //* Then
+        assert_eq!(
+            platform.name,
+            PlatformValue::Known("linux".to_string()),
+            "should parse the platform name"
+        );
+        assert_eq!(
+            platform.architectures,
+            vec![
+                PlatformValue::Known("amd64".to_string()),
+                PlatformValue::Known("arm64".to_string())
+            ],
+            "should parse the architectures array"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_other_values_parses_custom_name_and_architecture() {
+        //* Given
+        let tokens = quote! { (name = other("Haiku"), architectures(amd64, other("e2k"))) };
+
+        //* When
+        let platform = syn::parse2::<PlatformDef>(tokens).expect("should parse platform");
+
+        //* Then
+        assert_eq!(
+            platform.name,
+            PlatformValue::Other("haiku".to_string()),
+            "other platform name should be kept lowercase"
+        );
+        assert_eq!(
+            platform.architectures,
+            vec![
+                PlatformValue::Known("amd64".to_string()),
+                PlatformValue::Other("e2k".to_string())
+            ],
+            "other architecture should be kept alongside known ones"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_unknown_platform_name_fails() {
+        //* Given
+        let tokens = quote! { (name = "windwos", architectures(amd64)) };
+
+        //* When
+        let result = syn::parse2::<PlatformDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("a misspelled platform should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("unknown platform name `windwos`"),
+            "error should name the unknown platform"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_unknown_architecture_fails() {
+        //* Given
+        let tokens = quote! { (name = "linux", architectures = ["amd65"]) };
+
+        //* When
+        let result = syn::parse2::<PlatformDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("a misspelled architecture should be rejected");
+        assert!(
+            error.to_string().contains("unknown architecture `amd65`"),
+            "error should name the unknown architecture"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_single_architecture_value_fails() {
+        //* Given
+        let tokens = quote! { (name = "linux", architectures = "amd64") };
+
+        //* When
+        let result = syn::parse2::<PlatformDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("a single architecture should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("expected a list of architectures"),
+            "error should ask for a list of architectures"
+        );
+    }
+
+    #[test]
+    fn platform_def_with_unknown_attribute_fails() {
+        //* Given
+        let tokens = quote! { (name = "linux", arch(amd64)) };
+
+        //* When
+        let result = syn::parse2::<PlatformDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("an unknown attribute should be rejected");
+        assert!(
+            error.to_string().contains("unexpected platform attribute"),
+            "error should name the unexpected attribute"
+        );
+    }
+
+    #[test]
+    fn platform_def_without_name_fails() {
+        //* Given
+        let tokens = quote! { (architectures(amd64)) };
+
+        //* When
+        let result = syn::parse2::<PlatformDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("a platform without name should be rejected");
+        assert!(
+            error.to_string().contains("missing expected `name`"),
+            "error should report the missing name"
+        );
+    }
+
+    #[test]
+    fn external_docs_def_without_url_fails_to_parse() {
+        //* Given
+        let tokens = quote! { (description = "Core guide") };
+
+        //* When
+        let result = syn::parse2::<ExternalDocsDef>(tokens);
+
+        //* Then
+        let error = result
+            .err()
+            .expect("external docs without url should fail to parse");
+        assert!(
+            error
+                .to_string()
+                .contains("missing expected `url` external_docs attribute"),
+            "should report the missing url, got: {error}"
+        );
+    }
+}
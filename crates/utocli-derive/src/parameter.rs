@@ -1,7 +1,7 @@
 //! Parameter generation for ToParameter derive macro.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{ToTokens, quote};
 use syn::{Data, DeriveInput, Fields, Lit};
 
 use crate::{
@@ -9,15 +9,49 @@ use crate::{
     diagnostics::{Diagnostics, ToTokensDiagnostics},
     doc_comment::parse_doc_comments,
     parse_utils,
+    schema::serde::RenameRule,
 };
 
+/// Parsed container attributes from `#[param(...)]` on the struct.
+#[derive(Default)]
+struct ParameterContainerAttributes {
+    /// Rename rule applied to every parameter name without an explicit `long`/`rename`
+    rename_all: Option<RenameRule>,
+}
+
+impl ParameterContainerAttributes {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self, Diagnostics> {
+        let mut result = Self::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("param") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename_all") {
+                        let value = meta.value()?;
+                        let lit: syn::LitStr = value.parse()?;
+                        result.rename_all = Some(RenameRule::from_str(&lit.value())?);
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 /// Parsed parameter attributes from `#[param(...)]`.
 /// Matches utoipa's pattern for using AnyValue for example/default
 #[derive(Default)]
 struct ParameterAttributes {
+    /// Parameter name override, following clap's `long`
+    long: Option<String>,
     alias: Option<Vec<String>>,
     description: Option<String>,
+    /// Help text, following clap's `help`
+    help: Option<String>,
     scope: Option<String>,
+    env: Option<String>,
     position: Option<u32>,
     in_: Option<String>,
     format: Option<String>,
@@ -29,7 +63,25 @@ struct ParameterAttributes {
     /// Matches utoipa-gen/src/component/features/attributes.rs line 31557
     example: Option<AnyValue>,
     skip: bool,
+    deprecated: bool,
+    /// Hide the parameter from help output, following clap's `hide`
+    hidden: bool,
+    /// Fields or parameters that must be given along, following clap's `requires`
+    requires: Vec<String>,
+    /// Fields or parameters that cannot be given along, following clap's `conflicts_with`
+    conflicts: Vec<String>,
+    /// Help placeholder for the value, following clap's `value_name`
+    value_name: Option<String>,
+    /// Help section the parameter is grouped under, following clap's `help_heading`
+    section: Option<String>,
+    /// Value used when the parameter is given without one, following clap's
+    /// `default_missing_value`
+    default_missing_value: Option<String>,
+    /// How a flag is given, `presence` (clap's `SetTrue`) or `value`
+    flag_style: Option<TokenStream>,
     schema_with: Option<syn::TypePath>,
+    /// Schema type whose component holds the allowed values
+    possible_values_from: Option<syn::TypePath>,
     minimum: Option<f64>,
     maximum: Option<f64>,
     min_length: Option<usize>,
@@ -51,12 +103,43 @@ impl ParameterAttributes {
         for attr in attrs {
             if attr.path().is_ident("param") {
                 attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("alias") {
+                    if (meta.path.is_ident("alias") || meta.path.is_ident("visible_alias"))
+                        && meta.input.peek(syn::token::Paren)
+                    {
+                        // List form: alias("a", "all"), accumulating with the scalar form
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let aliases = content.parse_terminated(
+                            |input| input.parse::<syn::LitStr>(),
+                            syn::Token![,],
+                        )?;
+                        result
+                            .alias
+                            .get_or_insert_with(Vec::new)
+                            .extend(aliases.iter().map(syn::LitStr::value));
+                    } else if meta.path.is_ident("alias") || meta.path.is_ident("visible_alias") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.alias.get_or_insert_with(Vec::new).push(s.value());
+                        }
+                    } else if meta.path.is_ident("short") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        let short = match lit {
+                            Lit::Char(c) => Some(c.value().to_string()),
+                            Lit::Str(s) => Some(s.value()),
+                            _ => None,
+                        };
+                        if let Some(short) = short {
+                            // The short flag comes first among the aliases, as in clap's help
+                            result.alias.get_or_insert_with(Vec::new).insert(0, short);
+                        }
+                    } else if meta.path.is_ident("long") || meta.path.is_ident("rename") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
                         if let Lit::Str(s) = lit {
-                            let alias_str = s.value();
-                            result.alias = Some(vec![alias_str]);
+                            result.long = Some(s.value());
                         }
                     } else if meta.path.is_ident("description") {
                         let value = meta.value()?;
@@ -64,12 +147,24 @@ impl ParameterAttributes {
                         if let Lit::Str(s) = lit {
                             result.description = Some(s.value());
                         }
+                    } else if meta.path.is_ident("help") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.help = Some(s.value());
+                        }
                     } else if meta.path.is_ident("scope") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
                         if let Lit::Str(s) = lit {
                             result.scope = Some(s.value());
                         }
+                    } else if meta.path.is_ident("env") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.env = Some(s.value());
+                        }
                     } else if meta.path.is_ident("position") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
@@ -108,6 +203,16 @@ impl ParameterAttributes {
                         result.default = Some(parse_utils::parse_next(meta.input, || {
                             AnyValue::parse_any(meta.input)
                         })?);
+                    } else if meta.path.is_ident("default_value") {
+                        // clap's `default_value`: the default as its string representation
+                        let value = meta.value()?;
+                        let lit: syn::LitStr = value.parse()?;
+                        result.default = Some(AnyValue::Json(lit.to_token_stream()));
+                    } else if meta.path.is_ident("default_value_t") {
+                        // clap's `default_value_t`: a typed default expression, emitted as JSON
+                        let value = meta.value()?;
+                        let expr: syn::Expr = value.parse()?;
+                        result.default = Some(AnyValue::Json(expr.to_token_stream()));
                     } else if meta.path.is_ident("example") {
                         // Matches utoipa-gen/src/component/features/attributes.rs line 31562
                         result.example = Some(parse_utils::parse_next(meta.input, || {
@@ -115,9 +220,54 @@ impl ParameterAttributes {
                         })?);
                     } else if meta.path.is_ident("skip") {
                         result.skip = true;
+                    } else if meta.path.is_ident("deprecated") {
+                        // `deprecated` or `deprecated = <bool>`, the spec has no deprecation note
+                        result.deprecated = if meta.input.peek(syn::Token![=]) {
+                            match meta.value()?.parse::<Lit>()? {
+                                Lit::Bool(b) => b.value(),
+                                lit => {
+                                    return Err(syn::Error::new(
+                                        lit.span(),
+                                        "expected `deprecated` or `deprecated = <bool>`, deprecation notes are not supported: document the note in the parameter description",
+                                    ));
+                                }
+                            }
+                        } else {
+                            true
+                        };
+                    } else if meta.path.is_ident("requires") {
+                        result.requires.extend(parse_names(&meta)?);
+                    } else if meta.path.is_ident("conflicts_with")
+                        || meta.path.is_ident("conflicts")
+                    {
+                        result.conflicts.extend(parse_names(&meta)?);
+                    } else if meta.path.is_ident("value_name") {
+                        let value = meta.value()?;
+                        result.value_name = Some(value.parse::<syn::LitStr>()?.value());
+                    } else if meta.path.is_ident("section") || meta.path.is_ident("help_heading") {
+                        // Accepts both `section` and clap's `help_heading`
+                        let value = meta.value()?;
+                        result.section = Some(value.parse::<syn::LitStr>()?.value());
+                    } else if meta.path.is_ident("default_missing_value") {
+                        let value = meta.value()?;
+                        result.default_missing_value = Some(value.parse::<syn::LitStr>()?.value());
+                    } else if meta.path.is_ident("flag_style") {
+                        let value = meta.value()?;
+                        result.flag_style = Some(flag_style_tokens(&value.parse()?)?);
+                    } else if meta.path.is_ident("hidden") || meta.path.is_ident("hide") {
+                        // Accepts both `hidden` and clap's `hide = true`
+                        result.hidden = if meta.input.peek(syn::Token![=]) {
+                            let value = meta.value()?;
+                            value.parse::<syn::LitBool>()?.value()
+                        } else {
+                            true
+                        };
                     } else if meta.path.is_ident("schema_with") {
                         let value = meta.value()?;
                         result.schema_with = Some(value.parse()?);
+                    } else if meta.path.is_ident("possible_values_from") {
+                        let value = meta.value()?;
+                        result.possible_values_from = Some(value.parse()?);
                     } else if meta.path.is_ident("minimum") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
@@ -232,7 +382,10 @@ impl ToTokensDiagnostics for Parameter {
 
         // Generate parameters based on data structure
         let params_impl = match &self.input.data {
-            Data::Struct(data_struct) => self.generate_struct_parameters(&data_struct.fields)?,
+            Data::Struct(data_struct) => {
+                let container_attrs = ParameterContainerAttributes::parse(&self.input.attrs)?;
+                self.generate_struct_parameters(&data_struct.fields, &container_attrs)?
+            }
             Data::Enum(_) => {
                 return Err(Diagnostics::new("ToParameter cannot be derived for enums")
                     .help("ToParameter can only be derived for structs with named fields")
@@ -273,11 +426,16 @@ impl ToTokensDiagnostics for Parameter {
 }
 
 impl Parameter {
-    fn generate_struct_parameters(&self, fields: &Fields) -> Result<TokenStream, Diagnostics> {
+    fn generate_struct_parameters(
+        &self,
+        fields: &Fields,
+        container_attrs: &ParameterContainerAttributes,
+    ) -> Result<TokenStream, Diagnostics> {
         match fields {
             Fields::Named(named_fields) => {
                 let mut parameters = Vec::new();
 
+                let mut fields = Vec::new();
                 for field in &named_fields.named {
                     let field_attrs = ParameterAttributes::parse(&field.attrs)?;
 
@@ -286,11 +444,43 @@ impl Parameter {
                     }
 
                     let field_name = field.ident.as_ref().unwrap();
-                    let field_name_str = field_name.to_string();
+                    let field_name_str = field_attrs.long.clone().unwrap_or_else(|| {
+                        let field_name = field_name.to_string();
+                        match container_attrs.rename_all {
+                            Some(rule) => rule.apply(&field_name),
+                            None => field_name,
+                        }
+                    });
+                    fields.push((field, field_attrs, field_name_str));
+                }
+
+                // Related parameters are given by field name, as in clap, or by parameter name
+                let parameter_name = |name: &String| {
+                    fields
+                        .iter()
+                        .find(|(field, ..)| field.ident.as_ref().is_some_and(|ident| ident == name))
+                        .map_or_else(|| name.clone(), |(_, _, name)| name.clone())
+                };
+
+                for (field, field_attrs, field_name_str) in &fields {
+                    let field_name = field.ident.as_ref().unwrap();
+
+                    if field_attrs.in_.as_deref() == Some("environment")
+                        && field_attrs.position.is_some()
+                    {
+                        return Err(Diagnostics::with_span(
+                            field_name.span(),
+                            "environment parameters cannot be positional",
+                        )
+                        .help("Remove `position` or declare the parameter as an argument")
+                        .note("Environment parameters are read from the environment variable named after the parameter"));
+                    }
 
                     let ty = &field.ty;
                     let is_optional = is_option_type(ty);
                     let is_bool = is_bool_type(ty);
+                    // A positional `Vec<T>` collects every trailing argument
+                    let is_variadic = field_attrs.position.is_some() && is_vec_type(ty);
 
                     // Determine parameter type based on type and attributes
                     let param_in = if let Some(in_val) = &field_attrs.in_ {
@@ -298,6 +488,7 @@ impl Parameter {
                             "argument" => quote! { Some(ParameterIn::Argument) },
                             "flag" => quote! { Some(ParameterIn::Flag) },
                             "option" => quote! { Some(ParameterIn::Option) },
+                            "environment" => quote! { Some(ParameterIn::Environment) },
                             _ => quote! { None },
                         }
                     } else if is_bool {
@@ -309,10 +500,11 @@ impl Parameter {
                         quote! { None }
                     };
 
-                    // Get description from attributes or doc comments
+                    // Get description from attributes, clap-style help or doc comments
                     let description = field_attrs
                         .description
                         .clone()
+                        .or_else(|| field_attrs.help.clone())
                         .or_else(|| parse_doc_comments(&field.attrs))
                         .map(|d| quote! { Some(#d.to_string()) })
                         .unwrap_or_else(|| quote! { None });
@@ -333,22 +525,79 @@ impl Parameter {
                         quote! { None }
                     };
 
+                    let env = if let Some(env) = &field_attrs.env {
+                        quote! { Some(#env.to_string()) }
+                    } else {
+                        quote! { None }
+                    };
+
                     let position = if let Some(pos) = field_attrs.position {
                         quote! { Some(#pos) }
                     } else {
                         quote! { None }
                     };
 
-                    let required = if !is_optional && field_attrs.position.is_some() {
+                    // A positional with a default value can be omitted, so it is not required
+                    let required = if !is_optional
+                        && !is_variadic
+                        && field_attrs.position.is_some()
+                        && field_attrs.default.is_none()
+                    {
+                        quote! { Some(true) }
+                    } else {
+                        quote! { None }
+                    };
+
+                    let deprecated = if field_attrs.deprecated {
+                        quote! { Some(true) }
+                    } else {
+                        quote! { None }
+                    };
+
+                    let hidden = if field_attrs.hidden {
                         quote! { Some(true) }
                     } else {
                         quote! { None }
                     };
 
+                    let requires = if field_attrs.requires.is_empty() {
+                        quote! { None }
+                    } else {
+                        let requires = field_attrs.requires.iter().map(parameter_name);
+                        quote! { Some(vec![#(#requires.to_string()),*]) }
+                    };
+
+                    let conflicts = if field_attrs.conflicts.is_empty() {
+                        quote! { None }
+                    } else {
+                        let conflicts = field_attrs.conflicts.iter().map(parameter_name);
+                        quote! { Some(vec![#(#conflicts.to_string()),*]) }
+                    };
+
+                    let value_name = if let Some(value_name) = &field_attrs.value_name {
+                        quote! { Some(#value_name.to_string()) }
+                    } else {
+                        quote! { None }
+                    };
+
+                    let section = if let Some(section) = &field_attrs.section {
+                        quote! { Some(#section.to_string()) }
+                    } else {
+                        quote! { None }
+                    };
+
                     // Use schema_with if provided, otherwise generate schema from type
-                    let schema = if let Some(schema_with) = field_attrs.schema_with {
+                    let schema = if let Some(schema_with) = &field_attrs.schema_with {
                         // Call the custom schema function
                         quote! { Some(#schema_with()) }
+                    } else if let Some(values_type) = &field_attrs.possible_values_from {
+                        // The enum values are only known at runtime, reference the component
+                        quote! {
+                            Some(RefOr::new_ref(format!(
+                                "#/components/schemas/{}",
+                                <#values_type as ::utocli::ToSchema>::schema_name()
+                            )))
+                        }
                     } else {
                         // Generate basic schema based on type
                         let schema_type = if is_bool || (is_optional && is_inner_bool_type(ty)) {
@@ -363,18 +612,9 @@ impl Parameter {
                         };
 
                         if let Some(format_str) = &field_attrs.format {
-                            let format_ident = syn::Ident::new(
-                                &(format_str
-                                    .chars()
-                                    .next()
-                                    .unwrap()
-                                    .to_uppercase()
-                                    .collect::<String>()
-                                    + &format_str[1..]),
-                                proc_macro2::Span::call_site(),
-                            );
+                            let format = crate::schema::schema_format_tokens(format_str);
                             object_builder.extend(quote! {
-                                obj = obj.format(::utocli::SchemaFormat::#format_ident);
+                                obj = obj.format(#format);
                             });
                         }
 
@@ -472,6 +712,26 @@ impl Parameter {
                         }
                     };
 
+                    let variadic = if is_variadic {
+                        quote! { .variadic() }
+                    } else {
+                        quote! {}
+                    };
+
+                    // Sets the optional value arity along with the value
+                    let default_missing_value =
+                        if let Some(value) = &field_attrs.default_missing_value {
+                            quote! { .default_missing_value(#value) }
+                        } else {
+                            quote! {}
+                        };
+
+                    let flag_style = if let Some(flag_style) = &field_attrs.flag_style {
+                        quote! { .flag_style(#flag_style) }
+                    } else {
+                        quote! {}
+                    };
+
                     parameters.push(quote! {
                         Parameter {
                             name: #field_name_str.to_string(),
@@ -480,11 +740,24 @@ impl Parameter {
                             alias: #alias,
                             description: #description,
                             required: #required,
+                            deprecated: #deprecated,
+                            hidden: #hidden,
+                            requires: #requires,
+                            conflicts: #conflicts,
+                            value_name: #value_name,
+                            section: #section,
+                            default_missing_value: None,
+                            flag_style: None,
                             scope: #scope,
+                            env: #env,
                             arity: None,
+                            variadic: None,
                             schema: #schema,
                             extensions: None,
                         }
+                        #variadic
+                        #default_missing_value
+                        #flag_style
                     });
                 }
 
@@ -502,6 +775,33 @@ impl Parameter {
     }
 }
 
+/// Maps a `flag_style = "..."` value to its `FlagStyle` variant.
+///
+/// Shared by the `ToParameter` derive and the `command` macro parameters.
+pub(crate) fn flag_style_tokens(style: &syn::LitStr) -> syn::Result<TokenStream> {
+    match style.value().as_str() {
+        "presence" => Ok(quote! { ::utocli::FlagStyle::Presence }),
+        "value" => Ok(quote! { ::utocli::FlagStyle::Value }),
+        other => Err(syn::Error::new(
+            style.span(),
+            format!("unknown flag style `{other}`, expected `presence` or `value`"),
+        )),
+    }
+}
+
+/// Parse a list of names given as `name = "a"` or `name("a", "b")`.
+fn parse_names(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<String>> {
+    if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let names =
+            content.parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?;
+        Ok(names.iter().map(syn::LitStr::value).collect())
+    } else {
+        Ok(vec![meta.value()?.parse::<syn::LitStr>()?.value()])
+    }
+}
+
 /// Check if a type is `Option<T>`.
 fn is_option_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
@@ -512,6 +812,16 @@ fn is_option_type(ty: &syn::Type) -> bool {
     false
 }
 
+/// Check if a type is `Vec<T>`.
+fn is_vec_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return segment.ident == "Vec";
+    }
+    false
+}
+
 /// Check if a type is `bool`.
 fn is_bool_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
@@ -534,3 +844,78 @@ fn is_inner_bool_type(ty: &syn::Type) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::ParameterAttributes;
+
+    #[test]
+    fn parameter_attributes_with_deprecated_bool_sets_deprecated() {
+        //* Given
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! { #[param(deprecated = true)] }];
+
+        //* When
+        let result = ParameterAttributes::parse(&attrs);
+
+        //* Then
+        assert!(
+            result.is_ok_and(|attributes| attributes.deprecated),
+            "deprecated = true should mark the parameter deprecated"
+        );
+    }
+
+    #[test]
+    fn parameter_attributes_with_deprecation_note_fails() {
+        //* Given
+        let attrs: Vec<syn::Attribute> =
+            vec![parse_quote! { #[param(deprecated = "use --new-flag")] }];
+
+        //* When
+        let result = ParameterAttributes::parse(&attrs);
+
+        //* Then
+        let error = result.err().expect("deprecation note should be rejected");
+        assert!(
+            error.to_string().contains("deprecated = <bool>"),
+            "error should explain the accepted deprecated forms"
+        );
+    }
+
+    #[test]
+    fn parameter_attributes_with_flag_style_sets_flag_style() {
+        //* Given
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! { #[param(flag_style = "presence")] }];
+
+        //* When
+        let result = ParameterAttributes::parse(&attrs);
+
+        //* Then
+        let flag_style = result
+            .ok()
+            .and_then(|attributes| attributes.flag_style)
+            .expect("flag style should be parsed");
+        assert_eq!(
+            flag_style.to_string(),
+            quote::quote! { ::utocli::FlagStyle::Presence }.to_string(),
+            "presence should map to the presence flag style"
+        );
+    }
+
+    #[test]
+    fn parameter_attributes_with_unknown_flag_style_fails() {
+        //* Given
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! { #[param(flag_style = "toggle")] }];
+
+        //* When
+        let result = ParameterAttributes::parse(&attrs);
+
+        //* Then
+        let error = result.err().expect("unknown flag style should be rejected");
+        assert!(
+            error.to_string().contains("expected `presence` or `value`"),
+            "error should list the accepted flag styles"
+        );
+    }
+}
@@ -5,7 +5,7 @@
 
 use std::{borrow::Cow, mem};
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
     Attribute, Data, Field, Fields, Generics, Lit, LitInt, LitStr, Token, Type, parse::ParseStream,
@@ -16,6 +16,7 @@ use crate::{
     AnyValue,
     diagnostics::{Diagnostics, ToTokensDiagnostics},
     doc_comment::parse_doc_comments,
+    parse_utils,
 };
 
 /// Parse helpers for response attributes.
@@ -43,14 +44,18 @@ struct ContentAttributes {
     media_type: Option<String>,
     schema: Option<String>,
     example: Option<String>,
+    /// The file the example was read from, tracked for rebuilds
+    example_file: Option<LitStr>,
 }
 
 impl ContentAttributes {
-    fn parse(attrs: &[syn::Attribute]) -> Result<Self, Diagnostics> {
-        let mut result = Self::default();
+    /// Parses the `#[content(...)]` attributes, returning `None` if the field has none.
+    fn parse(attrs: &[syn::Attribute]) -> Result<Option<Self>, Diagnostics> {
+        let mut result = None;
 
         for attr in attrs {
             if attr.path().is_ident("content") {
+                let result = result.get_or_insert_with(Self::default);
                 attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("media_type") {
                         let value = meta.value()?;
@@ -70,6 +75,11 @@ impl ContentAttributes {
                         if let Lit::Str(s) = lit {
                             result.example = Some(s.value());
                         }
+                    } else if meta.path.is_ident("example_file") {
+                        let value = meta.value()?;
+                        let path: LitStr = value.parse()?;
+                        result.example = Some(parse_utils::read_example_file(&path)?);
+                        result.example_file = Some(path);
                     }
                     Ok(())
                 })
@@ -79,6 +89,19 @@ impl ContentAttributes {
 
         Ok(result)
     }
+
+    /// Returns the media type of this content.
+    ///
+    /// When no media type is given, it defaults to `application/json` for schema-bearing content
+    /// and to `text/plain` for example-only content.
+    fn resolve_media_type(&self) -> Option<String> {
+        match (&self.media_type, &self.schema, &self.example) {
+            (Some(media_type), _, _) => Some(media_type.clone()),
+            (None, Some(_), _) => Some("application/json".to_string()),
+            (None, None, Some(_)) => Some("text/plain".to_string()),
+            (None, None, None) => None,
+        }
+    }
 }
 
 /// Trait for parsing response attribute values from `#[response(...)]`.
@@ -107,7 +130,8 @@ trait DeriveResponseValue: syn::parse::Parse {
 
 /// Response status code (exit code for CLI).
 ///
-/// Utoipa uses HTTP status codes (200, 404, etc.), we use CLI exit codes ("0", "1", "2", etc.).
+/// Utoipa uses HTTP status codes (200, 404, etc.), we use CLI exit codes ("0", "1", "2", etc.),
+/// exit code ranges ("1-125") and "default".
 #[derive(Default)]
 pub(crate) struct ResponseStatus(TokenStream);
 
@@ -117,9 +141,11 @@ impl syn::parse::Parse for ResponseStatus {
         let lookahead = input.lookahead1();
         if lookahead.peek(LitInt) {
             let lit = input.parse::<LitInt>()?;
+            validate_exit_code(lit.base10_digits(), lit.span())?;
             Ok(Self(lit.to_token_stream()))
         } else if lookahead.peek(LitStr) {
             let lit = input.parse::<LitStr>()?;
+            validate_exit_code(&lit.value(), lit.span())?;
             Ok(Self(lit.to_token_stream()))
         } else {
             Err(lookahead.error())
@@ -127,6 +153,66 @@ impl syn::parse::Parse for ResponseStatus {
     }
 }
 
+impl ResponseStatus {
+    /// Returns the status code as a string, if it is a literal.
+    pub(crate) fn value(&self) -> Option<String> {
+        match syn::parse2::<Lit>(self.0.clone()).ok()? {
+            Lit::Str(lit) => Some(lit.value()),
+            Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Validates the shape of a response status: an exit code (`"0"`), an inclusive exit code
+/// range (`"1-125"`) or `"default"`.
+///
+/// Codes are not bounded to `u8` here, so specs documenting wider status codes keep compiling;
+/// `utocli::ExitCode` is the strict, typed counterpart.
+pub(crate) fn validate_exit_code(status: &str, span: Span) -> syn::Result<()> {
+    let valid = status == "default"
+        || match status.split_once('-') {
+            Some((start, end)) => matches!(
+                (start.parse::<u32>(), end.parse::<u32>()),
+                (Ok(start), Ok(end)) if start <= end
+            ),
+            None => status.parse::<u32>().is_ok(),
+        };
+    if valid {
+        return Ok(());
+    }
+    Err(
+        Diagnostics::with_span(span, format!("invalid exit code `{status}`"))
+            .help("Use an exit code like \"1\", an inclusive range like \"1-125\", or \"default\"")
+            .note("Example: #[response(status = \"1-125\", description = \"Failure\")]")
+            .into(),
+    )
+}
+
+/// Default descriptions for common CLI exit codes.
+///
+/// Used by `#[response(default_descriptions)]` for responses without an explicit description
+/// or doc comment.
+fn default_status_description(status: &str) -> Option<&'static str> {
+    match status {
+        "0" => Some("Success"),
+        "1" => Some("General error"),
+        "2" => Some("Misuse of command"),
+        "126" => Some("Command cannot execute"),
+        "127" => Some("Command not found"),
+        "130" => Some("Terminated by Ctrl-C"),
+        _ => None,
+    }
+}
+
+/// Check if the attribute is the container-level `#[response(default_descriptions)]` flag.
+fn is_default_descriptions_attribute(attribute: &Attribute) -> bool {
+    attribute.path().is_ident("response")
+        && attribute
+            .parse_args::<Ident>()
+            .is_ok_and(|ident| ident == "default_descriptions")
+}
+
 impl ToTokens for ResponseStatus {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.0.to_tokens(tokens);
@@ -160,6 +246,23 @@ impl<'r> From<ResponseValue> for ResponseTuple<'r> {
     }
 }
 
+impl ResponseTuple<'_> {
+    /// Sets the default description for the status code if the response has none.
+    fn apply_default_description(&mut self) {
+        let Some(ResponseTupleInner::Value(value)) = &mut self.inner else {
+            return;
+        };
+        if value.description.is_none() {
+            value.description = self
+                .status_code
+                .value()
+                .as_deref()
+                .and_then(default_status_description)
+                .map(str::to_string);
+        }
+    }
+}
+
 /// Inner content of a response tuple.
 ///
 /// This mirrors utoipa's `ResponseTupleInner` enum exactly.
@@ -189,6 +292,13 @@ pub struct ResponseValue {
     pub example: Option<AnyValue>,
     /// Content map: media_type -> (schema, example)
     pub content: Vec<(String, Option<String>, Option<String>)>, // (media_type, schema, example)
+    /// Extensions from `extend(...)`: (key, value)
+    pub extensions: Vec<(String, String)>,
+    /// Inline object schema built from the fields of a named struct (or struct variant),
+    /// documented as the `content_type` (default `application/json`) content.
+    pub inline_schema: Option<TokenStream>,
+    /// Files the content examples were read from, tracked for rebuilds
+    pub example_files: Vec<LitStr>,
 }
 
 impl ResponseValue {
@@ -207,6 +317,9 @@ impl ResponseValue {
             // Matches utoipa-gen/src/path/response/derive.rs line 37853
             example: derive_value.example.map(|(example, _)| example),
             content: Vec::new(),
+            extensions: derive_value.extensions,
+            inline_schema: None,
+            example_files: Vec::new(),
         }
     }
 
@@ -225,6 +338,9 @@ impl ResponseValue {
             // Extract AnyValue from tuple, discarding Ident
             example: derive_value.example.map(|(example, _)| example),
             content,
+            extensions: derive_value.extensions,
+            inline_schema: None,
+            example_files: Vec::new(),
         }
     }
 
@@ -243,6 +359,9 @@ impl ResponseValue {
             // Matches utoipa-gen/src/path/response/derive.rs line 37883
             example: response_value.example.map(|(example, _)| example),
             content: Vec::new(),
+            extensions: response_value.extensions,
+            inline_schema: None,
+            example_files: Vec::new(),
         }
     }
 }
@@ -274,7 +393,17 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                     .map(|d| quote! { Some(#d.to_string()) })
                     .unwrap_or_else(|| quote! { None });
 
-                let content = if value.content.is_empty() {
+                let inline_entry = value.inline_schema.as_ref().map(|schema| {
+                    let media_type = value.content_type.as_deref().unwrap_or("application/json");
+                    quote! {
+                        (#media_type.to_string(), ::utocli::MediaType {
+                            schema: Some(::utocli::RefOr::T(#schema)),
+                            example: None,
+                        })
+                    }
+                });
+
+                let content = if value.content.is_empty() && inline_entry.is_none() {
                     quote! { None }
                 } else {
                     let content_entries = value.content.iter().map(|(media_type, schema, example)| {
@@ -307,6 +436,7 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                         }
                     });
 
+                    let content_entries = inline_entry.into_iter().chain(content_entries);
                     quote! {
                         Some(::utocli::Map::from_iter(vec![
                             #(#content_entries),*
@@ -314,10 +444,31 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                     }
                 };
 
+                let extensions = if value.extensions.is_empty() {
+                    quote! { None }
+                } else {
+                    let ext_keys = value.extensions.iter().map(|(key, _)| key);
+                    let ext_values = value.extensions.iter().map(|(_, value)| value);
+                    quote! {
+                        Some(::utocli::Map::from_iter(vec![
+                            #((#ext_keys.to_string(), serde_json::Value::String(#ext_values.to_string()))),*
+                        ]))
+                    }
+                };
+
+                let example_files = value
+                    .example_files
+                    .iter()
+                    .map(parse_utils::track_example_file);
+
                 tokens.extend(quote! {
-                    ::utocli::Response {
-                        description: #description,
-                        content: #content,
+                    {
+                        #(#example_files)*
+                        ::utocli::Response {
+                            description: #description,
+                            content: #content,
+                            extensions: #extensions,
+                        }
                     }
                 });
             }
@@ -326,6 +477,7 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                     ::utocli::Response {
                         description: None,
                         content: None,
+                        extensions: None,
                     }
                 });
             }
@@ -346,6 +498,8 @@ struct DeriveToResponseValue {
     /// Example value paired with the Ident for better error messages.
     /// Matches utoipa pattern from line 37995
     example: Option<(AnyValue, Ident)>,
+    /// Extensions from `extend(...)`: (key, value)
+    extensions: Vec<(String, String)>,
 }
 
 impl DeriveResponseValue for DeriveToResponseValue {
@@ -359,6 +513,7 @@ impl DeriveResponseValue for DeriveToResponseValue {
         if other.example.is_some() {
             self.example = other.example;
         }
+        self.extensions.extend(other.extensions);
         self
     }
 }
@@ -390,12 +545,18 @@ impl syn::parse::Parse for DeriveToResponseValue {
                     // Matches utoipa-gen/src/path/response/derive.rs line 38041
                     response.example = Some((parse::example(input)?, ident));
                 }
+                "extend" => {
+                    // Parse extensions: extend(x_retryable = "true")
+                    response
+                        .extensions
+                        .extend(parse_utils::parse_extensions(input)?);
+                }
                 _ => {
                     return Err(Diagnostics::with_span(
                         ident.span(),
                         format!("unexpected attribute: {attribute_name}"),
                     )
-                    .help("Valid attributes are: description, content_type, example")
+                    .help("Valid attributes are: description, content_type, example, extend")
                     .note("Example: #[response(description = \"Success\", content_type = \"application/json\")]")
                     .into());
                 }
@@ -507,14 +668,34 @@ impl ToResponseNamedStructResponse<'_> {
 
         // Parse field-level #[content(...)] attributes
         let mut content = Vec::new();
+        let mut example_files = Vec::new();
         for field in fields {
-            let content_attrs = ContentAttributes::parse(&field.attrs)?;
-            if let Some(media_type) = content_attrs.media_type {
-                content.push((media_type, content_attrs.schema, content_attrs.example));
+            let Some(content_attrs) = ContentAttributes::parse(&field.attrs)? else {
+                continue;
+            };
+            let Some(media_type) = content_attrs.resolve_media_type() else {
+                return Err(Diagnostics::with_span(
+                    field.span(),
+                    "Cannot infer the media type of the response content",
+                )
+                .help("Set `media_type = \"...\"`, or add a `schema` or an `example` to the content")
+                .note("Content with a schema defaults to `application/json`, example-only content to `text/plain`"));
+            };
+            if content
+                .iter()
+                .any(|(existing, _, _)| existing == &media_type)
+            {
+                return Err(Diagnostics::with_span(
+                    field.span(),
+                    format!("Duplicate response content for media type `{media_type}`"),
+                )
+                .help("Set a distinct `media_type = \"...\"` on each content field"));
             }
+            example_files.extend(content_attrs.example_file);
+            content.push((media_type, content_attrs.schema, content_attrs.example));
         }
 
-        let response_value = if content.is_empty() {
+        let mut response_value = if content.is_empty() {
             ResponseValue::from_derive_to_response_value(
                 derive_value.unwrap_or_default(),
                 description,
@@ -526,6 +707,7 @@ impl ToResponseNamedStructResponse<'_> {
                 content,
             )
         };
+        response_value.example_files = example_files;
 
         Ok(Self(response_value.into()))
     }
@@ -561,6 +743,8 @@ struct DeriveIntoResponsesValue {
     /// Example value paired with the Ident for better error messages.
     /// Matches utoipa pattern from line 38069
     example: Option<(AnyValue, Ident)>,
+    /// Extensions from `extend(...)`: (key, value)
+    extensions: Vec<(String, String)>,
 }
 
 impl DeriveResponseValue for DeriveIntoResponsesValue {
@@ -576,6 +760,7 @@ impl DeriveResponseValue for DeriveIntoResponsesValue {
         if other.example.is_some() {
             self.example = other.example;
         }
+        self.extensions.extend(other.extensions);
 
         self
     }
@@ -642,12 +827,18 @@ impl syn::parse::Parse for DeriveIntoResponsesValue {
                     // Matches utoipa-gen/src/path/response/derive.rs line 38137
                     response.example = Some((parse::example(input)?, ident));
                 }
+                "extend" => {
+                    // Parse extensions: extend(x_retryable = "true")
+                    response
+                        .extensions
+                        .extend(parse_utils::parse_extensions(input)?);
+                }
                 _ => {
                     return Err(Diagnostics::with_span(
                         ident.span(),
                         format!("unexpected attribute: {attribute_name}"),
                     )
-                    .help("Valid attributes are: description, content_type, example")
+                    .help("Valid attributes are: description, content_type, example, extend")
                     .note("Example: #[response(description = \"Success\", content_type = \"application/json\")]")
                     .into());
                 }
@@ -672,17 +863,18 @@ pub struct IntoResponses {
 
 impl ToTokensDiagnostics for IntoResponses {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let responses = match &self.data {
+        // Split the container-level `#[response(default_descriptions)]` flag from the response attributes
+        let (default_descriptions, attributes): (Vec<Attribute>, Vec<Attribute>) = self
+            .attributes
+            .iter()
+            .cloned()
+            .partition(is_default_descriptions_attribute);
+        let default_descriptions = !default_descriptions.is_empty();
+
+        let mut responses = match &self.data {
             Data::Struct(struct_value) => match &struct_value.fields {
                 Fields::Named(fields) => {
-                    let response =
-                        NamedStructResponse::new(&self.attributes, &self.ident, &fields.named)?.0;
-                    let status = &response.status_code;
-                    let response_tokens = response.try_to_token_stream()?;
-
-                    vec![
-                        quote!((#status.to_string(), ::utocli::opencli::RefOr::T(#response_tokens))),
-                    ]
+                    vec![NamedStructResponse::new(&attributes, &self.ident, &fields.named)?.0]
                 }
                 Fields::Unnamed(fields) => {
                     let field = fields
@@ -691,24 +883,9 @@ impl ToTokensDiagnostics for IntoResponses {
                         .next()
                         .expect("Unnamed struct must have 1 field");
 
-                    let response =
-                        UnnamedStructResponse::new(&self.attributes, &field.ty, &field.attrs)?.0;
-                    let status = &response.status_code;
-                    let response_tokens = response.try_to_token_stream()?;
-
-                    vec![
-                        quote!((#status.to_string(), ::utocli::opencli::RefOr::T(#response_tokens))),
-                    ]
-                }
-                Fields::Unit => {
-                    let response = UnitStructResponse::new(&self.attributes)?.0;
-                    let status = &response.status_code;
-                    let response_tokens = response.try_to_token_stream()?;
-
-                    vec![
-                        quote!((#status.to_string(), ::utocli::opencli::RefOr::T(#response_tokens))),
-                    ]
+                    vec![UnnamedStructResponse::new(&attributes, &field.ty, &field.attrs)?.0]
                 }
+                Fields::Unit => vec![UnitStructResponse::new(&attributes)?.0],
             },
             Data::Enum(enum_value) => enum_value
                 .variants
@@ -731,14 +908,7 @@ impl ToTokensDiagnostics for IntoResponses {
                     }
                     Fields::Unit => Ok(UnitStructResponse::new(&variant.attrs)?.0),
                 })
-                .collect::<Result<Vec<ResponseTuple>, Diagnostics>>()?
-                .iter()
-                .map(|response| {
-                    let status = &response.status_code;
-                    let response_tokens = response.try_to_token_stream()?;
-                    Ok(quote!((#status.to_string(), ::utocli::opencli::RefOr::T(#response_tokens))))
-                })
-                .collect::<Result<Vec<_>, Diagnostics>>()?,
+                .collect::<Result<Vec<ResponseTuple>, Diagnostics>>()?,
             Data::Union(_) => {
                 return Err(Diagnostics::with_span(
                     self.ident.span(),
@@ -749,6 +919,21 @@ impl ToTokensDiagnostics for IntoResponses {
             }
         };
 
+        if default_descriptions {
+            responses
+                .iter_mut()
+                .for_each(ResponseTuple::apply_default_description);
+        }
+
+        let responses = responses
+            .iter()
+            .map(|response| {
+                let status = &response.status_code;
+                let response_tokens = response.try_to_token_stream()?;
+                Ok(quote!((#status.to_string(), ::utocli::opencli::RefOr::T(#response_tokens))))
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
+
         let ident = &self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
@@ -891,16 +1076,20 @@ impl Response for NamedStructResponse<'_> {}
 impl NamedStructResponse<'_> {
     fn new(
         attributes: &[Attribute],
-        _ident: &Ident,
-        _fields: &Punctuated<Field, Comma>,
+        ident: &Ident,
+        fields: &Punctuated<Field, Comma>,
     ) -> Result<Self, Diagnostics> {
         let mut derive_value = DeriveIntoResponsesValue::from_attributes(attributes)?
             .expect("`IntoResponses` must have `#[response(...)]` attribute");
         let description = parse_doc_comments(attributes);
         let status_code = mem::take(&mut derive_value.status);
 
-        let response_value =
+        let mut response_value =
             ResponseValue::from_derive_into_responses_value(derive_value, description);
+        // The fields are documented as `ToSchema` documents a struct with the same attributes
+        response_value.inline_schema = Some(crate::schema::Schema::named_fields_schema(
+            ident, attributes, fields,
+        )?);
 
         Ok(Self((status_code, response_value).into()))
     }
@@ -1,13 +1,15 @@
 //! Schema generation for ToSchema derive macro.
 
+use std::cell::RefCell;
+
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use syn::{Data, DeriveInput, Fields, Lit, Result};
+use syn::{Data, DeriveInput, Fields, Lit, Result, spanned::Spanned};
 
 use crate::{diagnostics::Diagnostics, doc_comment::parse_doc_comments};
 
 mod enums;
-mod serde;
+pub(crate) mod serde;
 
 use enums::{MixedEnum, PlainEnum, Root};
 
@@ -38,13 +40,40 @@ pub fn is_required(
 struct SchemaAttributes {
     description: Option<String>,
     title: Option<String>,
+    title_from_name: bool,
     rename_all: Option<String>,
+    /// Rename rule for the fields of enum struct variants
+    rename_all_fields: Option<String>,
+    /// Discriminator property of an internally tagged enum
+    discriminator: Option<String>,
     no_recursion: bool,
+    /// Inline the schema wherever the type is referenced instead of registering a component
+    inline: bool,
     as_name: Option<String>,
     example: Option<AnyValue>,
+    /// Example values, serialized as the JSON Schema `examples` array
+    examples: Option<Vec<AnyValue>>,
     deprecated: bool,
-    additional_properties: Option<bool>,
+    /// Mark every field as read-only
+    read_only: bool,
+    /// Mark every field as write-only
+    write_only: bool,
+    additional_properties: Option<AdditionalProperties>,
     bound: Option<syn::WherePredicate>,
+    /// Emit exclusive bounds in the JSON Schema 2020-12 numeric form
+    numeric_exclusive_bounds: bool,
+    // String validation attributes, applied to the string schema of plain enums and unit structs
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<String>,
+}
+
+/// Container-level `additional_properties` value: a boolean or a type whose schema
+/// constrains the additional property values.
+#[derive(Clone)]
+enum AdditionalProperties {
+    Bool(bool),
+    Type(Box<syn::Type>),
 }
 
 impl SchemaAttributes {
@@ -66,14 +95,32 @@ impl SchemaAttributes {
                         if let Lit::Str(s) = lit {
                             result.title = Some(s.value());
                         }
+                    } else if meta.path.is_ident("title_from_name") {
+                        result.title_from_name = true;
                     } else if meta.path.is_ident("rename_all") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
                         if let Lit::Str(s) = lit {
                             result.rename_all = Some(s.value());
                         }
+                    } else if meta.path.is_ident("rename_all_fields") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.rename_all_fields = Some(s.value());
+                        }
+                    } else if meta.path.is_ident("discriminator") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.discriminator = Some(s.value());
+                        }
                     } else if meta.path.is_ident("no_recursion") {
                         result.no_recursion = true;
+                    } else if meta.path.is_ident("numeric_exclusive_bounds") {
+                        result.numeric_exclusive_bounds = true;
+                    } else if meta.path.is_ident("inline") {
+                        result.inline = true;
                     } else if meta.path.is_ident("as") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
@@ -84,13 +131,23 @@ impl SchemaAttributes {
                         result.example = Some(parse_utils::parse_next(meta.input, || {
                             AnyValue::parse_any(meta.input)
                         })?);
+                    } else if meta.path.is_ident("examples") {
+                        result.examples = Some(parse_examples(&meta)?);
                     } else if meta.path.is_ident("deprecated") {
                         result.deprecated = true;
+                    } else if meta.path.is_ident("read_only") {
+                        result.read_only = true;
+                    } else if meta.path.is_ident("write_only") {
+                        result.write_only = true;
                     } else if meta.path.is_ident("additional_properties") {
                         let value = meta.value()?;
-                        let lit: Lit = value.parse()?;
-                        if let Lit::Bool(b) = lit {
-                            result.additional_properties = Some(b.value);
+                        if value.peek(syn::LitBool) {
+                            let lit: syn::LitBool = value.parse()?;
+                            result.additional_properties =
+                                Some(AdditionalProperties::Bool(lit.value));
+                        } else {
+                            result.additional_properties =
+                                Some(AdditionalProperties::Type(Box::new(value.parse()?)));
                         }
                     } else if meta.path.is_ident("bound") {
                         let value = meta.value()?;
@@ -100,6 +157,24 @@ impl SchemaAttributes {
                             let predicate: syn::WherePredicate = s.parse()?;
                             result.bound = Some(predicate);
                         }
+                    } else if meta.path.is_ident("min_length") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Int(i) = lit {
+                            result.min_length = Some(i.base10_parse()?);
+                        }
+                    } else if meta.path.is_ident("max_length") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Int(i) = lit {
+                            result.max_length = Some(i.base10_parse()?);
+                        }
+                    } else if meta.path.is_ident("pattern") {
+                        let value = meta.value()?;
+                        let lit: Lit = value.parse()?;
+                        if let Lit::Str(s) = lit {
+                            result.pattern = Some(s.value());
+                        }
                     }
                     Ok(())
                 })?;
@@ -129,6 +204,8 @@ struct FieldAttributes {
     multiple_of: Option<f64>,
     exclusive_minimum: Option<bool>,
     exclusive_maximum: Option<bool>,
+    /// Emit exclusive bounds in the numeric form, set from the container
+    numeric_exclusive_bounds: bool,
     max_properties: Option<usize>,
     min_properties: Option<usize>,
     min_items: Option<usize>,
@@ -136,12 +213,20 @@ struct FieldAttributes {
     // Default value
     default: Option<DefaultValue>,
     example: Option<AnyValue>,
+    /// Example values, serialized as the JSON Schema `examples` array
+    examples: Option<Vec<AnyValue>>,
+    /// Use the field type `Default::default()` value as the example
+    example_from_default: bool,
     deprecated: bool,
     read_only: bool,
     write_only: bool,
     nullable: Option<bool>,
     value_type: Option<syn::Type>,
     title: Option<String>,
+    /// Document a `Vec<(K, V)>` field as an object mapping keys to values
+    as_map: bool,
+    /// Document a `Result<T, E>` field as serde's externally tagged `Ok`/`Err` object
+    result_as_tagged: bool,
 }
 
 /// Represents different ways a default value can be specified
@@ -275,6 +360,10 @@ impl FieldAttributes {
                         result.example = Some(parse_utils::parse_next(meta.input, || {
                             AnyValue::parse_any(meta.input)
                         })?);
+                    } else if meta.path.is_ident("examples") {
+                        result.examples = Some(parse_examples(&meta)?);
+                    } else if meta.path.is_ident("example_from_default") {
+                        result.example_from_default = true;
                     } else if meta.path.is_ident("deprecated") {
                         result.deprecated = true;
                     } else if meta.path.is_ident("read_only") {
@@ -295,6 +384,10 @@ impl FieldAttributes {
                     } else if meta.path.is_ident("value_type") {
                         let value = meta.value()?;
                         result.value_type = Some(value.parse()?);
+                    } else if meta.path.is_ident("as_map") {
+                        result.as_map = true;
+                    } else if meta.path.is_ident("result_as_tagged") {
+                        result.result_as_tagged = true;
                     } else if meta.path.is_ident("title") {
                         let value = meta.value()?;
                         let lit: Lit = value.parse()?;
@@ -350,6 +443,42 @@ impl Schema {
         Ok(Self { input, attributes })
     }
 
+    /// Generate the inline object schema of named fields, as `ToSchema` documents a struct.
+    ///
+    /// Used by other derives documenting named fields (e.g., `IntoResponses` struct variants),
+    /// so the `#[serde(...)]` and `#[schema(...)]` attributes are honored the same way. Only
+    /// the `serde` and `schema` attributes of `attrs` apply.
+    pub(crate) fn named_fields_schema(
+        ident: &syn::Ident,
+        attrs: &[syn::Attribute],
+        fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    ) -> Result<TokenStream> {
+        let attrs = attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde") || attr.path().is_ident("schema"))
+            .cloned()
+            .collect();
+        let fields = Fields::Named(syn::FieldsNamed {
+            brace_token: Default::default(),
+            named: fields.clone(),
+        });
+        let schema = Self::new(DeriveInput {
+            attrs,
+            vis: syn::Visibility::Inherited,
+            ident: ident.clone(),
+            generics: Default::default(),
+            data: Data::Struct(syn::DataStruct {
+                struct_token: Default::default(),
+                fields: fields.clone(),
+                semi_token: None,
+            }),
+        })?;
+        if let Some(diagnostics) = Self::find_invalid_as_map_field(&fields) {
+            return Err(diagnostics.into());
+        }
+        Ok(schema.generate_struct_schema(&fields, false))
+    }
+
     pub fn into_token_stream(self) -> TokenStream {
         let name = &self.input.ident;
         let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
@@ -371,12 +500,22 @@ impl Schema {
             }
         }
 
-        // Generate schema type based on data structure
-        let schema_impl = match &self.input.data {
-            Data::Struct(data_struct) => self.generate_struct_schema(&data_struct.fields),
+        // Generate schema type based on data structure, collecting the references it builds
+        let (schema_impl, mut references) = match &self.input.data {
+            Data::Struct(data_struct) => {
+                if let Some(diagnostics) = self
+                    .find_recursive_inline_field(&data_struct.fields)
+                    .or_else(|| Self::find_invalid_as_map_field(&data_struct.fields))
+                {
+                    return diagnostics.into_token_stream();
+                }
+                SchemaReferences::collect(|| {
+                    self.generate_struct_schema(&data_struct.fields, false)
+                })
+            }
             Data::Enum(data_enum) => {
                 // For now, generate simple enum schema
-                self.generate_enum_schema(&data_enum.variants)
+                SchemaReferences::collect(|| self.generate_enum_schema(&data_enum.variants))
             }
             Data::Union(_) => {
                 return Diagnostics::new("ToSchema cannot be derived for unions")
@@ -386,29 +525,39 @@ impl Schema {
             }
         };
 
+        // Generate the schema with generic type parameters substituted by the composed schemas
+        let compose_impl = match &self.input.data {
+            Data::Struct(data_struct) => self.generate_struct_schema(&data_struct.fields, true),
+            _ => schema_impl.clone(),
+        };
+
         // Format schema name with generic parameters
-        let schema_name_value = if let Some(as_name) = &self.attributes.as_name {
-            // Use as_name if provided
-            as_name.clone()
+        let schema_name_value = self.schema_name_value();
+
+        // Only emitted for `#[schema(inline)]` containers, relying on the trait default otherwise
+        let inline_impl = if self.attributes.inline {
+            quote! {
+                fn inline() -> bool {
+                    true
+                }
+            }
         } else {
-            // Format name with generics: "Foo<T, U>" not just "Foo"
-            let type_params: Vec<_> = self
+            quote! {}
+        };
+
+        // Only emitted when the schema references components, relying on the trait default
+        // otherwise. References to the type parameters are substituted when composing.
+        references.names.retain(|reference| {
+            !self
                 .input
                 .generics
                 .type_params()
-                .map(|p| p.ident.to_string())
-                .collect();
-
-            if type_params.is_empty() {
-                name.to_string()
-            } else {
-                // Build generic name string: "Response<T, U>"
-                format!("{}<{}>", name, type_params.join(", "))
-            }
-        };
+                .any(|param| param.ident == reference)
+        });
+        let references_impl = references.to_impl();
 
-        // Check if this type has generic parameters
-        let has_generics = !self.input.generics.params.is_empty();
+        // Check if this type has generic type parameters (lifetimes don't need composing)
+        let has_generics = self.input.generics.type_params().next().is_some();
 
         if has_generics {
             // For generic types, generate both ToSchema and ComposeSchema implementations
@@ -423,16 +572,18 @@ impl Schema {
                     fn schema_name() -> &'static str {
                         #schema_name_value
                     }
+
+                    #inline_impl
+
+                    #references_impl
                 }
 
                 impl #impl_generics ::utocli::ComposeSchema for #name #ty_generics #where_clause {
                     fn compose(generics: ::std::vec::Vec<::utocli::RefOr<::utocli::Schema>>) -> ::utocli::RefOr<::utocli::Schema> {
                         use ::utocli::{Schema, SchemaType, Object, RefOr};
 
-                        // Generate schema using composed generic schemas
-                        // For now, return a reference to the schema name
-                        // TODO: Properly compose the schema with generic parameter schemas
-                        RefOr::new_ref(format!("#/components/schemas/{}", #schema_name_value))
+                        // Substitute the provided generic schemas where the type parameters appear
+                        RefOr::T(#compose_impl)
                     }
                 }
             }
@@ -449,12 +600,127 @@ impl Schema {
                     fn schema_name() -> &'static str {
                         #schema_name_value
                     }
+
+                    #inline_impl
+
+                    #references_impl
                 }
             }
         }
     }
 
-    fn generate_struct_schema(&self, fields: &Fields) -> TokenStream {
+    /// Format the schema name, including generic parameters (e.g., `Response<T, U>`).
+    fn schema_name_value(&self) -> String {
+        if let Some(as_name) = &self.attributes.as_name {
+            // Use as_name if provided
+            return as_name.clone();
+        }
+
+        // Format name with generics: "Foo<T, U>" not just "Foo"
+        let name = &self.input.ident;
+        let type_params: Vec<_> = self
+            .input
+            .generics
+            .type_params()
+            .map(|p| p.ident.to_string())
+            .collect();
+
+        if type_params.is_empty() {
+            name.to_string()
+        } else {
+            // Build generic name string: "Response<T, U>"
+            format!("{}<{}>", name, type_params.join(", "))
+        }
+    }
+
+    /// The container title: the explicit `title`, or the schema name with `title_from_name`.
+    fn container_title(&self) -> Option<String> {
+        self.attributes.title.clone().or_else(|| {
+            self.attributes
+                .title_from_name
+                .then(|| self.schema_name_value())
+        })
+    }
+
+    /// Find a field marked `as_map` whose type is not a list of key/value pairs.
+    ///
+    /// Such a field could not be documented as a map, so it is rejected at compile time instead
+    /// of silently falling back to its inferred schema.
+    fn find_invalid_as_map_field(fields: &Fields) -> Option<Diagnostics> {
+        fields.iter().find_map(|field| {
+            let field_attrs = FieldAttributes::parse(&field.attrs).unwrap_or_default();
+            if !field_attrs.as_map || get_pair_value_type(&field.ty).is_some() {
+                return None;
+            }
+
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| "field".to_string(), |ident| format!("field `{ident}`"));
+            Some(
+                Diagnostics::with_span(
+                    field.ty.span(),
+                    format!("{field_name} is marked `as_map` but is not a list of key/value pairs"),
+                )
+                .help("use a `Vec<(K, V)>` field type, or remove `as_map`")
+                .note("`as_map` documents a list of `(K, V)` pairs as an object of `V` values"),
+            )
+        })
+    }
+
+    /// Find a field that inlines the container type without `no_recursion`.
+    ///
+    /// Inlining a self-referential type expands its schema in place, recursing forever when the
+    /// schema is generated, so it is rejected at compile time instead.
+    fn find_recursive_inline_field(&self, fields: &Fields) -> Option<Diagnostics> {
+        if self.attributes.no_recursion {
+            return None;
+        }
+
+        fields.iter().find_map(|field| {
+            let field_attrs = FieldAttributes::parse(&field.attrs).unwrap_or_default();
+            let ty = field_attrs.value_type.as_ref().unwrap_or(&field.ty);
+            if !field_attrs.inline
+                || field_attrs.no_recursion
+                || field_attrs.schema_with.is_some()
+                || !type_references_ident(ty, &self.input.ident)
+            {
+                return None;
+            }
+
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| "field".to_string(), |ident| format!("field `{ident}`"));
+            Some(
+                Diagnostics::with_span(
+                    field.ty.span(),
+                    format!(
+                        "{field_name} inlines the recursive type `{}`",
+                        self.input.ident
+                    ),
+                )
+                .help("add `#[schema(no_recursion)]` to the field or the container")
+                .note("inlining a self-referential type recurses infinitely when generating its schema"),
+            )
+        })
+    }
+
+    /// Generate the struct schema tokens.
+    ///
+    /// When `compose` is true, fields typed by a generic type parameter use the schema provided
+    /// in the `generics` argument of `ComposeSchema::compose` (falling back to the inferred one).
+    fn generate_struct_schema(&self, fields: &Fields, compose: bool) -> TokenStream {
+        let type_params: Vec<&syn::Ident> = if compose {
+            self.input
+                .generics
+                .type_params()
+                .map(|p| &p.ident)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         match fields {
             Fields::Named(named_fields) => {
                 let mut properties = Vec::new();
@@ -483,6 +749,10 @@ impl Schema {
                     if container_no_recursion {
                         field_attrs.no_recursion = true;
                     }
+                    field_attrs.numeric_exclusive_bounds = self.attributes.numeric_exclusive_bounds;
+                    // Container-level read_only and write_only apply to every field
+                    field_attrs.read_only |= self.attributes.read_only;
+                    field_attrs.write_only |= self.attributes.write_only;
 
                     // Check both schema skip and serde skip (following utoipa's pattern)
                     if field_attrs.skip || field_rules.skip {
@@ -527,18 +797,61 @@ impl Schema {
                         quote! {
                             ::utocli::RefOr::T(#schema_with())
                         }
+                    } else if field_attrs
+                        .value_type
+                        .as_ref()
+                        .is_some_and(is_free_form_object_type)
+                    {
+                        // `value_type = Object` documents the field as a free-form object
+                        quote! {
+                            ::utocli::RefOr::T(::utocli::Schema::Object(Box::new(
+                                ::utocli::Object::new()
+                                    .schema_type(::utocli::SchemaType::Object)
+                                    .additional_properties(::utocli::AdditionalProperties::Bool(true))
+                            )))
+                        }
+                    } else if field_attrs.as_map
+                        && let Some(value_ty) = get_pair_value_type(ty)
+                    {
+                        // `as_map` documents a `Vec<(K, V)>` field as an object of `V` values
+                        let value_ref_or = infer_schema_ref_or(
+                            value_ty,
+                            field_attrs.inline,
+                            field_attrs.no_recursion,
+                        );
+                        quote! {
+                            ::utocli::RefOr::T(::utocli::Schema::Object(Box::new(
+                                ::utocli::Object::new()
+                                    .schema_type(::utocli::SchemaType::Object)
+                                    .additional_properties(::utocli::AdditionalProperties::from(#value_ref_or))
+                            )))
+                        }
+                    } else if field_attrs.result_as_tagged
+                        && let Some((ok_ty, err_ty)) =
+                            get_result_types(get_option_inner_type(ty).unwrap_or(ty))
+                    {
+                        // `result_as_tagged` documents a `Result<T, E>` field as serialized by
+                        // serde, an object with either an `Ok` or an `Err` member
+                        infer_result_schema_ref_or(
+                            ok_ty,
+                            err_ty,
+                            true,
+                            field_attrs.inline,
+                            field_attrs.no_recursion,
+                        )
                     } else {
                         // Use value_type override if provided
                         let ty_to_use = field_attrs.value_type.as_ref().unwrap_or(ty);
 
                         // Infer schema from field type (handles Vec, primitives, etc.)
                         // Pass inline flag, no_recursion flag, and validations from field attributes
-                        infer_schema_ref_or_with_validations(
+                        let inferred = infer_schema_ref_or_with_validations(
                             ty_to_use,
                             field_attrs.inline,
                             field_attrs.no_recursion,
                             &field_attrs,
-                        )
+                        );
+                        compose_generic_schema_ref_or(ty_to_use, &type_params, inferred)
                     };
 
                     // Apply additional field-level attributes (following utoipa's pattern)
@@ -549,6 +862,24 @@ impl Schema {
                         property_modifiers.push(quote! {
                             .example(Some(#example))
                         });
+                    } else if field_attrs.example_from_default {
+                        let example = AnyValue::new_type_default(ty);
+                        property_modifiers.push(quote! {
+                            .example(Some(#example))
+                        });
+                    }
+
+                    if let Some(ref examples) = field_attrs.examples {
+                        property_modifiers.push(quote! {
+                            .examples(vec![#(#examples),*])
+                        });
+                    }
+
+                    if let Some(ref format) = field_attrs.format {
+                        let format = schema_format_tokens(format);
+                        property_modifiers.push(quote! {
+                            .format(#format)
+                        });
                     }
 
                     if let Some(ref title) = field_attrs.title {
@@ -581,6 +912,25 @@ impl Schema {
                         });
                     }
 
+                    // References, arrays and compositions cannot carry the access flags, so they
+                    // are wrapped in an `allOf` that does
+                    let mut access_modifiers = Vec::new();
+                    if field_attrs.read_only {
+                        access_modifiers.push(quote! { .read_only(Some(true)) });
+                    }
+                    if field_attrs.write_only {
+                        access_modifiers.push(quote! { .write_only(Some(true)) });
+                    }
+                    let other_tokens = if access_modifiers.is_empty() {
+                        quote! { other => other, }
+                    } else {
+                        quote! {
+                            other => ::utocli::RefOr::T(::utocli::Schema::AllOf(
+                                ::utocli::AllOf::new().item(other) #(#access_modifiers)*
+                            )),
+                        }
+                    };
+
                     // Apply modifiers if any exist
                     if !property_modifiers.is_empty() {
                         schema_ref_or = quote! {
@@ -590,7 +940,7 @@ impl Schema {
                                         *obj = (*obj) #(#property_modifiers)*;
                                         ::utocli::RefOr::T(::utocli::Schema::Object(obj))
                                     },
-                                    other => other,
+                                    #other_tokens
                                 }
                             }
                         };
@@ -633,7 +983,7 @@ impl Schema {
                 }
 
                 // Add title if present (container-level)
-                if let Some(ref title) = self.attributes.title {
+                if let Some(ref title) = self.container_title() {
                     object_builder.extend(quote! {
                         .title(Some(#title))
                     });
@@ -645,6 +995,11 @@ impl Schema {
                         .example(Some(#example))
                     });
                 }
+                if let Some(ref examples) = self.attributes.examples {
+                    object_builder.extend(quote! {
+                        .examples(vec![#(#examples),*])
+                    });
+                }
 
                 // Add deprecated if present (container-level)
                 if self.attributes.deprecated {
@@ -654,12 +1009,20 @@ impl Schema {
                 }
 
                 // Add additional_properties if specified (container-level)
-                if let Some(additional_properties) = self.attributes.additional_properties
-                    && !additional_properties
-                {
-                    object_builder.extend(quote! {
-                        .additional_properties(Some(false))
-                    });
+                match &self.attributes.additional_properties {
+                    Some(AdditionalProperties::Bool(false)) => {
+                        object_builder.extend(quote! {
+                            .additional_properties(::utocli::AdditionalProperties::Bool(false))
+                        });
+                    }
+                    Some(AdditionalProperties::Type(ty)) => {
+                        let schema_ref_or =
+                            infer_schema_ref_or(ty, false, self.attributes.no_recursion);
+                        object_builder.extend(quote! {
+                            .additional_properties(::utocli::AdditionalProperties::from(#schema_ref_or))
+                        });
+                    }
+                    Some(AdditionalProperties::Bool(true)) | None => {}
                 }
 
                 quote! {
@@ -667,7 +1030,7 @@ impl Schema {
                 }
             }
             Fields::Unnamed(unnamed_fields) => {
-                self.generate_unnamed_struct_schema(&unnamed_fields.unnamed)
+                self.generate_unnamed_struct_schema(&unnamed_fields.unnamed, &type_params)
             }
             Fields::Unit => self.generate_unit_struct_schema(),
         }
@@ -676,6 +1039,7 @@ impl Schema {
     fn generate_unnamed_struct_schema(
         &self,
         fields: &syn::punctuated::Punctuated<syn::Field, syn::Token![,]>,
+        type_params: &[&syn::Ident],
     ) -> TokenStream {
         let fields_len = fields.len();
 
@@ -702,7 +1066,11 @@ impl Schema {
 
             // For single field, check for inline attribute on the field
             // For now, simplified: always inline for unnamed structs
-            let schema_ref_or = infer_schema_ref_or(first_ty, false, self.attributes.no_recursion);
+            let schema_ref_or = compose_generic_schema_ref_or(
+                first_ty,
+                type_params,
+                infer_schema_ref_or(first_ty, false, self.attributes.no_recursion),
+            );
 
             // Unwrap RefOr to get Schema (utoipa does this via ComponentSchema)
             tokens.extend(quote! {
@@ -768,17 +1136,40 @@ impl Schema {
             });
         }
 
+        object_builder.extend(self.string_validation_tokens());
+
         quote! {
             ::utocli::Schema::Object(Box::new(#object_builder))
         }
     }
 
+    /// Builder calls applying the container-level string validation attributes to an `Object`.
+    fn string_validation_tokens(&self) -> TokenStream {
+        let min_length = self
+            .attributes
+            .min_length
+            .map(|min_length| quote! { .min_length(#min_length) });
+        let max_length = self
+            .attributes
+            .max_length
+            .map(|max_length| quote! { .max_length(#max_length) });
+        let pattern = self
+            .attributes
+            .pattern
+            .as_ref()
+            .map(|pattern| quote! { .pattern(#pattern) });
+
+        quote! { #min_length #max_length #pattern }
+    }
+
     fn generate_enum_schema(
         &self,
         variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
     ) -> TokenStream {
         // Determine if this is a plain enum (all unit variants) or mixed enum
-        let is_plain = variants.iter().all(|v| matches!(v.fields, Fields::Unit));
+        // A discriminator documents every variant as a `oneOf` item, so it needs a mixed enum
+        let is_plain = variants.iter().all(|v| matches!(v.fields, Fields::Unit))
+            && self.attributes.discriminator.is_none();
 
         let root = Root::new(&self.input.ident, &self.input.attrs);
 
@@ -797,7 +1188,7 @@ impl Schema {
             }
         });
 
-        if is_plain {
+        let schema = if is_plain {
             // Use PlainEnum for unit variants only
             match PlainEnum::new(&root, variants, rename_all) {
                 Ok(plain_enum) => {
@@ -824,13 +1215,39 @@ impl Schema {
                         };
                     }
 
+                    let string_validation = self.string_validation_tokens();
+                    if !string_validation.is_empty() {
+                        // Wrap schema to constrain the enum string values
+                        schema = quote! {
+                            {
+                                match #schema {
+                                    ::utocli::Schema::Object(obj) => {
+                                        ::utocli::Schema::Object(Box::new((*obj) #string_validation))
+                                    }
+                                    other => other,
+                                }
+                            }
+                        };
+                    }
+
                     schema
                 }
                 Err(err) => err.to_compile_error(),
             }
         } else {
             // Use MixedEnum for enums with field variants
-            match MixedEnum::new(&root, variants) {
+            let rename_all_fields = self
+                .attributes
+                .rename_all_fields
+                .as_deref()
+                .and_then(|r| serde::RenameRule::from_str(r).ok());
+
+            match MixedEnum::new(
+                &root,
+                variants,
+                rename_all_fields,
+                self.attributes.discriminator.as_deref(),
+            ) {
                 Ok(mixed_enum) => {
                     let mut schema = mixed_enum.to_token_stream();
 
@@ -849,6 +1266,10 @@ impl Schema {
                                         obj.description = Some(#desc.to_string());
                                         ::utocli::Schema::Object(obj)
                                     }
+                                    ::utocli::Schema::OneOf(mut one_of) => {
+                                        one_of.description = Some(#desc.to_string());
+                                        ::utocli::Schema::OneOf(one_of)
+                                    }
                                     other => other,
                                 }
                             }
@@ -859,6 +1280,25 @@ impl Schema {
                 }
                 Err(err) => err.to_compile_error(),
             }
+        };
+
+        // Add title if present (container-level)
+        match self.container_title() {
+            Some(title) => quote! {
+                {
+                    match #schema {
+                        ::utocli::Schema::Object(mut obj) => {
+                            obj.title = Some(#title.to_string());
+                            ::utocli::Schema::Object(obj)
+                        }
+                        ::utocli::Schema::OneOf(one_of) => {
+                            ::utocli::Schema::OneOf(one_of.title(#title))
+                        }
+                        other => other,
+                    }
+                }
+            },
+            None => schema,
         }
     }
 }
@@ -886,6 +1326,98 @@ fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
     None
 }
 
+/// Parse an `examples(value, ...)` attribute into its example values.
+fn parse_examples(meta: &syn::meta::ParseNestedMeta) -> Result<Vec<AnyValue>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    let examples = content.parse_terminated(AnyValue::parse_any, syn::Token![,])?;
+    Ok(examples.into_iter().collect())
+}
+
+thread_local! {
+    /// The references built while generating a schema, see [`SchemaReferences::collect`].
+    static SCHEMA_REFERENCES: RefCell<Option<SchemaReferences>> = const { RefCell::new(None) };
+}
+
+/// The schema components referenced by a generated schema.
+#[derive(Default)]
+struct SchemaReferences {
+    /// Names of the components referenced through `$ref`, in order of appearance.
+    names: Vec<String>,
+    /// Types whose schema is inlined (`Type::schema()`), bringing their own references.
+    inlined: Vec<syn::Ident>,
+}
+
+impl SchemaReferences {
+    /// Run `generate`, collecting the references built by [`infer_schema_ref_or`] meanwhile.
+    fn collect<T>(generate: impl FnOnce() -> T) -> (T, Self) {
+        let previous = SCHEMA_REFERENCES.replace(Some(Self::default()));
+        let generated = generate();
+        let references = SCHEMA_REFERENCES.replace(previous).unwrap_or_default();
+        (generated, references)
+    }
+
+    /// Record a component reference, if references are being collected.
+    fn record_name(name: &str) {
+        SCHEMA_REFERENCES.with_borrow_mut(|references| {
+            if let Some(references) = references
+                && !references.names.iter().any(|existing| existing == name)
+            {
+                references.names.push(name.to_string());
+            }
+        });
+    }
+
+    /// Record an inlined type, if references are being collected.
+    fn record_inlined(type_ident: &syn::Ident) {
+        SCHEMA_REFERENCES.with_borrow_mut(|references| {
+            if let Some(references) = references
+                && !references.inlined.contains(type_ident)
+            {
+                references.inlined.push(type_ident.clone());
+            }
+        });
+    }
+
+    /// Generate the `ToSchema::schema_references` implementation, if any reference was built.
+    ///
+    /// The references of inlined types are chained after the direct ones, skipping duplicates.
+    fn to_impl(&self) -> TokenStream {
+        if self.names.is_empty() && self.inlined.is_empty() {
+            return quote! {};
+        }
+
+        let names = &self.names;
+        let inlined = &self.inlined;
+        quote! {
+            fn schema_references() -> ::std::vec::Vec<&'static str> {
+                let mut references: ::std::vec::Vec<&'static str> = ::std::vec![#(#names),*];
+                #(
+                    for reference in <#inlined as ::utocli::ToSchema>::schema_references() {
+                        if !references.contains(&reference) {
+                            references.push(reference);
+                        }
+                    }
+                )*
+                references
+            }
+        }
+    }
+}
+
+/// Extract inner type from `Box<T>`.
+fn get_box_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Box"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        return Some(inner_ty);
+    }
+    None
+}
+
 /// Check if a type is `Vec<T>`.
 fn is_vec_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
@@ -909,6 +1441,140 @@ fn get_vec_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
     None
 }
 
+/// Extract the value type from a `Vec<(K, V)>` of pairs, optionally wrapped in `Option`.
+fn get_pair_value_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let ty = get_option_inner_type(ty).unwrap_or(ty);
+    match get_vec_inner_type(ty)? {
+        syn::Type::Tuple(tuple) if tuple.elems.len() == 2 => tuple.elems.last(),
+        _ => None,
+    }
+}
+
+/// Extract the `T` and `E` types from `Result<T, E>`.
+fn get_result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Result"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+    {
+        let mut types = args.args.iter().filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+        if let (Some(ok_ty), Some(err_ty), None) = (types.next(), types.next(), types.next()) {
+            return Some((ok_ty, err_ty));
+        }
+    }
+    None
+}
+
+/// Extract the value type from `HashMap<K, V>` or `BTreeMap<K, V>`.
+fn get_map_value_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && (segment.ident == "HashMap" || segment.ident == "BTreeMap")
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+    {
+        return args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .nth(1);
+    }
+    None
+}
+
+/// Check if a type refers to `ident` (or `Self`), looking through generic arguments and
+/// compound types such as references, slices, arrays and tuples.
+fn type_references_ident(ty: &syn::Type, ident: &syn::Ident) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            segment.ident == *ident
+                || segment.ident == "Self"
+                || matches!(&segment.arguments, syn::PathArguments::AngleBracketed(args)
+                    if args.args.iter().any(|arg| matches!(arg,
+                        syn::GenericArgument::Type(ty) if type_references_ident(ty, ident))))
+        }),
+        syn::Type::Reference(reference) => type_references_ident(&reference.elem, ident),
+        syn::Type::Slice(slice) => type_references_ident(&slice.elem, ident),
+        syn::Type::Array(array) => type_references_ident(&array.elem, ident),
+        syn::Type::Paren(paren) => type_references_ident(&paren.elem, ident),
+        syn::Type::Group(group) => type_references_ident(&group.elem, ident),
+        syn::Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .any(|elem| type_references_ident(elem, ident)),
+        _ => false,
+    }
+}
+
+/// Check if a `value_type` override is the `Object` marker for a free-form object.
+fn is_free_form_object_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+    {
+        return type_path.path.is_ident("Object");
+    }
+    false
+}
+
+/// Returns the index of the generic type parameter the type refers to, if any.
+fn generic_type_param_index(ty: &syn::Type, type_params: &[&syn::Ident]) -> Option<usize> {
+    if let syn::Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+        && let Some(ident) = type_path.path.get_ident()
+    {
+        return type_params.iter().position(|param| *param == ident);
+    }
+    None
+}
+
+/// Substitute the composed generic schema for types referring to a generic type parameter.
+///
+/// Handles `T`, `Option<T>`, `Vec<T>` and `Option<Vec<T>>`, indexing the `generics` vector of
+/// `ComposeSchema::compose` by the type parameter position. The `inferred` tokens are used
+/// as-is for other types, and as fallback when no schema was provided for the type parameter.
+fn compose_generic_schema_ref_or(
+    ty: &syn::Type,
+    type_params: &[&syn::Ident],
+    inferred: TokenStream,
+) -> TokenStream {
+    if type_params.is_empty() {
+        return inferred;
+    }
+
+    let ty = if is_option_type(ty) {
+        get_option_inner_type(ty).unwrap_or(ty)
+    } else {
+        ty
+    };
+
+    if let Some(index) = generic_type_param_index(ty, type_params) {
+        return quote! {
+            generics.get(#index).cloned().unwrap_or_else(|| #inferred)
+        };
+    }
+
+    if is_vec_type(ty)
+        && let Some(item_ty) = get_vec_inner_type(ty)
+        && let Some(index) = generic_type_param_index(item_ty, type_params)
+    {
+        return quote! {
+            match generics.get(#index).cloned() {
+                Some(items) => ::utocli::RefOr::T(::utocli::Schema::Array(
+                    ::utocli::opencli::Array::new().items(items)
+                )),
+                None => #inferred,
+            }
+        };
+    }
+
+    inferred
+}
+
 /// Infer schema RefOr from Rust type with validation attributes. Returns RefOr<Schema> tokens.
 /// This is a wrapper around `infer_schema_ref_or` that applies field-level validations and default.
 fn infer_schema_ref_or_with_validations(
@@ -943,11 +1609,34 @@ fn infer_schema_ref_or_with_validations(
     // Build validation and default method calls
     let mut method_calls = Vec::new();
 
-    if let Some(min) = field_attrs.minimum {
-        method_calls.push(quote! { .minimum(#min) });
+    // In the numeric form, an exclusive minimum (or maximum) is the bound itself and the
+    // boolean flags are not emitted
+    let numeric = field_attrs.numeric_exclusive_bounds;
+    match (field_attrs.minimum, field_attrs.exclusive_minimum) {
+        (Some(min), Some(true)) if numeric => {
+            method_calls.push(quote! { .exclusive_minimum(#min) });
+        }
+        (min, excl_min) => {
+            if let Some(min) = min {
+                method_calls.push(quote! { .minimum(#min) });
+            }
+            if let Some(excl_min) = excl_min.filter(|_| !numeric) {
+                method_calls.push(quote! { .exclusive_minimum(#excl_min) });
+            }
+        }
     }
-    if let Some(max) = field_attrs.maximum {
-        method_calls.push(quote! { .maximum(#max) });
+    match (field_attrs.maximum, field_attrs.exclusive_maximum) {
+        (Some(max), Some(true)) if numeric => {
+            method_calls.push(quote! { .exclusive_maximum(#max) });
+        }
+        (max, excl_max) => {
+            if let Some(max) = max {
+                method_calls.push(quote! { .maximum(#max) });
+            }
+            if let Some(excl_max) = excl_max.filter(|_| !numeric) {
+                method_calls.push(quote! { .exclusive_maximum(#excl_max) });
+            }
+        }
     }
     if let Some(min_len) = field_attrs.min_length {
         method_calls.push(quote! { .min_length(#min_len) });
@@ -961,12 +1650,6 @@ fn infer_schema_ref_or_with_validations(
     if let Some(mult) = field_attrs.multiple_of {
         method_calls.push(quote! { .multiple_of(#mult) });
     }
-    if let Some(excl_min) = field_attrs.exclusive_minimum {
-        method_calls.push(quote! { .exclusive_minimum(#excl_min) });
-    }
-    if let Some(excl_max) = field_attrs.exclusive_maximum {
-        method_calls.push(quote! { .exclusive_maximum(#excl_max) });
-    }
     if let Some(max_props) = field_attrs.max_properties {
         method_calls.push(quote! { .max_properties(#max_props) });
     }
@@ -1019,6 +1702,24 @@ fn infer_schema_ref_or_with_validations(
 fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> TokenStream {
     use crate::type_tree::TypeTree;
 
+    // Fixed-size arrays are not supported by TypeTree, handle them (and Option-wrapped ones) first
+    let array_ty = if is_option_type(ty) {
+        get_option_inner_type(ty).unwrap_or(ty)
+    } else {
+        ty
+    };
+    if let syn::Type::Array(type_array) = array_ty {
+        return infer_fixed_array_schema_ref_or(type_array, inline, no_recursion);
+    }
+    if let syn::Type::Tuple(type_tuple) = array_ty
+        && !type_tuple.elems.is_empty()
+    {
+        return infer_tuple_schema_ref_or(type_tuple, inline, no_recursion);
+    }
+    if let Some((ok_ty, err_ty)) = get_result_types(array_ty) {
+        return infer_result_schema_ref_or(ok_ty, err_ty, false, inline, no_recursion);
+    }
+
     // Use TypeTree for proper generic analysis
     let type_tree = match TypeTree::from_type(ty) {
         Ok(tree) => tree,
@@ -1040,6 +1741,16 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
         ty
     };
 
+    // References are documented as the referenced type (e.g., `&'a str` as a string)
+    if let syn::Type::Reference(reference) = actual_ty {
+        return infer_schema_ref_or(&reference.elem, inline, no_recursion);
+    }
+
+    // Boxes are documented as the boxed type (e.g., `Option<Box<Node>>` as a `Node` reference)
+    if let Some(boxed_ty) = get_box_inner_type(actual_ty) {
+        return infer_schema_ref_or(boxed_ty, inline, no_recursion);
+    }
+
     // Check for Vec<T> using TypeTree - propagate no_recursion to inner type
     if type_tree.is_vec()
         || (type_tree.is_option() && type_tree.get_wrapped_type().is_some_and(|t| t.is_vec()))
@@ -1064,6 +1775,21 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
         }
     }
 
+    // Maps are objects whose additional properties are the value schema - propagate no_recursion
+    if (type_tree.is_map()
+        || (type_tree.is_option() && type_tree.get_wrapped_type().is_some_and(|t| t.is_map())))
+        && let Some(value_ty) = get_map_value_type(actual_ty)
+    {
+        let value_ref_or = infer_schema_ref_or(value_ty, inline, no_recursion);
+        return quote! {
+            ::utocli::RefOr::T(::utocli::Schema::Object(Box::new(
+                ::utocli::Object::new()
+                    .schema_type(::utocli::SchemaType::Object)
+                    .additional_properties(::utocli::AdditionalProperties::from(#value_ref_or))
+            )))
+        };
+    }
+
     // Extract type identifier for primitive and custom types
     if let syn::Type::Path(type_path) = actual_ty
         && let Some(segment) = type_path.path.segments.last()
@@ -1073,7 +1799,13 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
         return match type_name.as_str() {
             // Primitive types - wrap in RefOr::T (no_recursion doesn't apply)
             "i8" | "i16" | "i32" | "isize" | "i64" | "u8" | "u16" | "u32" | "usize" | "u64"
-            | "f32" | "f64" | "bool" | "String" | "str" => {
+            | "f32" | "f64" | "bool" | "String" | "str" | "PathBuf" | "Path" | "OsString"
+            | "OsStr" => {
+                let schema = infer_schema_inline(actual_ty);
+                quote! { ::utocli::RefOr::T(#schema) }
+            }
+            // String types of the enabled `chrono`/`time`/`uuid` features
+            _ if string_type_format(actual_ty).is_some() => {
                 let schema = infer_schema_inline(actual_ty);
                 quote! { ::utocli::RefOr::T(#schema) }
             }
@@ -1082,6 +1814,7 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
                 if no_recursion {
                     // When no_recursion is set, don't generate inline schema
                     // Just use a reference - this breaks the recursion cycle
+                    SchemaReferences::record_name(&type_name);
                     let ref_path = format!("#/components/schemas/{}", type_name);
                     quote! {
                         ::utocli::RefOr::Ref(::utocli::Ref {
@@ -1091,11 +1824,13 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
                 } else if inline {
                     // Generate inline schema by calling the type's schema() method
                     let type_ident = &segment.ident;
+                    SchemaReferences::record_inlined(type_ident);
                     quote! {
                         ::utocli::RefOr::T(#type_ident::schema())
                     }
                 } else {
                     // Generate reference
+                    SchemaReferences::record_name(&type_name);
                     let ref_path = format!("#/components/schemas/{}", type_name);
                     quote! {
                         ::utocli::RefOr::Ref(::utocli::Ref {
@@ -1112,6 +1847,221 @@ fn infer_schema_ref_or(ty: &syn::Type, inline: bool, no_recursion: bool) -> Toke
     quote! { ::utocli::RefOr::T(#schema) }
 }
 
+/// Infer schema RefOr from a fixed-size array type (`[T; N]`). Returns RefOr<Schema> tokens.
+///
+/// The array length is emitted as both `minItems` and `maxItems`. Only integer literal lengths
+/// are supported; for const-generic or expression lengths (e.g. `[T; N]`, `[T; LEN * 2]`) the
+/// length is not known at macro expansion time and the array is left unbounded.
+fn infer_fixed_array_schema_ref_or(
+    type_array: &syn::TypeArray,
+    inline: bool,
+    no_recursion: bool,
+) -> TokenStream {
+    let items_ref_or = infer_schema_ref_or(&type_array.elem, inline, no_recursion);
+
+    let length = match &type_array.len {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse::<usize>().ok(),
+        _ => None,
+    };
+    let bounds = length.map(|len| {
+        quote! {
+            .min_items(#len)
+            .max_items(#len)
+        }
+    });
+
+    quote! {
+        ::utocli::RefOr::T(::utocli::Schema::Array(
+            ::utocli::opencli::Array::new()
+                .items(#items_ref_or)
+                #bounds
+        ))
+    }
+}
+
+/// Infer schema RefOr from a tuple type (e.g., the `(String, u32)` pairs of a `Vec`). Returns
+/// RefOr<Schema> tokens.
+///
+/// Tuples serialize as fixed-length arrays, so the tuple length is emitted as both `minItems`
+/// and `maxItems`. The items are the element schema when every element has the same type,
+/// and an `anyOf` of the element schemas otherwise, since an item may match several of them
+/// (e.g. a small number in a `(u32, u64)` pair).
+fn infer_tuple_schema_ref_or(
+    type_tuple: &syn::TypeTuple,
+    inline: bool,
+    no_recursion: bool,
+) -> TokenStream {
+    let len = type_tuple.elems.len();
+    let first_ty = type_tuple.elems.first().expect("tuple should not be empty");
+    let all_elems_are_same = type_tuple
+        .elems
+        .iter()
+        .all(|elem| quote!(#elem).to_string() == quote!(#first_ty).to_string());
+
+    let items_ref_or = if all_elems_are_same {
+        infer_schema_ref_or(first_ty, inline, no_recursion)
+    } else {
+        let elem_ref_ors = type_tuple
+            .elems
+            .iter()
+            .map(|elem| infer_schema_ref_or(elem, inline, no_recursion));
+        quote! {
+            ::utocli::RefOr::T(::utocli::Schema::AnyOf(
+                ::utocli::AnyOf::new()
+                    #(.item(#elem_ref_ors))*
+            ))
+        }
+    };
+
+    quote! {
+        ::utocli::RefOr::T(::utocli::Schema::Array(
+            ::utocli::opencli::Array::new()
+                .items(#items_ref_or)
+                .min_items(#len)
+                .max_items(#len)
+        ))
+    }
+}
+
+/// Infer schema RefOr from the `T` and `E` types of a `Result<T, E>`. Returns RefOr<Schema>
+/// tokens.
+///
+/// By default the result is an `anyOf` of the `T` and `E` schemas, since a value may match
+/// both, or the single shared schema when `T` and `E` are the same type. When `tagged` is set,
+/// each branch is wrapped in an object with a single required `Ok` or `Err` property, matching
+/// serde's externally tagged representation of `Result`, and the branches form a `oneOf`.
+fn infer_result_schema_ref_or(
+    ok_ty: &syn::Type,
+    err_ty: &syn::Type,
+    tagged: bool,
+    inline: bool,
+    no_recursion: bool,
+) -> TokenStream {
+    if !tagged {
+        if ok_ty == err_ty {
+            return infer_schema_ref_or(ok_ty, inline, no_recursion);
+        }
+        let branches = [ok_ty, err_ty].map(|ty| infer_schema_ref_or(ty, inline, no_recursion));
+        return quote! {
+            ::utocli::RefOr::T(::utocli::Schema::AnyOf(
+                ::utocli::AnyOf::new()
+                    #(.item(#branches))*
+            ))
+        };
+    }
+
+    let branches = [("Ok", ok_ty), ("Err", err_ty)].map(|(tag, ty)| {
+        let ref_or = infer_schema_ref_or(ty, inline, no_recursion);
+        quote! {
+            ::utocli::RefOr::T(::utocli::Schema::Object(Box::new(
+                ::utocli::Object::new()
+                    .schema_type(::utocli::SchemaType::Object)
+                    .properties(::utocli::Map::from_iter(vec![(#tag.to_string(), #ref_or)]))
+                    .required(vec![#tag.to_string()])
+            )))
+        }
+    });
+
+    quote! {
+        ::utocli::RefOr::T(::utocli::Schema::OneOf(
+            ::utocli::OneOf::new()
+                #(.item(#branches))*
+        ))
+    }
+}
+
+/// Map a format string (e.g., `"date-time"`) to `SchemaFormat` tokens.
+/// Unknown formats fall back to `SchemaFormat::Custom` with the raw string.
+pub(crate) fn schema_format_tokens(format: &str) -> TokenStream {
+    let variant = match format {
+        "path" => quote! { Path },
+        "email" => quote! { Email },
+        "uri" => quote! { Uri },
+        "url" => quote! { Url },
+        "date" => quote! { Date },
+        "date-time" => quote! { DateTime },
+        "time" => quote! { Time },
+        "uuid" => quote! { Uuid },
+        "ipv4" => quote! { Ipv4 },
+        "ipv6" => quote! { Ipv6 },
+        "hostname" => quote! { Hostname },
+        "int32" => quote! { Int32 },
+        "int64" => quote! { Int64 },
+        "float" => quote! { Float },
+        "double" => quote! { Double },
+        _ => return quote! { ::utocli::SchemaFormat::Custom(#format.to_string()) },
+    };
+    quote! { ::utocli::SchemaFormat::#variant }
+}
+
+/// When a third-party type may be recognized without its crate path.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq)]
+enum Bare {
+    /// Only with its crate path, as the name is common to user types (e.g., `Date`).
+    Never,
+    /// Only with generic arguments, as the type is generic (e.g., `DateTime<Utc>`).
+    Generic,
+    /// Always, as the name is specific to its crate (e.g., `OffsetDateTime`).
+    Always,
+}
+
+/// Third-party types serialized as strings, as `(crate, type name, format, bare)`, enabled by
+/// the feature of their crate.
+const STRING_TYPE_FORMATS: &[(&str, &str, &str, Bare)] = &[
+    #[cfg(feature = "chrono")]
+    ("chrono", "DateTime", "date-time", Bare::Generic),
+    #[cfg(feature = "chrono")]
+    ("chrono", "NaiveDateTime", "date-time", Bare::Always),
+    #[cfg(feature = "chrono")]
+    ("chrono", "NaiveDate", "date", Bare::Always),
+    #[cfg(feature = "chrono")]
+    ("chrono", "NaiveTime", "time", Bare::Always),
+    #[cfg(feature = "time")]
+    ("time", "OffsetDateTime", "date-time", Bare::Always),
+    #[cfg(feature = "time")]
+    ("time", "PrimitiveDateTime", "date-time", Bare::Always),
+    #[cfg(feature = "time")]
+    ("time", "Date", "date", Bare::Never),
+    #[cfg(feature = "time")]
+    ("time", "Time", "time", Bare::Never),
+    #[cfg(feature = "uuid")]
+    ("uuid", "Uuid", "uuid", Bare::Never),
+];
+
+/// Get the string format of a third-party type, if its feature is enabled.
+///
+/// Types qualified with their crate (e.g., `chrono::NaiveDate` or `uuid::Uuid`) are always
+/// recognized. Types imported and written bare (e.g., `DateTime<Utc>` or `OffsetDateTime`)
+/// are recognized as long as user types sharing their name (e.g., a `Date` or a non-generic
+/// `DateTime` struct) can be told apart, and keep their own schema otherwise.
+fn string_type_format(ty: &syn::Type) -> Option<&'static str> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segments = &type_path.path.segments;
+    let (first, last) = (segments.first()?, segments.last()?);
+    let is_bare_match = |bare: Bare| match bare {
+        Bare::Never => false,
+        Bare::Generic => !last.arguments.is_empty(),
+        Bare::Always => true,
+    };
+    STRING_TYPE_FORMATS
+        .iter()
+        .find(|(krate, name, _, bare)| {
+            last.ident == name
+                && if segments.len() == 1 {
+                    is_bare_match(*bare)
+                } else {
+                    first.ident == krate
+                }
+        })
+        .map(|(_, _, format, _)| *format)
+}
+
 /// Infer inline schema from Rust type. Returns Schema tokens (not RefOr).
 /// Only handles primitive types - custom types should use `infer_schema_ref_or` instead.
 fn infer_schema_inline(ty: &syn::Type) -> TokenStream {
@@ -1180,6 +2130,25 @@ fn infer_schema_inline(ty: &syn::Type) -> TokenStream {
                         .schema_type(::utocli::SchemaType::String)
                 ))
             },
+            // Filesystem path types
+            "PathBuf" | "Path" | "OsString" | "OsStr" => quote! {
+                ::utocli::Schema::Object(Box::new(
+                    ::utocli::Object::new()
+                        .schema_type(::utocli::SchemaType::String)
+                        .format(::utocli::SchemaFormat::Path)
+                ))
+            },
+            // String types of the enabled `chrono`/`time`/`uuid` features
+            _ if let Some(format) = string_type_format(ty) => {
+                let format = schema_format_tokens(format);
+                quote! {
+                    ::utocli::Schema::Object(Box::new(
+                        ::utocli::Object::new()
+                            .schema_type(::utocli::SchemaType::String)
+                            .format(#format)
+                    ))
+                }
+            }
             // Unknown types default to string
             _ => quote! {
                 ::utocli::Schema::Object(Box::new(
@@ -1213,14 +2182,148 @@ impl Clone for Schema {
             attributes: SchemaAttributes {
                 description: self.attributes.description.clone(),
                 title: self.attributes.title.clone(),
+                title_from_name: self.attributes.title_from_name,
                 rename_all: self.attributes.rename_all.clone(),
+                rename_all_fields: self.attributes.rename_all_fields.clone(),
+                discriminator: self.attributes.discriminator.clone(),
                 no_recursion: self.attributes.no_recursion,
+                inline: self.attributes.inline,
                 as_name: self.attributes.as_name.clone(),
                 example: self.attributes.example.clone(),
+                examples: self.attributes.examples.clone(),
                 deprecated: self.attributes.deprecated,
-                additional_properties: self.attributes.additional_properties,
+                read_only: self.attributes.read_only,
+                write_only: self.attributes.write_only,
+                additional_properties: self.attributes.additional_properties.clone(),
                 bound: self.attributes.bound.clone(),
+                numeric_exclusive_bounds: self.attributes.numeric_exclusive_bounds,
+                min_length: self.attributes.min_length,
+                max_length: self.attributes.max_length,
+                pattern: self.attributes.pattern.clone(),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{Schema, type_references_ident};
+
+    #[test]
+    fn into_token_stream_with_recursive_inline_field_emits_compile_error() {
+        //* Given
+        let input: syn::DeriveInput = parse_quote! {
+            struct Category {
+                #[schema(inline)]
+                children: Vec<Category>,
+            }
+        };
+
+        //* When
+        let tokens = Schema::new(input)
+            .expect("should parse schema attributes")
+            .into_token_stream()
+            .to_string();
+
+        //* Then
+        assert!(
+            tokens.contains("compile_error"),
+            "recursive inline field should expand to a compile error, got {tokens}"
+        );
+        assert!(
+            tokens.contains("inlines the recursive type `Category`"),
+            "error should name the recursive type, got {tokens}"
+        );
+    }
+
+    #[test]
+    fn into_token_stream_with_no_recursion_inline_field_generates_schema() {
+        //* Given
+        let input: syn::DeriveInput = parse_quote! {
+            struct Category {
+                #[schema(inline, no_recursion)]
+                children: Vec<Category>,
+            }
+        };
+
+        //* When
+        let tokens = Schema::new(input)
+            .expect("should parse schema attributes")
+            .into_token_stream()
+            .to_string();
+
+        //* Then
+        assert!(
+            !tokens.contains("compile_error"),
+            "no_recursion should allow inlining the recursive type, got {tokens}"
+        );
+    }
+
+    #[test]
+    fn type_references_ident_with_nested_self_reference_returns_true() {
+        //* Given
+        let ident: syn::Ident = parse_quote!(Category);
+        let ty: syn::Type = parse_quote!(Option<Vec<Box<Category>>>);
+
+        //* When
+        let references = type_references_ident(&ty, &ident);
+
+        //* Then
+        assert!(references, "nested generic argument should be detected");
+    }
+
+    #[test]
+    fn type_references_ident_with_self_keyword_returns_true() {
+        //* Given
+        let ident: syn::Ident = parse_quote!(Category);
+        let ty: syn::Type = parse_quote!(Vec<Self>);
+
+        //* When
+        let references = type_references_ident(&ty, &ident);
+
+        //* Then
+        assert!(references, "`Self` should refer to the container");
+    }
+
+    #[test]
+    fn type_references_ident_with_unrelated_type_returns_false() {
+        //* Given
+        let ident: syn::Ident = parse_quote!(Category);
+        let ty: syn::Type = parse_quote!(Option<Vec<String>>);
+
+        //* When
+        let references = type_references_ident(&ty, &ident);
+
+        //* Then
+        assert!(!references, "unrelated types should not be detected");
+    }
+
+    #[test]
+    fn into_token_stream_with_as_map_on_non_pair_field_emits_compile_error() {
+        //* Given
+        let input: syn::DeriveInput = parse_quote! {
+            struct Labels {
+                #[schema(as_map)]
+                labels: Vec<String>,
+            }
+        };
+
+        //* When
+        let tokens = Schema::new(input)
+            .expect("should parse schema attributes")
+            .into_token_stream()
+            .to_string();
+
+        //* Then
+        assert!(
+            tokens.contains("compile_error"),
+            "as_map on a non-pair field should expand to a compile error, got {tokens}"
+        );
+        assert!(
+            tokens.contains("field `labels` is marked `as_map`"),
+            "error should name the field, got {tokens}"
+        );
+    }
+}
@@ -10,7 +10,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{Fields, Variant, punctuated::Punctuated, token::Comma};
 
-use super::serde::{self, RenameRule, SerdeContainer, SerdeEnumRepr, SerdeValue};
+use super::serde::{self, RenameRule, SerdeContainer, SerdeEnumRepr};
 use crate::doc_comment::parse_doc_comments;
 
 /// Root context for enum schema generation
@@ -33,6 +33,7 @@ impl<'a> Root<'a> {
 pub struct PlainEnum<'e> {
     pub root: &'e Root<'e>,
     variants: Vec<String>,
+    variant_descriptions: Vec<Option<String>>,
     serde_enum_repr: SerdeEnumRepr,
     pub description: Option<String>,
 }
@@ -45,8 +46,8 @@ impl<'e> PlainEnum<'e> {
     ) -> syn::Result<Self> {
         let container_rules = serde::parse_container(root.attributes)?;
 
-        // Collect variant names, applying rename rules
-        let variant_names: Vec<String> = variants
+        // Collect variant names, applying rename rules, along with their doc comments
+        let (variant_names, variant_descriptions): (Vec<String>, Vec<Option<String>>) = variants
             .iter()
             .filter_map(|variant| {
                 // Check for #[serde(skip)]
@@ -66,15 +67,16 @@ impl<'e> PlainEnum<'e> {
                     variant.ident.to_string()
                 };
 
-                Some(name)
+                Some((name, parse_doc_comments(&variant.attrs)))
             })
-            .collect();
+            .unzip();
 
         let description = parse_doc_comments(root.attributes);
 
         Ok(Self {
             root,
             variants: variant_names,
+            variant_descriptions,
             serde_enum_repr: container_rules.enum_repr,
             description,
         })
@@ -121,11 +123,28 @@ impl<'e> PlainEnum<'e> {
             })
             .collect();
 
+        // Emit the variant doc comments as `x-enum-descriptions`, parallel to the enum values
+        let descriptions_tokens = if self.variant_descriptions.iter().any(Option::is_some) {
+            let descriptions = self
+                .variant_descriptions
+                .iter()
+                .map(|description| description.clone().unwrap_or_default());
+            quote! {
+                .extensions(::utocli::Map::from_iter([(
+                    "x-enum-descriptions".to_string(),
+                    serde_json::json!([#(#descriptions),*]),
+                )]))
+            }
+        } else {
+            quote! {}
+        };
+
         quote! {
             ::utocli::Schema::Object(Box::new(
                 ::utocli::Object::new()
                     .schema_type(::utocli::SchemaType::String)
                     .enum_values(vec![#(#enum_values),*])
+                    #descriptions_tokens
             ))
         }
     }
@@ -222,11 +241,31 @@ pub struct MixedEnum<'p> {
 }
 
 impl<'p> MixedEnum<'p> {
-    pub fn new(root: &'p Root, variants: &Punctuated<Variant, Comma>) -> syn::Result<Self> {
+    pub fn new(
+        root: &'p Root,
+        variants: &Punctuated<Variant, Comma>,
+        rename_all_fields: Option<RenameRule>,
+        discriminator: Option<&str>,
+    ) -> syn::Result<Self> {
         let container_rules = serde::parse_container(root.attributes)?;
         let rename_all = container_rules.rename_all;
 
+        if let Some(discriminator) = discriminator {
+            match &container_rules.enum_repr {
+                SerdeEnumRepr::InternallyTagged { tag } if tag == discriminator => {}
+                _ => {
+                    return Err(syn::Error::new(
+                        root.ident.span(),
+                        format!(
+                            "discriminator `{discriminator}` requires an internally tagged enum: `#[serde(tag = \"{discriminator}\")]`"
+                        ),
+                    ));
+                }
+            }
+        }
+
         let mut variant_schemas = Vec::new();
+        let mut discriminated_items = Vec::new();
 
         for variant in variants {
             let variant_serde = serde::parse_value(&variant.attrs)?;
@@ -238,24 +277,50 @@ impl<'p> MixedEnum<'p> {
 
             let name = if let Some(rename) = &variant_serde.rename {
                 rename.clone()
-            } else if let Some(rule) = variant_serde.rename_all.or(rename_all) {
+            } else if let Some(rule) = rename_all {
                 rule.apply(&variant.ident.to_string())
             } else {
                 variant.ident.to_string()
             };
 
+            // A variant `rename_all` renames its own fields, taking precedence over the
+            // container `rename_all_fields`
+            let fields_rename_all = variant_serde
+                .rename_all
+                .or(rename_all_fields)
+                .or(container_rules.rename_all_fields);
+
             let variant_schema = Self::generate_variant_schema(
                 &variant.fields,
                 &name,
                 &container_rules,
-                &variant_serde,
+                fields_rename_all,
             )?;
 
+            if discriminator.is_some() {
+                discriminated_items.push(Self::generate_discriminated_item(
+                    &variant.fields,
+                    &name,
+                    &container_rules,
+                    &variant_schema,
+                ));
+            }
+
             variant_schemas.push((name, variant_schema));
         }
 
         // Generate final schema combining all variants
-        let schema_tokens = Self::combine_variant_schemas(&variant_schemas, &container_rules);
+        let schema_tokens = match discriminator {
+            Some(discriminator) => quote! {
+                {
+                    let mut one_of = ::utocli::OneOf::new();
+                    let mut discriminator = ::utocli::Discriminator::new(#discriminator);
+                    #(#discriminated_items)*
+                    ::utocli::Schema::OneOf(one_of.discriminator(discriminator))
+                }
+            },
+            None => Self::combine_variant_schemas(&variant_schemas, &container_rules),
+        };
 
         let description = parse_doc_comments(root.attributes);
 
@@ -270,7 +335,7 @@ impl<'p> MixedEnum<'p> {
         fields: &Fields,
         variant_name: &str,
         container: &SerdeContainer,
-        _variant_serde: &SerdeValue,
+        fields_rename_all: Option<RenameRule>,
     ) -> syn::Result<TokenStream> {
         match fields {
             Fields::Named(named) => {
@@ -287,6 +352,8 @@ impl<'p> MixedEnum<'p> {
                     let field_name = field.ident.as_ref().unwrap();
                     let field_name_str = if let Some(rename) = field_serde.rename {
                         rename
+                    } else if let Some(rule) = fields_rename_all {
+                        rule.apply(&field_name.to_string())
                     } else {
                         field_name.to_string()
                     };
@@ -339,6 +406,11 @@ impl<'p> MixedEnum<'p> {
                     let ty = &field.ty;
                     let schema_ref_or = super::infer_schema_ref_or(ty, false, false);
 
+                    // Externally tagged newtype variants keep references to the field schema
+                    if container.enum_repr == SerdeEnumRepr::ExternallyTagged {
+                        return Ok(Self::wrap_externally_tagged(schema_ref_or, variant_name));
+                    }
+
                     let schema = quote! {
                         match #schema_ref_or {
                             ::utocli::RefOr::T(s) => s,
@@ -384,6 +456,11 @@ impl<'p> MixedEnum<'p> {
                     ))
                 };
 
+                // Externally tagged unit variants are serialized as the bare variant name
+                if container.enum_repr == SerdeEnumRepr::ExternallyTagged {
+                    return Ok(schema);
+                }
+
                 Ok(Self::wrap_variant_schema(
                     schema,
                     variant_name,
@@ -393,6 +470,59 @@ impl<'p> MixedEnum<'p> {
         }
     }
 
+    /// Generate the statement adding the variant to the discriminated `one_of`.
+    ///
+    /// Newtype variants of a referenced schema are added as the reference, mapped from the
+    /// variant name in the `discriminator`. Other variants are added inline, unit variants as
+    /// objects holding only the tag property.
+    fn generate_discriminated_item(
+        fields: &Fields,
+        variant_name: &str,
+        container: &SerdeContainer,
+        variant_schema: &TokenStream,
+    ) -> TokenStream {
+        match fields {
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                let ty = &unnamed.unnamed.first().unwrap().ty;
+                let schema_ref_or = super::infer_schema_ref_or(ty, false, false);
+                let inline_schema = Self::wrap_variant_schema(
+                    quote! { schema },
+                    variant_name,
+                    &container.enum_repr,
+                );
+                quote! {
+                    match #schema_ref_or {
+                        ::utocli::RefOr::Ref(reference) => {
+                            discriminator = discriminator.mapping(#variant_name, reference.ref_path.clone());
+                            one_of = one_of.item(::utocli::RefOr::Ref(reference));
+                        }
+                        ::utocli::RefOr::T(schema) => {
+                            one_of = one_of.item(#inline_schema);
+                        }
+                    }
+                }
+            }
+            Fields::Unit => {
+                // Unit variants are objects holding only the tag property
+                let schema = Self::wrap_variant_schema(
+                    quote! {
+                        ::utocli::Schema::Object(Box::new(
+                            ::utocli::Object::new().schema_type(::utocli::SchemaType::Object)
+                        ))
+                    },
+                    variant_name,
+                    &container.enum_repr,
+                );
+                quote! {
+                    one_of = one_of.item(#schema);
+                }
+            }
+            _ => quote! {
+                one_of = one_of.item(#variant_schema);
+            },
+        }
+    }
+
     fn wrap_variant_schema(
         schema: TokenStream,
         variant_name: &str,
@@ -400,20 +530,7 @@ impl<'p> MixedEnum<'p> {
     ) -> TokenStream {
         match repr {
             SerdeEnumRepr::ExternallyTagged => {
-                // Wrap in object with variant name as property
-                quote! {
-                    ::utocli::Schema::Object(Box::new(
-                        ::utocli::Object::new()
-                            .schema_type(::utocli::SchemaType::Object)
-                            .properties({
-                                use ::utocli::Map;
-                                Map::from_iter(vec![
-                                    (#variant_name.to_string(), ::utocli::RefOr::T(#schema))
-                                ])
-                            })
-                            .required(vec![#variant_name.to_string()])
-                    ))
-                }
+                Self::wrap_externally_tagged(quote! { ::utocli::RefOr::T(#schema) }, variant_name)
             }
             SerdeEnumRepr::InternallyTagged { tag } => {
                 // Add tag property to the schema
@@ -474,10 +591,38 @@ impl<'p> MixedEnum<'p> {
         }
     }
 
+    /// Wrap a variant schema in an object with the variant name as its single property.
+    fn wrap_externally_tagged(schema_ref_or: TokenStream, variant_name: &str) -> TokenStream {
+        quote! {
+            ::utocli::Schema::Object(Box::new(
+                ::utocli::Object::new()
+                    .schema_type(::utocli::SchemaType::Object)
+                    .properties({
+                        use ::utocli::Map;
+                        Map::from_iter(vec![
+                            (#variant_name.to_string(), #schema_ref_or)
+                        ])
+                    })
+                    .required(vec![#variant_name.to_string()])
+            ))
+        }
+    }
+
     fn combine_variant_schemas(
         variants: &[(String, TokenStream)],
-        _container: &SerdeContainer,
+        container: &SerdeContainer,
     ) -> TokenStream {
+        // Externally tagged variants are one of the variant objects (e.g., `{"A": 123}`)
+        if container.enum_repr == SerdeEnumRepr::ExternallyTagged {
+            let variant_schemas = variants.iter().map(|(_, schema)| schema);
+            return quote! {
+                ::utocli::Schema::OneOf(
+                    ::utocli::OneOf::new()
+                        #(.item(#variant_schemas))*
+                )
+            };
+        }
+
         // For CLI, we use a properties-based approach to represent the enum variants
         // In a true OpenAPI implementation, this would use oneOf
         let variant_props: Vec<_> = variants
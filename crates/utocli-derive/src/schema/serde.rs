@@ -33,6 +33,8 @@ pub enum SerdeEnumRepr {
 pub struct SerdeContainer {
     /// Rename rule for all fields/variants
     pub rename_all: Option<RenameRule>,
+    /// Rename rule for the fields of all struct variants (enums only)
+    pub rename_all_fields: Option<RenameRule>,
     /// Enum representation strategy
     pub enum_repr: SerdeEnumRepr,
     /// Whether #[serde(default)] is set
@@ -43,7 +45,8 @@ pub struct SerdeContainer {
 
 impl SerdeContainer {
     /// Parse a single serde attribute, currently supported attributes are:
-    ///     * `rename_all = ...`
+    ///     * `rename_all = ...` or `rename_all(serialize = ..., deserialize = ...)`
+    ///     * `rename_all_fields = ...` or `rename_all_fields(serialize = ..., deserialize = ...)`
     ///     * `tag = ...`
     ///     * `content = ...`
     ///     * `untagged`
@@ -56,9 +59,13 @@ impl SerdeContainer {
 
         attr.parse_nested_meta(|meta| {
             if meta.path.is_ident("rename_all") {
-                let value = meta.value()?;
-                let s: syn::LitStr = value.parse()?;
-                self.rename_all = Some(RenameRule::from_str(&s.value())?);
+                if let Some(s) = parse_serialize_value(&meta)? {
+                    self.rename_all = Some(RenameRule::from_str(&s.value())?);
+                }
+            } else if meta.path.is_ident("rename_all_fields") {
+                if let Some(s) = parse_serialize_value(&meta)? {
+                    self.rename_all_fields = Some(RenameRule::from_str(&s.value())?);
+                }
             } else if meta.path.is_ident("tag") {
                 let value = meta.value()?;
                 let tag: syn::LitStr = value.parse()?;
@@ -193,13 +200,13 @@ impl SerdeValue {
 
         attr.parse_nested_meta(|meta| {
             if meta.path.is_ident("rename") {
-                let value = meta.value()?;
-                let s: syn::LitStr = value.parse()?;
-                self.rename = Some(s.value());
+                if let Some(s) = parse_serialize_value(&meta)? {
+                    self.rename = Some(s.value());
+                }
             } else if meta.path.is_ident("rename_all") {
-                let value = meta.value()?;
-                let s: syn::LitStr = value.parse()?;
-                self.rename_all = Some(RenameRule::from_str(&s.value())?);
+                if let Some(s) = parse_serialize_value(&meta)? {
+                    self.rename_all = Some(RenameRule::from_str(&s.value())?);
+                }
             } else if meta.path.is_ident("skip") {
                 self.skip = true;
             } else if meta.path.is_ident("skip_serializing") {
@@ -246,6 +253,29 @@ pub fn parse_value(attributes: &[Attribute]) -> syn::Result<SerdeValue> {
     Ok(value)
 }
 
+/// Parse the value of a serde attribute that can be split by direction, e.g. `rename = "..."`
+/// or `rename(serialize = "...", deserialize = "...")`.
+///
+/// The schema documents the serialized form, so the `serialize` value is returned, or `None`
+/// when only a `deserialize` value is given.
+fn parse_serialize_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<syn::LitStr>> {
+    if !meta.input.peek(syn::token::Paren) {
+        return Ok(Some(meta.value()?.parse()?));
+    }
+
+    let mut serialize = None;
+    meta.parse_nested_meta(|meta| {
+        let value: syn::LitStr = meta.value()?.parse()?;
+        if meta.path.is_ident("serialize") {
+            serialize = Some(value);
+        } else if !meta.path.is_ident("deserialize") {
+            return Err(meta.error("expected `serialize` or `deserialize`"));
+        }
+        Ok(())
+    })?;
+    Ok(serialize)
+}
+
 /// Rename rules from serde
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RenameRule {
@@ -337,6 +367,42 @@ impl RenameRule {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_container_with_split_rename_all_uses_serialize_rule() {
+        //* Given
+        let attrs: Vec<Attribute> = vec![syn::parse_quote! {
+            #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+        }];
+
+        //* When
+        let container = parse_container(&attrs).expect("should parse serde container");
+
+        //* Then
+        assert_eq!(
+            container.rename_all,
+            Some(RenameRule::CamelCase),
+            "the serialize rule should be used"
+        );
+    }
+
+    #[test]
+    fn parse_value_with_deserialize_only_rename_keeps_field_name() {
+        //* Given
+        let attrs: Vec<Attribute> = vec![syn::parse_quote! {
+            #[serde(rename(deserialize = "legacy_name"), default)]
+        }];
+
+        //* When
+        let value = parse_value(&attrs).expect("should parse serde value");
+
+        //* Then
+        assert_eq!(value.rename, None, "deserialize renames should be ignored");
+        assert!(
+            value.default,
+            "the following attributes should still be parsed"
+        );
+    }
+
     #[test]
     fn rename_rule_apply_transforms_string_correctly() {
         //* When/Then - Testing pure transformation logic
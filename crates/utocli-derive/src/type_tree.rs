@@ -166,7 +166,6 @@ impl<'t> TypeTree<'t> {
     }
 
     /// Check if this is a Map type (HashMap or BTreeMap).
-    #[allow(dead_code)]
     pub fn is_map(&self) -> bool {
         self.generic_type == Some(GenericType::Map)
     }
@@ -13,10 +13,13 @@ pub use utocli_core;
 pub use utocli_core::opencli;
 // Re-export all main types at the crate root for convenience
 pub use utocli_core::{
-    Architecture, Arity, Array, Command, CommandPath, Commands, Components, ComposeSchema, Contact,
-    EnvironmentVariable, Extensions, ExternalDocs, Info, IntoResponses, License, Map, MediaType,
-    Object, OpenCli, Parameter, ParameterIn, ParameterScope, Platform, PlatformName, Ref, RefOr,
-    Response, Schema, SchemaFormat, SchemaType, Tag, ToResponse, ToSchema,
+    AdditionalProperties, AllOf, AnyOf, Architecture, Arity, Array, Command, CommandExample,
+    CommandNode, CommandPath, Commands, CommandsExt, Components, ComposeSchema, Contact,
+    Discriminator, EnvironmentVariable, Error, ExclusiveBound, ExitCode, Extensions, ExternalDocs,
+    FlagStyle, Info, IntoResponses, License, Lint, Map, MediaType, Object, OneOf, OpenCli,
+    OrderedResponses, Parameter, ParameterIn, ParameterScope, Platform, PlatformName, Ref, RefOr,
+    Response, Schema, SchemaFormat, SchemaType, SpecChange, SpecDiff, Tag, ToResponse, ToSchema,
+    ValidationError,
 };
 // Re-export derive macros when the macros feature is enabled
 #[cfg(feature = "macros")]
@@ -0,0 +1,66 @@
+//! Integration tests for building the commands map with the builder API.
+
+use utocli::opencli::{Command, Commands, CommandsExt as _};
+
+#[test]
+fn command_chaining_with_three_commands_registers_all_paths() {
+    //* When
+    let commands = Commands::new()
+        .command("ocs", Command::new().summary("Root command"))
+        .command("/validate", Command::new().summary("Validate command"))
+        .command("/generate", Command::new().summary("Generate command"));
+
+    //* Then
+    assert_eq!(commands.len(), 3, "should register three commands");
+    let validate = commands
+        .get_command("/validate")
+        .expect("should find the validate command by path");
+    assert_eq!(
+        validate.summary.as_deref(),
+        Some("Validate command"),
+        "should return the command registered under the path"
+    );
+    assert!(
+        commands.get_command("/lint").is_none(),
+        "unregistered path should not be found"
+    );
+}
+
+#[test]
+fn iter_paths_with_chained_commands_yields_insertion_order() {
+    //* Given
+    let commands = Commands::new()
+        .command("ocs", Command::new())
+        .command("/validate", Command::new())
+        .command("/generate", Command::new());
+
+    //* When
+    let paths: Vec<&str> = commands.iter_paths().collect();
+
+    //* Then
+    assert_eq!(
+        paths,
+        vec!["ocs", "/validate", "/generate"],
+        "paths should be iterated in insertion order"
+    );
+}
+
+#[test]
+fn roots_with_multiple_binaries_yields_root_commands_only() {
+    //* Given
+    let commands = Commands::new()
+        .command("foo", Command::new().summary("The foo binary"))
+        .command("/foo/x", Command::new())
+        .command("bar", Command::new().summary("The bar binary"))
+        .command("/bar/y", Command::new());
+
+    //* When
+    let roots: Vec<&str> = commands.roots().collect();
+
+    //* Then
+    assert_eq!(
+        roots,
+        vec!["foo", "bar"],
+        "roots should be the paths without a leading slash"
+    );
+}
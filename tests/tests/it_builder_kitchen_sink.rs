@@ -4,9 +4,10 @@
 //! based on the official OpenCLI specification example.
 
 use utocli::opencli::{
-    Architecture, Arity, Array, Command, Commands, Components, Contact, EnvironmentVariable,
-    ExternalDocs, Info, License, Map, MediaType, Object, OpenCliBuilder, Parameter, ParameterScope,
-    Platform, PlatformName, Ref, RefOr, Response, Schema, SchemaFormat, SchemaType, Tag,
+    Architecture, Arity, Array, Command, Commands, CommandsExt as _, Components, Contact,
+    EnvironmentVariable, ExternalDocs, Info, License, Map, MediaType, Object, OpenCliBuilder,
+    Parameter, ParameterScope, Platform, PlatformName, Ref, RefOr, Response, Schema, SchemaFormat,
+    SchemaType, Tag,
 };
 
 #[test]
@@ -42,6 +43,84 @@ fn generate_opencli_spec_using_builder_succeeds() {
     insta::assert_snapshot!(json_output);
 }
 
+#[test]
+fn serialize_opencli_spec_using_builder_to_toml_succeeds() {
+    //* Given
+    let opencli = OpenCliBuilder::new()
+        .info(build_info())
+        .commands(build_commands())
+        .components(build_components())
+        .tags(build_tags())
+        .platforms(build_platforms())
+        .environment(build_environment_variables())
+        .external_docs(build_external_docs())
+        .build();
+
+    //* When
+    let toml_output = opencli.to_toml().expect("should serialize OpenCLI to TOML");
+
+    //* Then
+    let value: toml::Value = toml::from_str(&toml_output).expect("should parse generated TOML");
+    assert_eq!(
+        value["opencli"].as_str(),
+        Some("1.0.0"),
+        "opencli version should be serialized"
+    );
+    assert_eq!(
+        value["info"]["title"].as_str(),
+        Some(opencli.info.title.as_str()),
+        "info title should be serialized"
+    );
+    let commands = value["commands"]
+        .as_table()
+        .expect("commands should be a table");
+    assert_eq!(
+        commands.len(),
+        opencli.commands.len(),
+        "every command should be serialized"
+    );
+    assert!(
+        commands.contains_key("/validate"),
+        "command paths should be serialized as quoted keys"
+    );
+    let schemas = value["components"]["schemas"]
+        .as_table()
+        .expect("component schemas should be a table");
+    assert!(
+        schemas
+            .values()
+            .any(|schema| schema.to_string().contains("$ref")),
+        "schema references should be flattened into tables"
+    );
+}
+
+#[test]
+fn serialize_opencli_spec_to_toml_sorts_keys() {
+    //* Given
+    let opencli = OpenCliBuilder::new()
+        .info(build_info())
+        .commands(
+            Commands::new()
+                .command("/zeta", Command::new())
+                .command("/alpha", Command::new()),
+        )
+        .build();
+
+    //* When
+    let toml_output = opencli.to_toml().expect("should serialize OpenCLI to TOML");
+
+    //* Then
+    let position = |key: &str| {
+        toml_output
+            .find(key)
+            .unwrap_or_else(|| panic!("{key} should be serialized"))
+    };
+    assert!(
+        position("\"/alpha\"") < position("\"/zeta\""),
+        "commands should be emitted in key order regardless of insertion order"
+    );
+}
+
 #[test]
 fn serialize_opencli_spec_using_builder_to_yaml_succeeds() {
     //* Given
@@ -70,6 +149,45 @@ fn serialize_opencli_spec_using_builder_to_yaml_succeeds() {
     insta::assert_snapshot!(yaml_output);
 }
 
+#[test]
+fn command_tree_with_kitchen_sink_commands_nests_subcommands_under_root() {
+    //* Given
+    let opencli = OpenCliBuilder::new()
+        .info(build_info())
+        .commands(build_commands())
+        .build();
+
+    //* When
+    let tree = opencli.command_tree();
+
+    //* Then
+    assert_eq!(
+        tree.name, "ocs",
+        "the ocs root command should anchor the tree"
+    );
+    assert_eq!(
+        tree.command,
+        opencli.commands.get_command("ocs"),
+        "the root node should hold the root command"
+    );
+    let children = tree
+        .children
+        .iter()
+        .map(|child| child.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        children,
+        ["generate", "lint", "validate"],
+        "subcommands should be children of the root, sorted by name"
+    );
+    assert!(
+        tree.children
+            .iter()
+            .all(|child| child.command.is_some() && child.children.is_empty()),
+        "subcommands should hold their command and have no children"
+    );
+}
+
 /// Builds the Info section with contact and license information.
 fn build_info() -> Info {
     Info::new("Open Command-Line Interface Specification", "1.0.0")
@@ -491,12 +609,11 @@ fn build_components() -> Components {
 
 /// Builds all commands for the CLI.
 fn build_commands() -> Commands {
-    let mut commands = Commands::new();
-    commands.insert("ocs".to_string(), build_root_command());
-    commands.insert("/validate".to_string(), build_validate_command());
-    commands.insert("/generate".to_string(), build_generate_command());
-    commands.insert("/lint".to_string(), build_lint_command());
-    commands
+    Commands::new()
+        .command("ocs", build_root_command())
+        .command("/validate", build_validate_command())
+        .command("/generate", build_generate_command())
+        .command("/lint", build_lint_command())
 }
 
 /// Builds the root 'ocs' command.
@@ -0,0 +1,911 @@
+//! E2E tests for the command attribute macro.
+//!
+//! These tests verify that the `#[command(...)]` attribute generates correct command
+//! definitions for various parameter and response patterns.
+
+#![allow(dead_code)]
+
+use utocli::{
+    CommandPath, CommandsExt as _, ExternalDocs, FlagStyle, Lint, OpenCli as _, ParameterScope,
+    RefOr, Schema,
+};
+
+#[test]
+fn command_with_schema_ref_parameter_generates_component_reference() {
+    //* Given
+    #[utocli::command(
+        name = "export",
+        parameters(
+            (name = "format", in = "option", schema_ref = "OutputFormat")
+        )
+    )]
+    fn export_command() {}
+
+    //* When
+    let command = __command_export_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(parameter) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    match &parameter.schema {
+        Some(RefOr::Ref(reference)) => assert_eq!(
+            reference.ref_path, "#/components/schemas/OutputFormat",
+            "schema_ref should reference the schema component"
+        ),
+        other => panic!("expected a schema reference, got {other:?}"),
+    }
+}
+
+#[test]
+fn command_with_possible_values_from_references_enum_schema_component() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    enum ExportFormat {
+        Json,
+        Yaml,
+    }
+
+    #[utocli::command(
+        name = "export",
+        parameters(
+            (name = "format", in = "option", possible_values_from = ExportFormat)
+        )
+    )]
+    fn export_format_command() {}
+
+    //* When
+    let command = __command_export_format_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(parameter) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    match &parameter.schema {
+        Some(RefOr::Ref(reference)) => assert_eq!(
+            reference.ref_path, "#/components/schemas/ExportFormat",
+            "possible_values_from should reference the enum schema component"
+        ),
+        other => panic!("expected a schema reference, got {other:?}"),
+    }
+}
+
+#[test]
+fn lint_with_enum_repeated_across_derived_commands_reports_duplicate() {
+    //* Given
+    #[utocli::command(
+        name = "validate",
+        parameters(
+            (name = "format", in = "option", enum_values("json", "yaml", "text"))
+        )
+    )]
+    fn validate_command() {}
+
+    #[utocli::command(
+        name = "generate",
+        parameters(
+            (name = "format", in = "option", enum_values("json", "yaml", "text"))
+        )
+    )]
+    fn generate_command() {}
+
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "app", version = "1.0.0"),
+        commands(validate_command, generate_command)
+    )]
+    struct CliDoc;
+
+    //* When
+    let lints = CliDoc::opencli().lint();
+
+    //* Then
+    assert_eq!(lints.len(), 1, "should report the repeated enum once");
+    let Lint::DuplicateInlineEnum { values, locations } = &lints[0] else {
+        panic!("expected a duplicate inline enum lint, got {:?}", lints[0]);
+    };
+    assert_eq!(
+        values,
+        &vec![
+            serde_json::json!("json"),
+            serde_json::json!("yaml"),
+            serde_json::json!("text")
+        ],
+        "should report the json/yaml/text enum"
+    );
+    assert_eq!(
+        locations,
+        &vec!["validate.format".to_string(), "generate.format".to_string()],
+        "should report both commands declaring the enum"
+    );
+}
+
+#[test]
+fn command_with_inline_enum_parameter_generates_inline_schema() {
+    //* Given
+    #[utocli::command(
+        name = "export",
+        parameters(
+            (name = "format", in = "option", enum_values("json", "yaml"))
+        )
+    )]
+    fn export_command() {}
+
+    //* When
+    let command = __command_export_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(parameter) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert!(
+        matches!(&parameter.schema, Some(RefOr::T(Schema::Object(_)))),
+        "parameter without schema_ref should have an inline schema"
+    );
+}
+
+#[test]
+fn command_with_parameter_ref_generates_parameter_component_reference() {
+    //* Given
+    #[utocli::command(
+        name = "build",
+        parameters(
+            (ref = "ConfigFile"),
+            (name = "strict", in = "flag")
+        )
+    )]
+    fn build_command() {}
+
+    //* When
+    let command = __command_build_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    assert_eq!(parameters.len(), 2, "should have both parameters");
+    match &parameters[0] {
+        RefOr::Ref(reference) => assert_eq!(
+            reference.ref_path, "#/components/parameters/ConfigFile",
+            "ref should reference the shared parameter component"
+        ),
+        other => panic!("expected a parameter reference, got {other:?}"),
+    }
+    assert!(
+        matches!(&parameters[1], RefOr::T(parameter) if parameter.name == "strict"),
+        "parameter without ref should be declared inline"
+    );
+}
+
+#[test]
+fn command_with_deprecated_parameter_marks_parameter_deprecated() {
+    //* Given
+    #[utocli::command(
+        name = "sync",
+        parameters(
+            (name = "old-flag", in = "flag", deprecated),
+            (name = "new-flag", in = "flag")
+        )
+    )]
+    fn sync_command() {}
+
+    //* When
+    let command = __command_sync_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let (RefOr::T(old_flag), RefOr::T(new_flag)) = (&parameters[0], &parameters[1]) else {
+        panic!("expected inline parameters");
+    };
+    assert_eq!(
+        old_flag.deprecated,
+        Some(true),
+        "--old-flag should be marked deprecated"
+    );
+    assert_eq!(
+        new_flag.deprecated, None,
+        "--new-flag should not be marked deprecated"
+    );
+}
+
+#[test]
+fn command_with_hidden_parameter_marks_parameter_hidden() {
+    //* Given
+    #[utocli::command(
+        name = "sync",
+        parameters(
+            (name = "debug-dump", in = "flag", hidden),
+            (name = "verbose", in = "flag")
+        )
+    )]
+    fn sync_command() {}
+
+    //* When
+    let command = __command_sync_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let (RefOr::T(debug_dump), RefOr::T(verbose)) = (&parameters[0], &parameters[1]) else {
+        panic!("expected inline parameters");
+    };
+    assert_eq!(
+        debug_dump.hidden,
+        Some(true),
+        "--debug-dump should be marked hidden"
+    );
+    assert_eq!(
+        verbose.hidden, None,
+        "--verbose should not be marked hidden"
+    );
+}
+
+#[test]
+fn command_with_environment_parameter_sets_environment_location() {
+    //* Given
+    #[utocli::command(
+        name = "deploy",
+        parameters(
+            (name = "DEPLOY_TOKEN", in = "environment", description = "API token")
+        )
+    )]
+    fn deploy_command() {}
+
+    //* When
+    let command = __command_deploy_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(token) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(
+        token.in_,
+        Some(utocli::ParameterIn::Environment),
+        "DEPLOY_TOKEN should be sourced from the environment"
+    );
+    let value = serde_json::to_value(token).expect("should serialize parameter");
+    assert_eq!(
+        value["in"], "environment",
+        "environment parameters should serialize as environment"
+    );
+}
+
+#[test]
+fn command_with_related_parameters_sets_requires_and_conflicts() {
+    //* Given
+    #[utocli::command(
+        name = "export",
+        parameters(
+            (name = "json", in = "flag", conflicts("yaml")),
+            (name = "yaml", in = "flag", conflicts("json")),
+            (name = "output", in = "option", requires("json"))
+        )
+    )]
+    fn export_command() {}
+
+    //* When
+    let command = __command_export_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let (RefOr::T(json), RefOr::T(output)) = (&parameters[0], &parameters[2]) else {
+        panic!("expected inline parameters");
+    };
+    assert_eq!(
+        json.conflicts,
+        Some(vec!["yaml".to_string()]),
+        "--json should conflict with --yaml"
+    );
+    assert_eq!(
+        output.requires,
+        Some(vec!["json".to_string()]),
+        "--output should require --json"
+    );
+}
+
+#[test]
+fn command_with_strip_suffix_derives_name_without_custom_suffix() {
+    //* Given
+    #[utocli::command(strip_suffix = "_cmd")]
+    fn list_cmd() {}
+
+    //* When
+    let path = __command_list_cmd::path();
+
+    //* Then
+    assert_eq!(
+        path, "list",
+        "the _cmd suffix should be stripped from the name"
+    );
+}
+
+#[test]
+fn command_with_strip_suffix_repeated_at_end_strips_it_once() {
+    //* Given
+    #[utocli::command(strip_suffix = "s")]
+    fn pass() {}
+
+    //* When
+    let path = __command_pass::path();
+
+    //* Then
+    assert_eq!(path, "pas", "the suffix should be stripped only once");
+}
+
+#[test]
+fn command_with_value_name_sets_parameter_help_placeholder() {
+    //* Given
+    #[utocli::command(
+        name = "render",
+        parameters(
+            (name = "output", in = "option", value_name = "FILE")
+        )
+    )]
+    fn render_command() {}
+
+    //* When
+    let command = __command_render_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(output) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(
+        output.value_name.as_deref(),
+        Some("FILE"),
+        "--output should use the FILE placeholder"
+    );
+}
+
+#[test]
+fn command_with_section_groups_parameter_under_help_section() {
+    //* Given
+    #[utocli::command(
+        name = "fetch",
+        parameters(
+            (name = "proxy", in = "option", section = "Networking")
+        )
+    )]
+    fn fetch_command() {}
+
+    //* When
+    let command = __command_fetch_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(proxy) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(
+        proxy.section.as_deref(),
+        Some("Networking"),
+        "--proxy should be grouped under the Networking section"
+    );
+}
+
+#[test]
+fn command_with_flag_style_sets_flag_style() {
+    //* Given
+    #[utocli::command(
+        name = "lint",
+        parameters(
+            (name = "strict", in = "flag", flag_style = "presence", extend(x_group = "checks"))
+        )
+    )]
+    fn lint_command() {}
+
+    //* When
+    let command = __command_lint_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(strict) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(
+        strict.flag_style,
+        Some(FlagStyle::Presence),
+        "--strict should be a presence flag"
+    );
+    let extensions = strict.extensions.as_ref().expect("should have extensions");
+    assert_eq!(
+        extensions.get("x-group"),
+        Some(&serde_json::json!("checks")),
+        "the flag style should be kept alongside other extensions"
+    );
+}
+
+#[test]
+fn command_with_default_missing_value_makes_color_value_optional() {
+    //* Given
+    #[utocli::command(
+        name = "diff",
+        parameters(
+            (name = "color", in = "option", default_missing_value = "always")
+        )
+    )]
+    fn diff_command() {}
+
+    //* When
+    let command = __command_diff_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(color) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(
+        color.default_missing_value.as_deref(),
+        Some("always"),
+        "a bare --color should default to always"
+    );
+    assert_eq!(
+        color.arity,
+        Some(utocli::Arity::range(0, 1)),
+        "--color should accept zero or one value"
+    );
+}
+
+#[test]
+fn command_with_global_parameter_uses_inherited_scope() {
+    //* Given
+    #[utocli::command(
+        name = "deploy",
+        parameters(
+            (name = "verbose", in = "flag", global),
+            (name = "dry-run", in = "flag")
+        )
+    )]
+    fn deploy_command() {}
+
+    //* When
+    let command = __command_deploy_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let (RefOr::T(verbose), RefOr::T(dry_run)) = (&parameters[0], &parameters[1]) else {
+        panic!("expected inline parameters");
+    };
+    assert_eq!(
+        verbose.scope,
+        Some(ParameterScope::Inherited),
+        "global parameter should be inherited by subcommands"
+    );
+    assert_eq!(
+        dry_run.scope,
+        Some(ParameterScope::Local),
+        "parameter without global should stay local"
+    );
+}
+
+#[test]
+fn command_with_external_docs_links_command_documentation() {
+    //* Given
+    #[utocli::command(
+        name = "deploy",
+        external_docs(
+            url = "https://example.com/docs/deploy",
+            description = "Deployment guide"
+        )
+    )]
+    fn deploy_command() {}
+
+    //* When
+    let command = __command_deploy_command::command();
+
+    //* Then
+    assert_eq!(
+        command.external_docs,
+        Some(ExternalDocs::new("https://example.com/docs/deploy").description("Deployment guide")),
+        "command should link its external documentation"
+    );
+    let json = serde_json::to_value(&command).expect("command should serialize");
+    assert_eq!(
+        json["x-externalDocs"]["url"], "https://example.com/docs/deploy",
+        "external docs should serialize as the x-externalDocs extension"
+    );
+    assert!(
+        json.get("externalDocs").is_none(),
+        "external docs should not use the root-only externalDocs key"
+    );
+}
+
+#[test]
+fn command_with_emit_source_links_command_to_function_path() {
+    //* Given
+    #[utocli::command(name = "deploy", emit_source)]
+    fn deploy_command() {}
+
+    #[utocli::command(name = "status")]
+    fn status_command() {}
+
+    //* When
+    let deploy = __command_deploy_command::command();
+    let status = __command_status_command::command();
+
+    //* Then
+    let source = deploy
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get("x-source"))
+        .and_then(|source| source.as_str())
+        .expect("emit_source should add the x-source extension");
+    assert_eq!(
+        source,
+        concat!(module_path!(), "::deploy_command"),
+        "x-source should hold the fully-qualified function path"
+    );
+    assert!(
+        status
+            .extensions
+            .is_none_or(|extensions| !extensions.contains_key("x-source")),
+        "x-source should only be emitted on opt-in"
+    );
+}
+
+#[test]
+fn command_with_pattern_and_length_bounds_applies_them_to_parameter_schema() {
+    //* Given
+    #[utocli::command(
+        name = "tag",
+        parameters(
+            (
+                name = "version",
+                in = "argument",
+                position = 1,
+                schema_format = "semver",
+                pattern = "^v?[0-9]+\\.[0-9]+\\.[0-9]+$",
+                min_length = 5,
+                max_length = 32
+            )
+        )
+    )]
+    fn tag_command() {}
+
+    //* When
+    let command = __command_tag_command::command();
+
+    //* Then
+    let parameters = command.parameters.expect("should have parameters");
+    let RefOr::T(version) = &parameters[0] else {
+        panic!("expected an inline parameter");
+    };
+    let Some(RefOr::T(Schema::Object(schema))) = &version.schema else {
+        panic!("expected an inline object schema");
+    };
+    assert_eq!(
+        schema.pattern.as_deref(),
+        Some("^v?[0-9]+\\.[0-9]+\\.[0-9]+$"),
+        "schema should carry the pattern"
+    );
+    assert_eq!(schema.min_length, Some(5), "schema should carry min_length");
+    assert_eq!(
+        schema.max_length,
+        Some(32),
+        "schema should carry max_length"
+    );
+    assert!(
+        schema.format.is_some(),
+        "schema should keep the format alongside the pattern"
+    );
+}
+
+#[test]
+fn command_with_range_and_default_responses_keeps_declaration_order() {
+    //* Given
+    #[utocli::command(
+        name = "run",
+        responses(
+            (status = "0", description = "Success"),
+            (status = "2", description = "Misuse of command"),
+            (status = "10-125", description = "Script failure"),
+            (status = "default", description = "Unexpected exit code")
+        )
+    )]
+    fn run_command() {}
+
+    //* When
+    let command = __command_run_command::command();
+
+    //* Then
+    let responses = command.responses.expect("should have responses");
+    let keys = responses.keys().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        vec!["0", "2", "10-125", "default"],
+        "exact, ranged and default responses should serialize in declaration order"
+    );
+}
+
+#[test]
+fn command_with_integer_response_status_normalizes_to_string_key() {
+    //* Given
+    #[utocli::command(
+        name = "check",
+        responses(
+            (status = 0, description = "Success"),
+            (status = 1, description = "Check failed")
+        )
+    )]
+    fn check_command() {}
+
+    //* When
+    let command = __command_check_command::command();
+
+    //* Then
+    let responses = command.responses.expect("should have responses");
+    assert_eq!(
+        responses
+            .get("1")
+            .and_then(|response| response.description.as_deref()),
+        Some("Check failed"),
+        "the integer status should produce the \"1\" key"
+    );
+    assert!(
+        responses.contains_key("0"),
+        "the integer status should produce the \"0\" key"
+    );
+}
+
+#[test]
+fn opencli_with_nested_subcommand_composes_parent_path() {
+    //* Given
+    #[utocli::command(name = "/remote", summary = "Manage remotes")]
+    fn remote_command() {}
+
+    #[utocli::command(name = "add", parent = "/remote", summary = "Add a remote")]
+    fn remote_add_command() {}
+
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "git", version = "1.0.0"),
+        commands(remote_command, remote_add_command)
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    let paths: Vec<&str> = opencli.commands.iter_paths().collect();
+    assert_eq!(
+        paths,
+        vec!["/remote", "/remote/add"],
+        "should key the subcommand under its parent path"
+    );
+    let add = opencli
+        .commands
+        .get_command("/remote/add")
+        .expect("should register the nested subcommand");
+    assert_eq!(
+        add.summary.as_deref(),
+        Some("Add a remote"),
+        "nested path should map to the subcommand definition"
+    );
+}
+
+#[test]
+fn command_with_root_name_parent_keys_subcommand_with_leading_slash() {
+    //* Given
+    #[utocli::command(name = "add", parent = "ocs")]
+    fn add_command() {}
+
+    //* When
+    let path = __command_add_command::path();
+
+    //* Then
+    assert_eq!(
+        path, "/ocs/add",
+        "the parent path should be `/`-separated like the other subcommand keys"
+    );
+}
+
+#[test]
+fn opencli_with_multiple_root_commands_keys_each_binary_separately() {
+    //* Given
+    #[utocli::command(name = "foo", root, summary = "The foo binary")]
+    fn foo_command() {}
+
+    #[utocli::command(name = "bar", root, summary = "The bar binary")]
+    fn bar_command() {}
+
+    #[utocli::command(name = "x", parent = "/foo", summary = "Run foo x")]
+    fn foo_x_command() {}
+
+    #[utocli::command(name = "y", parent = "/bar", summary = "Run bar y")]
+    fn bar_y_command() {}
+
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "toolbox", version = "1.0.0"),
+        commands(foo_command, bar_command, foo_x_command, bar_y_command)
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    let roots: Vec<&str> = opencli.commands.roots().collect();
+    assert_eq!(
+        roots,
+        vec!["foo", "bar"],
+        "should register both binary roots"
+    );
+    let paths: Vec<&str> = opencli.commands.iter_paths().collect();
+    assert_eq!(
+        paths,
+        vec!["foo", "bar", "/foo/x", "/bar/y"],
+        "should key subcommands under their binary root"
+    );
+    let bar_y = opencli
+        .commands
+        .get_command("/bar/y")
+        .expect("should register the bar subcommand");
+    assert_eq!(
+        bar_y.summary.as_deref(),
+        Some("Run bar y"),
+        "subcommand path should map to the subcommand definition"
+    );
+}
+
+#[test]
+fn command_with_multi_paragraph_doc_comment_splits_summary_and_description() {
+    //* Given
+    /// Build the project
+    ///
+    /// Compiles all sources and writes the artifacts
+    /// to the output directory.
+    #[utocli::command(name = "build")]
+    fn build_command() {}
+
+    //* When
+    let command = __command_build_command::command();
+
+    //* Then
+    assert_eq!(
+        command.summary.as_deref(),
+        Some("Build the project"),
+        "first paragraph should become the summary"
+    );
+    assert_eq!(
+        command.description.as_deref(),
+        Some("Compiles all sources and writes the artifacts\nto the output directory."),
+        "remaining paragraphs should become the description"
+    );
+}
+
+#[test]
+fn command_with_single_line_doc_comment_uses_it_as_summary_only() {
+    //* Given
+    /// Build the project
+    #[utocli::command(name = "build")]
+    fn build_command() {}
+
+    //* When
+    let command = __command_build_command::command();
+
+    //* Then
+    assert_eq!(
+        command.summary.as_deref(),
+        Some("Build the project"),
+        "single line should become the summary"
+    );
+    assert_eq!(
+        command.description, None,
+        "single line doc comment should have no description"
+    );
+}
+
+#[test]
+fn command_with_example_file_embeds_file_contents_as_response_example() {
+    //* Given
+    #[utocli::command(
+        name = "validate",
+        responses(
+            (
+                status = "0",
+                description = "Validation successful",
+                content(
+                    (
+                        media_type = "application/json",
+                        example_file = "tests/assets/examples/validate-success.json"
+                    )
+                )
+            )
+        )
+    )]
+    fn validate_command() {}
+
+    //* When
+    let command = __command_validate_command::command();
+
+    //* Then
+    let responses = command.responses.expect("should have responses");
+    let content = responses["0"]
+        .content
+        .as_ref()
+        .expect("should have content");
+    assert_eq!(
+        content["application/json"].example,
+        Some(serde_json::json!({ "valid": true, "file": "opencli.yaml", "errors": [] })),
+        "example file should be embedded as the response example"
+    );
+}
+
+#[test]
+fn command_with_examples_serializes_invocation_examples() {
+    //* Given
+    /// Validate an OpenCLI specification
+    #[utocli::command(
+        name = "validate",
+        examples(
+            (command = "ocs validate spec.yaml", description = "Validate a specification"),
+            (
+                command = "ocs validate spec.yaml --strict",
+                description = "Validate strictly",
+                output = "✓ Validation successful"
+            )
+        )
+    )]
+    fn validate_command() {}
+
+    //* When
+    let command = __command_validate_command::command();
+
+    //* Then
+    let json = serde_json::to_value(&command).expect("command should serialize");
+    assert_eq!(
+        json["x-examples"],
+        serde_json::json!([
+            {
+                "command": "ocs validate spec.yaml",
+                "description": "Validate a specification"
+            },
+            {
+                "command": "ocs validate spec.yaml --strict",
+                "description": "Validate strictly",
+                "output": "✓ Validation successful"
+            }
+        ]),
+        "examples should serialize in declaration order as x-examples"
+    );
+}
+
+#[test]
+fn command_with_single_paragraph_doc_comment_uses_about_as_summary_or_description() {
+    //* Given
+    /// Build the project
+    #[utocli::command(name = "build")]
+    fn build_command() {}
+
+    /// Build the project
+    #[utocli::command(name = "build", about_as_description)]
+    fn build_about_command() {}
+
+    //* When
+    let default_command = __command_build_command::command();
+    let toggled_command = __command_build_about_command::command();
+
+    //* Then
+    assert_eq!(
+        default_command.summary.as_deref(),
+        Some("Build the project"),
+        "the doc comment should be the summary by default"
+    );
+    assert_eq!(
+        default_command.description, None,
+        "the description should be empty by default"
+    );
+    assert_eq!(
+        toggled_command.description.as_deref(),
+        Some("Build the project"),
+        "the doc comment should be the description with about_as_description"
+    );
+    assert_eq!(
+        toggled_command.summary, None,
+        "no summary should be emitted with about_as_description"
+    );
+}
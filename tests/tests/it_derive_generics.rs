@@ -99,6 +99,86 @@ fn generic_struct_with_lifetime_and_type_params() {
     );
 }
 
+#[test]
+fn lifetime_only_struct_with_borrowed_fields_generates_schema() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Borrowed<'a> {
+        name: &'a str,
+        count: u32,
+    }
+
+    //* When
+    let schema = Borrowed::schema();
+    let name = Borrowed::schema_name();
+
+    //* Then
+    let utocli::Schema::Object(obj) = schema else {
+        panic!("Borrowed schema should be Object");
+    };
+    let properties = obj.properties.expect("should have properties");
+    let property_type = |name: &str| match properties.get(name) {
+        Some(utocli::RefOr::T(utocli::Schema::Object(prop))) => prop.schema_type.clone(),
+        _ => None,
+    };
+    assert_eq!(
+        property_type("name"),
+        Some(utocli::SchemaType::String),
+        "&'a str should be a string property"
+    );
+    assert_eq!(
+        property_type("count"),
+        Some(utocli::SchemaType::Integer),
+        "u32 should be an integer property"
+    );
+    assert_eq!(
+        name, "Borrowed",
+        "lifetimes should not appear in schema name"
+    );
+}
+
+#[test]
+fn lifetime_struct_with_borrowed_custom_type_references_component() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Inner {
+        value: i32,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Outer<'a> {
+        inner: &'a Inner,
+        items: Vec<&'a str>,
+    }
+
+    //* When
+    let schema = Outer::schema();
+
+    //* Then
+    let utocli::Schema::Object(obj) = schema else {
+        panic!("Outer schema should be Object");
+    };
+    let properties = obj.properties.expect("should have properties");
+    assert!(
+        matches!(
+            properties.get("inner"),
+            Some(utocli::RefOr::Ref(reference)) if reference.ref_path == "#/components/schemas/Inner"
+        ),
+        "&'a Inner should reference the Inner component"
+    );
+    let Some(utocli::RefOr::T(utocli::Schema::Array(array))) = properties.get("items") else {
+        panic!("Vec<&'a str> should be an array property");
+    };
+    assert!(
+        matches!(
+            array.items.as_deref(),
+            Some(utocli::RefOr::T(utocli::Schema::Object(item)))
+                if item.schema_type == Some(utocli::SchemaType::String)
+        ),
+        "&'a str items should be strings"
+    );
+}
+
 #[test]
 fn generic_struct_with_vec_field() {
     //* Given
@@ -193,8 +273,8 @@ fn generic_enum_compiles_and_generates_schema() {
 
     //* Then
     assert!(
-        matches!(schema, utocli::Schema::Object(_)),
-        "Result enum schema should compile"
+        matches!(schema, utocli::Schema::OneOf(_)),
+        "Result enum schema should be oneOf"
     );
 }
 
@@ -213,8 +293,8 @@ fn generic_enum_with_variants() {
 
     //* Then
     assert!(
-        matches!(schema, utocli::Schema::Object(_)),
-        "Element<String> schema should be Object"
+        matches!(schema, utocli::Schema::OneOf(_)),
+        "Element<String> schema should be oneOf"
     );
 }
 
@@ -339,8 +419,8 @@ fn generic_enum_with_inline_fields() {
 
     //* Then
     assert!(
-        matches!(schema, utocli::Schema::Object(_)),
-        "Variants schema should be Object"
+        matches!(schema, utocli::Schema::OneOf(_)),
+        "Variants schema should be oneOf"
     );
 }
 
@@ -396,8 +476,73 @@ fn generic_types_implement_compose_schema() {
 
     //* Then
     assert!(
-        matches!(composed, utocli::RefOr::Ref(_)),
-        "ComposeSchema should return a Ref"
+        matches!(composed, utocli::RefOr::T(utocli::Schema::Object(_))),
+        "ComposeSchema should return an inline schema"
+    );
+}
+
+#[test]
+fn compose_generic_struct_substitutes_provided_schema() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Wrapper<T> {
+        value: T,
+        label: String,
+    }
+
+    let string_schema = utocli::RefOr::T(utocli::Schema::Object(Box::new(
+        utocli::Object::new().schema_type(utocli::SchemaType::String),
+    )));
+
+    //* When
+    let composed = Wrapper::<String>::compose(vec![string_schema.clone()]);
+
+    //* Then
+    let utocli::RefOr::T(utocli::Schema::Object(obj)) = composed else {
+        panic!("Wrapper<String> should compose to an inline Object schema");
+    };
+    let properties = obj.properties.expect("should have properties");
+    assert_eq!(
+        properties.get("value"),
+        Some(&string_schema),
+        "value should be substituted by the provided string schema"
+    );
+}
+
+#[test]
+fn compose_generic_struct_substitutes_provided_schema_in_containers() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Page<K, V> {
+        key: Option<K>,
+        items: Vec<V>,
+    }
+
+    let string_schema = utocli::RefOr::T(utocli::Schema::Object(Box::new(
+        utocli::Object::new().schema_type(utocli::SchemaType::String),
+    )));
+    let user_ref = utocli::RefOr::new_ref("#/components/schemas/User");
+
+    //* When
+    let composed = Page::<String, i32>::compose(vec![string_schema.clone(), user_ref.clone()]);
+
+    //* Then
+    let utocli::RefOr::T(utocli::Schema::Object(obj)) = composed else {
+        panic!("Page<K, V> should compose to an inline Object schema");
+    };
+    let properties = obj.properties.expect("should have properties");
+    assert_eq!(
+        properties.get("key"),
+        Some(&string_schema),
+        "Option<K> should be substituted by the first provided schema"
+    );
+    let Some(utocli::RefOr::T(utocli::Schema::Array(array))) = properties.get("items") else {
+        panic!("Vec<V> should compose to an Array schema");
+    };
+    assert_eq!(
+        array.items.as_deref(),
+        Some(&user_ref),
+        "Vec<V> items should be substituted by the second provided schema"
     );
 }
 
@@ -5,7 +5,7 @@
 
 #![allow(dead_code)]
 
-use utocli::{IntoResponses as _, RefOr};
+use utocli::{ExitCode, IntoResponses as _, RefOr};
 
 #[test]
 fn into_responses_with_enum_with_multiple_variants_generates_response_map() {
@@ -233,3 +233,287 @@ fn into_responses_with_different_exit_codes_generates_correct_map() {
         "should support exit code 127 (command not found)"
     );
 }
+
+#[test]
+fn into_responses_with_range_and_default_status_generates_exit_code_keys() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    enum ExitCodes {
+        /// Success
+        #[response(status = "0")]
+        Success,
+
+        /// Command failure
+        #[response(status = "1-125")]
+        Failure,
+
+        /// Unexpected exit code
+        #[response(status = "default")]
+        Unexpected,
+    }
+
+    //* When
+    let responses = ExitCodes::responses();
+
+    //* Then
+    let codes = responses
+        .keys()
+        .map(|key| key.parse::<ExitCode>())
+        .collect::<Result<Vec<_>, _>>()
+        .expect("every response key should be a valid exit code");
+    assert_eq!(codes.len(), 3, "should generate a response per variant");
+    assert!(
+        codes.contains(&ExitCode::Range(1, 125)),
+        "should key the ranged response by its range"
+    );
+    assert!(
+        codes.contains(&ExitCode::Default),
+        "should key the default response as default"
+    );
+}
+
+#[test]
+fn into_responses_with_default_descriptions_fills_undocumented_variants() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    #[response(default_descriptions)]
+    enum CommandResponse {
+        #[response(status = "0")]
+        Success,
+
+        #[response(status = 1)]
+        Failure,
+
+        /// Invalid arguments provided
+        #[response(status = "2")]
+        Usage,
+
+        #[response(status = "42")]
+        Custom,
+    }
+
+    //* When
+    let responses = CommandResponse::responses();
+
+    //* Then
+    let description = |status: &str| match responses.get(status) {
+        Some(RefOr::T(response)) => response.description.clone(),
+        _ => panic!("response {status} should be inline"),
+    };
+    assert_eq!(
+        description("0").as_deref(),
+        Some("Success"),
+        "exit code 0 should default to Success"
+    );
+    assert_eq!(
+        description("1").as_deref(),
+        Some("General error"),
+        "exit code 1 should default to General error"
+    );
+    assert_eq!(
+        description("2").as_deref(),
+        Some("Invalid arguments provided"),
+        "doc comment should take precedence over the default description"
+    );
+    assert_eq!(
+        description("42"),
+        None,
+        "unknown exit codes should have no default description"
+    );
+}
+
+#[test]
+fn into_responses_without_default_descriptions_leaves_undocumented_variants_empty() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    enum CommandResponse {
+        #[response(status = "0")]
+        Success,
+
+        #[response(status = "1")]
+        Failure,
+    }
+
+    //* When
+    let responses = CommandResponse::responses();
+
+    //* Then
+    for (status, response) in &responses {
+        let RefOr::T(response) = response else {
+            panic!("response {status} should be inline");
+        };
+        assert_eq!(
+            response.description, None,
+            "response {status} should have no description without the opt-in"
+        );
+    }
+}
+
+#[test]
+fn into_responses_with_extend_adds_extensions_to_variant_response() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    enum FetchResponse {
+        /// Fetched
+        #[response(status = "0")]
+        Success,
+
+        /// Network error
+        #[response(status = "75", extend(x_retryable = "true"))]
+        Unavailable,
+    }
+
+    //* When
+    let responses = FetchResponse::responses();
+
+    //* Then
+    let Some(RefOr::T(unavailable)) = responses.get("75") else {
+        panic!("expected an inline response for status 75");
+    };
+    assert_eq!(
+        unavailable
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-retryable")),
+        Some(&serde_json::json!("true")),
+        "the variant response should carry the x-retryable extension"
+    );
+    let Some(RefOr::T(success)) = responses.get("0") else {
+        panic!("expected an inline response for status 0");
+    };
+    assert_eq!(
+        success.extensions, None,
+        "responses without extend should have no extensions"
+    );
+}
+
+#[test]
+fn into_responses_with_struct_variant_generates_inline_json_schema() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    enum CommandResponse {
+        /// Command succeeded
+        #[response(status = "0")]
+        Success {
+            message: String,
+            code: u32,
+            warning: Option<String>,
+        },
+    }
+
+    //* When
+    let responses = CommandResponse::responses();
+
+    //* Then
+    let RefOr::T(response) = responses.get("0").expect("status 0 should exist") else {
+        panic!("Expected concrete Response, not a reference");
+    };
+    let content = response.content.as_ref().expect("should have content");
+    let media = content
+        .get("application/json")
+        .expect("struct variant should default to application/json content");
+    let schema = serde_json::to_value(&media.schema).expect("should serialize schema");
+    assert_eq!(
+        schema,
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" },
+                "code": { "type": "integer", "format": "int32" },
+                "warning": { "type": "string" }
+            },
+            "required": ["message", "code"]
+        }),
+        "struct variant fields should become inline properties with non-Option fields required"
+    );
+}
+
+#[test]
+fn into_responses_with_serde_attributes_on_struct_variant_follows_serialized_names() {
+    //* Given
+    #[derive(serde::Serialize, utocli::IntoResponses)]
+    enum CommandResponse {
+        /// Command succeeded
+        #[response(status = "0")]
+        #[serde(rename_all = "camelCase")]
+        Success {
+            exit_message: String,
+            #[serde(skip)]
+            internal_state: u32,
+            #[schema(rename = "elapsed")]
+            elapsed_ms: u64,
+        },
+    }
+
+    //* When
+    let responses = CommandResponse::responses();
+
+    //* Then
+    let RefOr::T(response) = responses.get("0").expect("status 0 should exist") else {
+        panic!("Expected concrete Response, not a reference");
+    };
+    let media = response
+        .content
+        .as_ref()
+        .and_then(|content| content.get("application/json"))
+        .expect("struct variant should have application/json content");
+    let schema = serde_json::to_value(&media.schema).expect("should serialize schema");
+    let properties = schema["properties"]
+        .as_object()
+        .expect("schema should have properties");
+    assert!(
+        properties.contains_key("exitMessage"),
+        "rename_all should rename the fields, got {schema}"
+    );
+    assert!(
+        properties.contains_key("elapsed"),
+        "schema rename should rename the field, got {schema}"
+    );
+    assert_eq!(
+        properties.len(),
+        2,
+        "skipped fields should not be documented, got {schema}"
+    );
+}
+
+#[test]
+fn into_responses_ordered_responses_orders_exit_codes_numerically() {
+    //* Given
+    #[derive(utocli::IntoResponses)]
+    enum CommandResponse {
+        /// Success
+        #[response(status = "0")]
+        Success,
+
+        /// Usage error
+        #[response(status = "2")]
+        Usage,
+
+        /// Configuration error
+        #[response(status = "10")]
+        Config,
+    }
+
+    //* When
+    let responses = CommandResponse::ordered_responses();
+
+    //* Then
+    let codes = responses
+        .iter()
+        .map(|(code, _)| code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        ["0", "2", "10"],
+        "exit codes should be ordered numerically"
+    );
+    let json = serde_json::to_string(&responses).expect("responses should serialize");
+    let positions = ["\"0\"", "\"2\"", "\"10\""].map(|key| {
+        json.find(key)
+            .unwrap_or_else(|| panic!("serialized responses should contain {key}"))
+    });
+    assert!(
+        positions.is_sorted(),
+        "serialized responses should keep the numeric order: {json}"
+    );
+}
@@ -39,6 +39,50 @@ fn serialize_opencli_spec_using_derive_to_yaml_succeeds() {
     insta::assert_snapshot!(yaml_output);
 }
 
+#[test]
+fn project_kitchen_sink_schemas_to_json_schema_validates_instances() {
+    //* Given
+    let opencli = CliDoc::opencli();
+
+    //* When
+    let mut json_schema = opencli
+        .to_json_schema()
+        .expect("should project schemas to JSON Schema");
+
+    //* Then
+    assert!(
+        json_schema["$defs"]["ValidationResult"].is_object(),
+        "schemas should be projected under $defs"
+    );
+    assert_eq!(
+        json_schema["$defs"]["ValidationError"]["properties"]["severity"]["$ref"],
+        "#/$defs/Severity",
+        "schema references should point to $defs"
+    );
+
+    json_schema["$ref"] = serde_json::json!("#/$defs/ValidationResult");
+    let validator =
+        jsonschema::validator_for(&json_schema).expect("projected schema should compile");
+    let valid = serde_json::json!({
+        "valid": false,
+        "file": "opencli.yaml",
+        "errors": [{ "line": 3, "message": "unknown field", "severity": "Error" }]
+    });
+    assert!(
+        validator.is_valid(&valid),
+        "a matching instance should be valid"
+    );
+    let invalid = serde_json::json!({
+        "valid": false,
+        "file": "opencli.yaml",
+        "errors": [{ "line": 3, "message": "unknown field", "severity": "Fatal" }]
+    });
+    assert!(
+        !validator.is_valid(&invalid),
+        "an instance violating a referenced schema should be invalid"
+    );
+}
+
 #[derive(utocli::OpenCli)]
 #[opencli(
     info(
@@ -0,0 +1,248 @@
+//! E2E tests for the OpenCli derive macro.
+//!
+//! These tests verify that the derive macro generates correct specification metadata
+//! from the `#[opencli(...)]` attribute.
+
+#![allow(dead_code)]
+
+use utocli::{Architecture, ExternalDocs, Info, OpenCli as _, Platform, PlatformName, Tag};
+
+#[test]
+fn opencli_with_platform_aliases_maps_to_platform_and_architecture_variants() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "app", version = "1.0.0"),
+        platforms(
+            linux,
+            (name = "macos", architectures(x86_64, aarch64)),
+            (name = "windows", architectures = ["amd64", "386"])
+        )
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    assert_eq!(
+        opencli.platforms,
+        Some(vec![
+            Platform::new(PlatformName::Linux),
+            Platform::new(PlatformName::Macos)
+                .architectures(vec![Architecture::Amd64, Architecture::Arm64]),
+            Platform::new(PlatformName::Windows)
+                .architectures(vec![Architecture::Amd64, Architecture::I386]),
+        ]),
+        "platform and architecture aliases should map to their variants"
+    );
+}
+
+#[test]
+fn opencli_with_other_platform_values_maps_to_other_variants() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "app", version = "1.0.0"),
+        platforms(
+            (name = other("haiku"), architectures(amd64, other("e2k"))),
+            (name = other("LINUX"), architectures(other("RISCV64")))
+        )
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    assert_eq!(
+        opencli.platforms,
+        Some(vec![
+            Platform::new(PlatformName::Other("haiku".to_string())).architectures(vec![
+                Architecture::Amd64,
+                Architecture::Other("e2k".to_string())
+            ]),
+            Platform::new(PlatformName::Linux).architectures(vec![Architecture::Riscv64]),
+        ]),
+        "unknown values should map to the other variants and known ones to their variant"
+    );
+}
+
+#[test]
+fn opencli_with_tag_external_docs_links_tag_documentation() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "app", version = "1.0.0"),
+        tags(
+            (name = "core", external_docs(url = "https://example.com/docs/core")),
+            (name = "validation", description = "Validation commands")
+        )
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    assert_eq!(
+        opencli.tags,
+        Some(vec![
+            Tag::new("core").external_docs(ExternalDocs::new("https://example.com/docs/core")),
+            Tag::new("validation").description("Validation commands"),
+        ]),
+        "tag should link its external documentation"
+    );
+    let json = serde_json::to_value(&opencli).expect("specification should serialize");
+    assert_eq!(
+        json["tags"][0]["x-externalDocs"]["url"], "https://example.com/docs/core",
+        "tag external docs should serialize as the x-externalDocs extension"
+    );
+    assert!(
+        json["tags"][0].get("externalDocs").is_none(),
+        "tag external docs should not use the root-only externalDocs key"
+    );
+}
+
+#[test]
+fn opencli_with_info_summary_and_terms_of_service_round_trips_through_json() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(info(
+        title = "app",
+        version = "1.0.0",
+        summary = "Manage app resources",
+        terms_of_service = "https://example.com/terms"
+    ))]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+    let json = serde_json::to_value(&opencli).expect("specification should serialize");
+
+    //* Then
+    assert_eq!(
+        json["info"]["x-summary"], "Manage app resources",
+        "summary should serialize as the x-summary extension"
+    );
+    assert_eq!(
+        json["info"]["x-termsOfService"], "https://example.com/terms",
+        "terms of service should serialize as the x-termsOfService extension"
+    );
+    let parsed: utocli::opencli::OpenCli =
+        serde_json::from_value(json).expect("specification should deserialize");
+    assert_eq!(
+        parsed.info,
+        Info::new("app", "1.0.0")
+            .summary("Manage app resources")
+            .terms_of_service("https://example.com/terms"),
+        "info should round-trip through JSON"
+    );
+}
+
+#[test]
+fn opencli_with_info_author_populates_contact_name_and_email() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(info(
+        title = "app",
+        version = "1.0.0",
+        author = "Jane Doe <jane@example.com>"
+    ))]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    let contact = opencli
+        .info
+        .contact
+        .expect("author should populate the contact");
+    assert_eq!(
+        contact.name.as_deref(),
+        Some("Jane Doe"),
+        "contact name should be the author name"
+    );
+    assert_eq!(
+        contact.email.as_deref(),
+        Some("jane@example.com"),
+        "contact email should be the author email"
+    );
+}
+
+#[test]
+fn opencli_with_bare_info_version_uses_package_version() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(info(title = "app", version))]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    assert_eq!(
+        opencli.info.version,
+        env!("CARGO_PKG_VERSION"),
+        "bare version should expand to the package version"
+    );
+}
+
+#[test]
+fn opencli_with_bare_info_version_ignores_shadowed_env_macro() {
+    //* Given
+    #[allow(unused_macros)]
+    macro_rules! env {
+        ($($tokens:tt)*) => {
+            "shadowed"
+        };
+    }
+
+    #[derive(utocli::OpenCli)]
+    #[opencli(info(title = "app", version))]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+
+    //* Then
+    assert_eq!(
+        opencli.info.version,
+        ::core::env!("CARGO_PKG_VERSION"),
+        "bare version should use the core env macro regardless of local macros"
+    );
+}
+
+#[test]
+fn opencli_with_install_commands_round_trips_through_json() {
+    //* Given
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "ocs", version = "1.0.0"),
+        install(brew = "brew install ocs", cargo = "cargo install ocs")
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = CliDoc::opencli();
+    let json = opencli.to_json().expect("should serialize");
+    let parsed = utocli::opencli::OpenCli::from_json(&json).expect("should deserialize");
+
+    //* Then
+    let install = opencli
+        .install
+        .as_ref()
+        .expect("should have install commands");
+    assert_eq!(
+        install.get("brew").map(String::as_str),
+        Some("brew install ocs"),
+        "the brew install command should be set"
+    );
+    assert_eq!(
+        install.get("cargo").map(String::as_str),
+        Some("cargo install ocs"),
+        "the cargo install command should be set"
+    );
+    assert_eq!(parsed, opencli, "install commands should round-trip");
+}
@@ -5,7 +5,7 @@
 
 #![allow(dead_code)]
 
-use utocli::{Schema, ToSchema as _};
+use utocli::{RefOr, Schema, ToSchema as _};
 
 /// Test case from utoipa: Pet -> Owner -> Pet recursion
 ///
@@ -161,3 +161,61 @@ fn schema_with_mutual_recursion_no_recursion_breaks_type_a_type_b_cycle() {
         "TypeB schema should be an Object schema"
     );
 }
+
+/// An inlined self-referential field compiles once `no_recursion` breaks the cycle.
+#[test]
+fn schema_with_inline_self_reference_and_no_recursion_compiles() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    pub struct Category {
+        name: String,
+        #[schema(inline, no_recursion)]
+        parent: Option<Box<Category>>,
+    }
+
+    //* When
+    let schema = Category::schema();
+
+    //* Then
+    let Schema::Object(object) = schema else {
+        panic!("Category schema should be an Object schema");
+    };
+    let properties = object.properties.expect("should have properties");
+    assert!(
+        properties.contains_key("parent"),
+        "parent field should be documented"
+    );
+}
+
+/// An `Option<Box<Self>>` field with `no_recursion` is documented as a reference to the
+/// container, instead of recursing into its schema.
+#[test]
+fn schema_with_optional_boxed_self_reference_and_no_recursion_references_container() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    pub struct Node {
+        value: i32,
+        #[schema(no_recursion)]
+        next: Option<Box<Node>>,
+    }
+
+    //* When
+    let schema = Node::schema();
+
+    //* Then
+    let Schema::Object(object) = schema else {
+        panic!("Node schema should be an Object schema");
+    };
+    let properties = object.properties.expect("should have properties");
+    assert_eq!(
+        properties.get("next").and_then(RefOr::as_ref_path),
+        Some("#/components/schemas/Node"),
+        "next should reference the Node component, not the Box wrapper"
+    );
+    assert!(
+        !object
+            .required
+            .is_some_and(|required| required.contains(&"next".to_string())),
+        "the optional next field should not be required"
+    );
+}
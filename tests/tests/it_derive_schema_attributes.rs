@@ -1,12 +1,13 @@
 //! Tests for full struct schema attribute support (100% utoipa parity).
 //!
 //! These tests verify complete support for all struct schema attributes:
-//! - Field-level: example, deprecated, read_only, write_only, nullable, value_type, title
-//! - Container-level: example, deprecated, additional_properties
+//! - Field-level: example, examples, example_from_default, deprecated, read_only, write_only,
+//!   nullable, value_type, title
+//! - Container-level: example, examples, deprecated, additional_properties, read_only, write_only
 
 #![allow(dead_code)]
 
-use utocli::{Schema, ToSchema};
+use utocli::{AdditionalProperties, RefOr, Schema, SchemaFormat, ToSchema};
 
 #[test]
 fn derive_struct_with_field_example() {
@@ -33,6 +34,44 @@ fn derive_struct_with_field_example() {
     // We verify compilation succeeds which confirms the generated code is valid
 }
 
+#[test]
+fn derive_struct_with_example_from_default_uses_default_value_as_example() {
+    //* Given
+    #[derive(Default, serde::Serialize, utocli::ToSchema)]
+    struct RetryPolicy {
+        attempts: u32,
+        backoff: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Config {
+        #[schema(example_from_default)]
+        threads: u32,
+        #[schema(inline, example_from_default)]
+        retry: RetryPolicy,
+    }
+
+    //* When
+    let schema = Config::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["threads"]["example"],
+        serde_json::json!(0),
+        "primitive example should be the default value"
+    );
+    assert_eq!(
+        value["properties"]["retry"]["example"],
+        serde_json::json!({ "attempts": 0, "backoff": "" }),
+        "inline struct example should be the serialized default value"
+    );
+    assert!(
+        value["properties"]["retry"].get("default").is_none(),
+        "example_from_default should not set the schema default"
+    );
+}
+
 #[test]
 fn derive_struct_with_deprecated_field() {
     //* Given
@@ -97,6 +136,95 @@ fn derive_struct_with_write_only_field() {
     // Compilation success confirms write_only attribute is properly handled
 }
 
+#[test]
+fn derive_struct_with_read_only_container_marks_every_field() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(read_only)]
+    struct Build {
+        id: u64,
+        status: String,
+    }
+
+    //* When
+    let schema = Build::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    for field in ["id", "status"] {
+        assert_eq!(
+            value["properties"][field]["readOnly"],
+            serde_json::json!(true),
+            "{field} should be read-only"
+        );
+        assert!(
+            value["properties"][field].get("writeOnly").is_none(),
+            "{field} should not be write-only"
+        );
+    }
+}
+
+#[test]
+fn derive_struct_with_read_only_container_wraps_reference_and_array_fields() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    #[schema(read_only)]
+    struct Build {
+        owner: Owner,
+        tags: Vec<String>,
+    }
+
+    //* When
+    let schema = Build::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["owner"],
+        serde_json::json!({ "allOf": [{ "$ref": "#/components/schemas/Owner" }], "readOnly": true }),
+        "the reference should be wrapped in a read-only allOf"
+    );
+    assert_eq!(
+        value["properties"]["tags"]["readOnly"],
+        serde_json::json!(true),
+        "the array should be wrapped in a read-only allOf"
+    );
+    assert_eq!(
+        value["properties"]["tags"]["allOf"][0]["type"],
+        serde_json::json!("array"),
+        "the wrapped schema should be the array"
+    );
+}
+
+#[test]
+fn derive_struct_with_write_only_container_marks_every_field() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(write_only)]
+    struct Credentials {
+        username: String,
+        password: String,
+    }
+
+    //* When
+    let schema = Credentials::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    for field in ["username", "password"] {
+        assert_eq!(
+            value["properties"][field]["writeOnly"],
+            serde_json::json!(true),
+            "{field} should be write-only"
+        );
+    }
+}
+
 #[test]
 fn derive_struct_with_nullable_field() {
     //* Given
@@ -160,6 +288,107 @@ fn derive_struct_with_value_type_override() {
     // The timestamp field should be inferred as String due to value_type override
 }
 
+#[test]
+fn derive_struct_with_value_type_override_on_newtype_field() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct MyId(u64);
+
+    #[derive(utocli::ToSchema)]
+    struct Order {
+        #[schema(value_type = String)]
+        id: MyId,
+    }
+
+    //* When
+    let schema = Order::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    let properties = obj.properties.expect("should have properties");
+    let Some(RefOr::T(Schema::Object(id))) = properties.get("id") else {
+        panic!("id should be an inline object schema, not a reference to MyId");
+    };
+    assert_eq!(
+        id.schema_type,
+        Some(utocli::SchemaType::String),
+        "id should be documented as a string"
+    );
+}
+
+#[test]
+fn derive_struct_with_value_type_override_to_vec() {
+    //* Given
+    struct Samples;
+
+    #[derive(utocli::ToSchema)]
+    struct Measurement {
+        #[schema(value_type = Vec<i32>)]
+        samples: Samples,
+    }
+
+    //* When
+    let schema = Measurement::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    let properties = obj.properties.expect("should have properties");
+    let Some(RefOr::T(Schema::Array(samples))) = properties.get("samples") else {
+        panic!("samples should be an array schema");
+    };
+    let Some(RefOr::T(Schema::Object(items))) = samples.items.as_deref() else {
+        panic!("samples items should be an inline object schema");
+    };
+    assert_eq!(
+        items.schema_type,
+        Some(utocli::SchemaType::Integer),
+        "samples items should be integers"
+    );
+    assert_eq!(
+        items.format,
+        Some(SchemaFormat::Int32),
+        "samples items should have int32 format"
+    );
+}
+
+#[test]
+fn derive_struct_with_value_type_object_produces_free_form_object() {
+    //* Given
+    struct Metadata;
+
+    #[derive(utocli::ToSchema)]
+    struct Resource {
+        #[schema(value_type = Object)]
+        metadata: Metadata,
+    }
+
+    //* When
+    let schema = Resource::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    let properties = obj.properties.expect("should have properties");
+    let Some(RefOr::T(Schema::Object(metadata))) = properties.get("metadata") else {
+        panic!("metadata should be an inline object schema");
+    };
+    assert_eq!(
+        metadata.schema_type,
+        Some(utocli::SchemaType::Object),
+        "metadata should be an object"
+    );
+    assert_eq!(
+        metadata.additional_properties,
+        Some(AdditionalProperties::Bool(true)),
+        "metadata should allow additional properties"
+    );
+}
+
 #[test]
 fn derive_struct_with_field_title() {
     //* Given
@@ -230,6 +459,45 @@ fn derive_struct_with_container_example() {
     // Compilation success confirms example attributes are properly handled
 }
 
+#[test]
+fn derive_struct_with_function_example_serializes_returned_value() {
+    //* Given
+    mod examples {
+        #[derive(serde::Serialize)]
+        pub struct User {
+            pub id: u64,
+            pub name: String,
+        }
+
+        pub fn example_user() -> User {
+            User {
+                id: 1,
+                name: "alice".to_string(),
+            }
+        }
+    }
+
+    #[derive(utocli::ToSchema)]
+    #[schema(example = examples::example_user)]
+    struct User {
+        id: u64,
+        name: String,
+    }
+
+    //* When
+    let schema = User::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(
+        obj.example,
+        Some(serde_json::json!({ "id": 1, "name": "alice" })),
+        "example should be the JSON of the value returned by the function"
+    );
+}
+
 #[test]
 fn derive_struct_with_container_deprecated() {
     //* Given
@@ -264,10 +532,90 @@ fn derive_struct_with_no_additional_properties() {
     let schema = StrictStruct::schema();
 
     //* Then
-    let Schema::Object(_obj) = schema else {
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(
+        obj.additional_properties,
+        Some(AdditionalProperties::Bool(false)),
+        "additional properties should be forbidden"
+    );
+}
+
+#[test]
+fn derive_struct_with_typed_additional_properties() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(additional_properties = String)]
+    struct Labels {
+        name: String,
+    }
+
+    //* When
+    let schema = Labels::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    let Some(AdditionalProperties::Schema(values)) = obj.additional_properties else {
+        panic!("additional properties should be a schema");
+    };
+    let RefOr::T(Schema::Object(values)) = *values else {
+        panic!("additional properties schema should be an inline object schema");
+    };
+    assert_eq!(
+        values.schema_type,
+        Some(utocli::SchemaType::String),
+        "additional property values should be strings"
+    );
+}
+
+#[test]
+fn derive_struct_with_map_field_uses_value_schema_as_additional_properties() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Inventory {
+        stock: std::collections::HashMap<String, u32>,
+        owners: std::collections::BTreeMap<String, Owner>,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    //* When
+    let schema = Inventory::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
         panic!("Expected Object schema");
     };
-    // Compilation success confirms additional_properties is properly handled
+    let properties = obj.properties.expect("should have properties");
+    let Some(RefOr::T(Schema::Object(stock))) = properties.get("stock") else {
+        panic!("stock should be an inline object schema");
+    };
+    let Some(AdditionalProperties::Schema(stock_values)) = &stock.additional_properties else {
+        panic!("stock additional properties should be a schema");
+    };
+    assert!(
+        matches!(
+            stock_values.as_ref(),
+            RefOr::T(Schema::Object(values)) if values.schema_type == Some(utocli::SchemaType::Integer)
+        ),
+        "stock values should be integers"
+    );
+    let Some(RefOr::T(Schema::Object(owners))) = properties.get("owners") else {
+        panic!("owners should be an inline object schema");
+    };
+    assert_eq!(
+        owners.additional_properties,
+        Some(AdditionalProperties::Schema(Box::new(RefOr::new_ref(
+            "#/components/schemas/Owner"
+        )))),
+        "owners values should reference the Owner schema"
+    );
 }
 
 #[test]
@@ -290,6 +638,86 @@ fn derive_struct_with_container_title() {
     // Compilation success confirms container title is properly handled
 }
 
+#[test]
+fn derive_struct_with_title_from_name_uses_type_name_as_title() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(title_from_name)]
+    struct Profile {
+        username: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    #[schema(title_from_name)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    //* When
+    let profile = Profile::schema();
+    let page = Page::<String>::schema();
+
+    //* Then
+    let Schema::Object(profile) = profile else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(
+        profile.title.as_deref(),
+        Some("Profile"),
+        "title should be the type name"
+    );
+    let Schema::Object(page) = page else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(
+        page.title.as_deref(),
+        Some("Page<T>"),
+        "title should include the generic parameters"
+    );
+}
+
+#[test]
+fn derive_struct_without_title_from_name_emits_no_title() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Profile {
+        username: String,
+    }
+
+    //* When
+    let schema = Profile::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(obj.title, None, "title should not be inferred by default");
+}
+
+#[test]
+fn derive_enum_with_title_from_name_uses_type_name_as_title() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(title_from_name)]
+    enum Severity {
+        Error,
+        Warning,
+    }
+
+    //* When
+    let schema = Severity::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema");
+    };
+    assert_eq!(
+        obj.title.as_deref(),
+        Some("Severity"),
+        "title should be the enum name"
+    );
+}
+
 #[test]
 fn derive_struct_with_all_container_attributes() {
     //* Given
@@ -441,3 +869,153 @@ fn derive_struct_with_mixed_literal_and_json_examples() {
     assert_eq!(props.len(), 4, "should have 4 properties");
     // Compilation success confirms mixing literals and json!() works
 }
+
+#[test]
+fn derive_struct_with_field_format_round_trips_through_serialization() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Event {
+        #[schema(format = "date-time")]
+        created_at: String,
+        #[schema(format = "uuid")]
+        id: String,
+        #[schema(format = "semver")]
+        version: String,
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["created_at"]["format"],
+        serde_json::json!("date-time"),
+        "date-time format should be serialized"
+    );
+    assert_eq!(
+        value["properties"]["id"]["format"],
+        serde_json::json!("uuid"),
+        "uuid format should be serialized"
+    );
+    assert_eq!(
+        value["properties"]["version"]["format"],
+        serde_json::json!("semver"),
+        "unknown format should be serialized as its raw string"
+    );
+
+    let Schema::Object(round_trip) =
+        serde_json::from_value(value).expect("should deserialize schema")
+    else {
+        panic!("Expected Object schema");
+    };
+    let properties = round_trip.properties.expect("should have properties");
+    let format_of = |name: &str| match properties.get(name) {
+        Some(RefOr::T(Schema::Object(obj))) => obj.format.clone(),
+        _ => None,
+    };
+    assert_eq!(
+        format_of("created_at"),
+        Some(SchemaFormat::DateTime),
+        "date-time format should deserialize to its variant"
+    );
+    assert_eq!(
+        format_of("id"),
+        Some(SchemaFormat::Uuid),
+        "uuid format should deserialize to its variant"
+    );
+    assert_eq!(
+        format_of("version"),
+        Some(SchemaFormat::Custom("semver".to_string())),
+        "unknown format should deserialize to a custom format"
+    );
+}
+
+#[test]
+fn derive_struct_with_container_inline_is_embedded_instead_of_registered() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(inline)]
+    struct Coordinates {
+        lat: f64,
+        lon: f64,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Location {
+        name: String,
+        coordinates: Coordinates,
+    }
+
+    #[derive(utocli::OpenCli)]
+    #[opencli(
+        info(title = "geo", version = "1.0.0"),
+        components(schemas(Location, Coordinates))
+    )]
+    struct CliDoc;
+
+    //* When
+    let opencli = <CliDoc as utocli::OpenCli>::opencli();
+
+    //* Then
+    assert!(Coordinates::inline(), "Coordinates should be marked inline");
+    assert!(!Location::inline(), "Location should not be marked inline");
+
+    let schemas = opencli
+        .components
+        .and_then(|components| components.schemas)
+        .expect("should have schema components");
+    assert!(
+        !schemas.contains_key("Coordinates"),
+        "inline schema should not be registered as a component"
+    );
+    let Some(RefOr::T(Schema::Object(location))) = schemas.get("Location") else {
+        panic!("Location should be registered as an inline object schema");
+    };
+    let properties = location
+        .properties
+        .as_ref()
+        .expect("should have properties");
+    let Some(RefOr::T(Schema::Object(coordinates))) = properties.get("coordinates") else {
+        panic!("coordinates should be embedded instead of referenced");
+    };
+    assert!(
+        coordinates
+            .properties
+            .as_ref()
+            .is_some_and(|properties| properties.contains_key("lat")),
+        "embedded schema should be the Coordinates schema"
+    );
+}
+
+#[test]
+fn derive_struct_with_examples_serializes_examples_array() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(examples(serde_json::json!({"name": "rex"}), serde_json::json!({"name": "tom"})))]
+    struct Pet {
+        #[schema(example = "rex", examples("rex", "tom"))]
+        name: String,
+    }
+
+    //* When
+    let schema = Pet::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["examples"],
+        serde_json::json!([{"name": "rex"}, {"name": "tom"}]),
+        "container examples should serialize as the examples array"
+    );
+    assert_eq!(
+        value["properties"]["name"]["examples"],
+        serde_json::json!(["rex", "tom"]),
+        "field examples should serialize as the examples array"
+    );
+    assert_eq!(
+        value["properties"]["name"]["example"],
+        serde_json::json!("rex"),
+        "the singular example should be kept alongside the examples"
+    );
+}
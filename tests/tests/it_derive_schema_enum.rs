@@ -9,7 +9,7 @@
 #![allow(dead_code)]
 
 use serde_json::json;
-use utocli::{Schema, SchemaType, ToSchema};
+use utocli::{OneOf, RefOr, Schema, SchemaType, ToSchema};
 
 #[test]
 fn derive_to_schema_with_plain_enum_generates_string_schema() {
@@ -222,7 +222,7 @@ fn derive_to_schema_with_untagged_generates_null_schema() {
 }
 
 #[test]
-fn derive_to_schema_with_mixed_enum_named_fields_generates_object_with_variant_properties() {
+fn derive_to_schema_with_mixed_enum_named_fields_generates_one_of_variant_objects() {
     //* Given
     #[derive(utocli::ToSchema, serde::Serialize)]
     enum ApiResponse {
@@ -234,25 +234,19 @@ fn derive_to_schema_with_mixed_enum_named_fields_generates_object_with_variant_p
     let schema = ApiResponse::schema();
 
     //* Then
-    let Schema::Object(obj) = schema else {
-        panic!("Expected Object schema for mixed enum with named fields");
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for mixed enum with named fields");
     };
+    assert_eq!(one_of.items.len(), 2, "should have one branch per variant");
     assert_eq!(
-        obj.schema_type,
-        Some(SchemaType::Object),
-        "mixed enum should generate object schema"
-    );
-    let props = obj
-        .properties
-        .expect("mixed enum should have variant properties");
-    assert!(
-        props.contains_key("Success") || props.contains_key("Error"),
-        "properties should contain variant names"
+        variant_property_names(&one_of),
+        vec!["Success", "Error"],
+        "each branch should be keyed by its variant name"
     );
 }
 
 #[test]
-fn derive_to_schema_with_mixed_enum_unnamed_fields_generates_object_schema() {
+fn derive_to_schema_with_mixed_enum_unnamed_fields_generates_one_of_schema() {
     //* Given
     #[derive(utocli::ToSchema, serde::Serialize)]
     enum Result<T> {
@@ -264,17 +258,13 @@ fn derive_to_schema_with_mixed_enum_unnamed_fields_generates_object_schema() {
     let schema = Result::<u32>::schema();
 
     //* Then
-    let Schema::Object(obj) = schema else {
-        panic!("Expected Object schema for mixed enum with unnamed fields");
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for mixed enum with unnamed fields");
     };
     assert_eq!(
-        obj.schema_type,
-        Some(SchemaType::Object),
-        "mixed enum should generate object schema"
-    );
-    assert!(
-        obj.properties.is_some(),
-        "mixed enum should have properties"
+        variant_property_names(&one_of),
+        vec!["Ok", "Err"],
+        "each branch should be keyed by its variant name"
     );
 }
 
@@ -292,19 +282,135 @@ fn derive_to_schema_with_mixed_variants_includes_all_variant_types() {
     let schema = Message::schema();
 
     //* Then
-    let Schema::Object(obj) = schema else {
-        panic!("Expected Object schema for enum with mixed variants");
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for enum with mixed variants");
     };
     assert_eq!(
-        obj.schema_type,
+        one_of.items.len(),
+        3,
+        "all three variants should be present in oneOf"
+    );
+    let RefOr::T(Schema::Object(empty)) = &one_of.items[2] else {
+        panic!("unit variant branch should be an inline object schema");
+    };
+    assert_eq!(
+        empty.enum_values,
+        Some(vec![json!("Empty")]),
+        "unit variant should be serialized as its bare name"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_externally_tagged_newtype_variant_wraps_field_schema() {
+    //* Given
+    #[derive(utocli::ToSchema, serde::Serialize)]
+    enum E {
+        A(u32),
+        B { x: String },
+    }
+
+    //* When
+    let schema = E::schema();
+
+    //* Then
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for externally tagged enum");
+    };
+    let RefOr::T(Schema::Object(branch)) = &one_of.items[0] else {
+        panic!("newtype variant branch should be an inline object schema");
+    };
+    assert_eq!(
+        branch.schema_type,
         Some(SchemaType::Object),
-        "mixed enum should generate object schema"
+        "newtype variant branch should be an object"
     );
-    let props = obj.properties.expect("mixed enum should have properties");
     assert_eq!(
-        props.len(),
-        3,
-        "all three variants should be present in properties"
+        branch.required,
+        Some(vec!["A".to_string()]),
+        "variant property should be required"
+    );
+    let props = branch
+        .properties
+        .as_ref()
+        .expect("branch should have properties");
+    assert_eq!(props.len(), 1, "branch should have a single property");
+    let Some(RefOr::T(Schema::Object(value))) = props.get("A") else {
+        panic!("variant property should be the inline field schema");
+    };
+    assert_eq!(
+        value.schema_type,
+        Some(SchemaType::Integer),
+        "newtype variant property should be the u32 schema"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_externally_tagged_struct_variant_wraps_fields_object() {
+    //* Given
+    #[derive(utocli::ToSchema, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Event {
+        Started(u32),
+        FileChanged { path: String },
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for externally tagged enum");
+    };
+    let RefOr::T(Schema::Object(branch)) = &one_of.items[1] else {
+        panic!("struct variant branch should be an inline object schema");
+    };
+    assert_eq!(
+        branch.required,
+        Some(vec!["file_changed".to_string()]),
+        "renamed variant property should be required"
+    );
+    let props = branch
+        .properties
+        .as_ref()
+        .expect("branch should have properties");
+    let Some(RefOr::T(Schema::Object(fields))) = props.get("file_changed") else {
+        panic!("variant property should be named after the renamed variant");
+    };
+    assert_eq!(
+        fields.schema_type,
+        Some(SchemaType::Object),
+        "struct variant property should be an object"
+    );
+    assert!(
+        fields
+            .properties
+            .as_ref()
+            .is_some_and(|fields| fields.contains_key("path")),
+        "struct variant property should describe the variant fields"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_documented_mixed_enum_sets_one_of_description() {
+    //* Given
+    /// A filesystem event
+    #[derive(utocli::ToSchema, serde::Serialize)]
+    enum Event {
+        Created(String),
+        Removed { path: String },
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let Schema::OneOf(one_of) = schema else {
+        panic!("Expected oneOf schema for externally tagged enum");
+    };
+    assert_eq!(
+        one_of.description.as_deref(),
+        Some("A filesystem event"),
+        "doc comment should become the oneOf description"
     );
 }
 
@@ -562,3 +668,208 @@ fn derive_to_schema_with_kebab_case_rename_all_transforms_to_kebab_case() {
         "variant names should be transformed to kebab-case"
     );
 }
+
+#[test]
+fn derive_to_schema_with_variant_doc_comments_generates_enum_descriptions() {
+    //* Given
+    #[derive(utocli::ToSchema, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum OutputFormat {
+        /// Machine-readable JSON output
+        Json,
+        Yaml,
+        /// Human-readable plain text
+        Text,
+    }
+
+    //* When
+    let schema = OutputFormat::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema for plain enum");
+    };
+    assert_eq!(
+        obj.enum_values,
+        Some(vec![json!("json"), json!("yaml"), json!("text")]),
+        "variant names should be transformed by rename_all rule"
+    );
+    let extensions = obj.extensions.expect("should have extensions");
+    assert_eq!(
+        extensions.get("x-enum-descriptions"),
+        Some(&json!([
+            "Machine-readable JSON output",
+            "",
+            "Human-readable plain text"
+        ])),
+        "variant descriptions should be parallel to the enum values"
+    );
+}
+
+#[test]
+fn derive_to_schema_without_variant_doc_comments_omits_enum_descriptions() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    enum Level {
+        Low,
+        High,
+    }
+
+    //* When
+    let schema = Level::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema for plain enum");
+    };
+    assert_eq!(
+        obj.extensions, None,
+        "enum without variant doc comments should not have extensions"
+    );
+}
+
+/// Returns the single property name of each `oneOf` branch.
+fn variant_property_names(one_of: &OneOf) -> Vec<&str> {
+    one_of
+        .items
+        .iter()
+        .map(|item| {
+            let RefOr::T(Schema::Object(branch)) = item else {
+                panic!("branch should be an inline object schema");
+            };
+            let props = branch
+                .properties
+                .as_ref()
+                .expect("branch should have properties");
+            props
+                .keys()
+                .next()
+                .expect("branch should have a property")
+                .as_str()
+        })
+        .collect()
+}
+
+#[test]
+fn derive_to_schema_with_serde_rename_all_fields_renames_struct_variant_fields() {
+    //* Given
+    #[derive(utocli::ToSchema, serde::Serialize)]
+    #[serde(tag = "kind", rename_all_fields = "camelCase")]
+    enum Event {
+        FileChanged {
+            file_path: String,
+            line_count: u32,
+        },
+        #[serde(rename_all = "kebab-case")]
+        DirRemoved {
+            dir_path: String,
+        },
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    let expected = serde_json::to_value(Event::FileChanged {
+        file_path: "a.txt".to_string(),
+        line_count: 3,
+    })
+    .expect("should serialize variant");
+    assert!(
+        expected.get("filePath").is_some(),
+        "serde should render the fields in camelCase"
+    );
+    let file_changed = &value["properties"]["FileChanged"];
+    assert_eq!(
+        file_changed["required"],
+        json!(["filePath", "lineCount", "kind"]),
+        "container rename_all_fields should rename the struct variant fields"
+    );
+    assert!(
+        file_changed["properties"].get("filePath").is_some(),
+        "renamed field should be a variant property"
+    );
+    assert_eq!(
+        value["properties"]["DirRemoved"]["required"],
+        json!(["dir-path", "kind"]),
+        "variant rename_all should take precedence over rename_all_fields"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_schema_rename_all_fields_renames_struct_variant_fields() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(rename_all_fields = "camelCase")]
+    enum Event {
+        Started(u32),
+        FileChanged { file_path: String },
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["oneOf"][1]["properties"]["FileChanged"]["required"],
+        json!(["filePath"]),
+        "schema rename_all_fields should rename the struct variant fields"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_discriminator_generates_one_of_with_mapping() {
+    //* Given
+    #[derive(serde::Serialize, utocli::ToSchema)]
+    struct FileSource {
+        path: String,
+    }
+
+    #[derive(serde::Serialize, utocli::ToSchema)]
+    struct UrlSource {
+        url: String,
+    }
+
+    #[derive(serde::Serialize, utocli::ToSchema)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    #[schema(discriminator = "type")]
+    enum Source {
+        File(FileSource),
+        Url(UrlSource),
+        Stdin,
+    }
+
+    //* When
+    let schema = Source::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["discriminator"],
+        serde_json::json!({
+            "propertyName": "type",
+            "mapping": {
+                "file": "#/components/schemas/FileSource",
+                "url": "#/components/schemas/UrlSource"
+            }
+        }),
+        "discriminator should map the variant names to their schema references"
+    );
+    assert_eq!(
+        value["oneOf"],
+        serde_json::json!([
+            { "$ref": "#/components/schemas/FileSource" },
+            { "$ref": "#/components/schemas/UrlSource" },
+            {
+                "type": "object",
+                "properties": {
+                    "type": { "type": "string", "enum": ["stdin"] }
+                },
+                "required": ["type"]
+            }
+        ]),
+        "newtype variants should be referenced and unit variants inlined with their tag"
+    );
+}
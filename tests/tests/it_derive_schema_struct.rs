@@ -7,7 +7,7 @@
 
 #![allow(dead_code)]
 
-use utocli::{Schema, SchemaType, ToSchema};
+use utocli::{RefOr, Schema, SchemaType, ToSchema};
 
 #[test]
 fn derive_to_schema_with_single_field_unnamed_struct_inlines_wrapped_type() {
@@ -252,3 +252,497 @@ fn schema_name_with_schema_as_returns_custom_name() {
         "schema name should use custom name from schema(as) attribute"
     );
 }
+
+#[test]
+fn derive_to_schema_with_fixed_size_array_field_generates_bounded_array() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Point {
+        coordinates: [f32; 3],
+    }
+
+    //* When
+    let schema = Point::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema for named struct");
+    };
+    let properties = obj.properties.expect("should have properties");
+    let Some(RefOr::T(Schema::Array(array))) = properties.get("coordinates") else {
+        panic!("Expected inline Array schema for fixed-size array field");
+    };
+    assert_eq!(
+        array.min_items,
+        Some(3),
+        "minItems should equal array length"
+    );
+    assert_eq!(
+        array.max_items,
+        Some(3),
+        "maxItems should equal array length"
+    );
+    let Some(RefOr::T(Schema::Object(items))) = array.items.as_deref() else {
+        panic!("Expected inline Object schema for array items");
+    };
+    assert_eq!(
+        items.schema_type,
+        Some(SchemaType::Number),
+        "f32 items should have number type"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_named_struct_preserves_field_declaration_order() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Ordered {
+        zeta: String,
+        alpha: i32,
+        mid: bool,
+    }
+
+    //* When
+    let schema = Ordered::schema();
+    let json = serde_json::to_string(&schema).expect("should serialize schema");
+
+    //* Then
+    let value: serde_json::Value = serde_json::from_str(&json).expect("should parse schema");
+    assert_eq!(
+        value["required"],
+        serde_json::json!(["zeta", "alpha", "mid"]),
+        "required should list fields in declaration order"
+    );
+
+    let positions: Vec<usize> = ["\"zeta\":{", "\"alpha\":{", "\"mid\":{"]
+        .iter()
+        .map(|key| json.find(key).expect("property should be serialized"))
+        .collect();
+    assert!(
+        positions.windows(2).all(|pair| pair[0] < pair[1]),
+        "properties should be serialized in declaration order, got: {json}"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_path_fields_generates_string_with_path_format() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Workspace {
+        root: std::path::PathBuf,
+        cache_dir: Option<std::ffi::OsString>,
+    }
+
+    //* When
+    let schema = Workspace::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["root"],
+        serde_json::json!({ "type": "string", "format": "path" }),
+        "PathBuf should map to a string with path format"
+    );
+    assert_eq!(
+        value["properties"]["cache_dir"],
+        serde_json::json!({ "type": "string", "format": "path" }),
+        "Option<OsString> should map to a string with path format"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_chrono_fields_generates_string_with_date_formats() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Release {
+        published_at: chrono::DateTime<chrono::Utc>,
+        cutoff: Option<chrono::NaiveDate>,
+    }
+
+    //* When
+    let schema = Release::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["published_at"],
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        "DateTime<Utc> should map to a string with date-time format"
+    );
+    assert_eq!(
+        value["properties"]["cutoff"],
+        serde_json::json!({ "type": "string", "format": "date" }),
+        "Option<NaiveDate> should map to a string with date format"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_imported_chrono_date_time_generates_string_with_date_time_format() {
+    //* Given
+    use chrono::{DateTime, Utc};
+
+    #[derive(utocli::ToSchema)]
+    struct Release {
+        published_at: DateTime<Utc>,
+    }
+
+    //* When
+    let schema = Release::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["published_at"],
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        "an imported DateTime<Utc> should map to a string with date-time format"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_time_fields_generates_string_with_date_formats() {
+    //* Given
+    use time::OffsetDateTime;
+
+    #[derive(utocli::ToSchema)]
+    struct Release {
+        published_at: time::OffsetDateTime,
+        updated_at: Option<OffsetDateTime>,
+        cutoff: time::Date,
+    }
+
+    //* When
+    let schema = Release::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["published_at"],
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        "OffsetDateTime should map to a string with date-time format"
+    );
+    assert_eq!(
+        value["properties"]["updated_at"],
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        "an imported OffsetDateTime should map to a string with date-time format"
+    );
+    assert_eq!(
+        value["properties"]["cutoff"],
+        serde_json::json!({ "type": "string", "format": "date" }),
+        "Date should map to a string with date format"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_uuid_field_generates_string_with_uuid_format() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Resource {
+        id: uuid::Uuid,
+    }
+
+    //* When
+    let schema = Resource::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["id"],
+        serde_json::json!({ "type": "string", "format": "uuid" }),
+        "Uuid should map to a string with uuid format"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_user_type_named_like_date_type_generates_reference() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct DateTime {
+        _value: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Uuid {
+        _value: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Event {
+        at: DateTime,
+        id: Uuid,
+    }
+
+    //* When
+    let schema = Event::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["at"],
+        serde_json::json!({ "$ref": "#/components/schemas/DateTime" }),
+        "a user type named DateTime should be referenced"
+    );
+    assert_eq!(
+        value["properties"]["id"],
+        serde_json::json!({ "$ref": "#/components/schemas/Uuid" }),
+        "a user type named Uuid should be referenced"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_vec_of_pairs_generates_array_of_pairs() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Config {
+        limits: Vec<(String, u32)>,
+    }
+
+    //* When
+    let schema = Config::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["limits"],
+        serde_json::json!({
+            "type": "array",
+            "items": {
+                "type": "array",
+                "items": {
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "integer", "format": "int32" }
+                    ]
+                },
+                "minItems": 2,
+                "maxItems": 2
+            }
+        }),
+        "pairs should be documented as two-element arrays"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_overlapping_pair_types_generates_any_of_items() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Range {
+        bounds: (u32, u64),
+    }
+
+    //* When
+    let schema = Range::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["bounds"],
+        serde_json::json!({
+            "type": "array",
+            "items": {
+                "anyOf": [
+                    { "type": "integer", "format": "int32" },
+                    { "type": "integer", "format": "int64" }
+                ]
+            },
+            "minItems": 2,
+            "maxItems": 2
+        }),
+        "items of overlapping types should be an anyOf, so a value may match both"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_as_map_vec_of_pairs_generates_object() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Config {
+        #[schema(as_map)]
+        labels: Vec<(String, String)>,
+    }
+
+    //* When
+    let schema = Config::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["labels"],
+        serde_json::json!({
+            "type": "object",
+            "additionalProperties": { "type": "string" }
+        }),
+        "as_map pairs should be documented as an object of values"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_result_field_generates_any_of_branches() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Outcome {
+        result: Result<String, u32>,
+    }
+
+    //* When
+    let schema = Outcome::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["result"],
+        serde_json::json!({
+            "anyOf": [
+                { "type": "string" },
+                { "type": "integer", "format": "int32" }
+            ]
+        }),
+        "Result should be documented as an anyOf of its Ok and Err schemas"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_same_type_result_field_generates_single_schema() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Outcome {
+        result: Result<String, String>,
+    }
+
+    //* When
+    let schema = Outcome::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["result"],
+        serde_json::json!({ "type": "string" }),
+        "a Result with the same Ok and Err types should collapse to their shared schema"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_result_as_tagged_field_generates_ok_and_err_objects() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Outcome {
+        #[schema(result_as_tagged)]
+        result: Result<String, u32>,
+    }
+
+    //* When
+    let schema = Outcome::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["result"],
+        serde_json::json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": { "Ok": { "type": "string" } },
+                    "required": ["Ok"]
+                },
+                {
+                    "type": "object",
+                    "properties": { "Err": { "type": "integer", "format": "int32" } },
+                    "required": ["Err"]
+                }
+            ]
+        }),
+        "tagged Result should be documented as serde's Ok/Err objects"
+    );
+}
+
+#[test]
+fn derive_schema_with_custom_type_fields_reports_schema_references() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Tag {
+        label: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Pet {
+        name: String,
+        owner: Option<Owner>,
+        tags: Vec<Tag>,
+        previous_owner: Owner,
+    }
+
+    //* When
+    let references = Pet::schema_references();
+
+    //* Then
+    assert_eq!(
+        references,
+        vec!["Owner", "Tag"],
+        "both referenced types should be reported once, in order of appearance"
+    );
+    assert!(
+        Owner::schema_references().is_empty(),
+        "a schema of primitive fields should reference no component"
+    );
+}
+
+#[test]
+fn derive_schema_with_inline_field_reports_references_of_inlined_type() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Tag {
+        label: String,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Profile {
+        owner: Owner,
+        tags: Vec<Tag>,
+    }
+
+    #[derive(utocli::ToSchema)]
+    struct Pet {
+        tag: Tag,
+        #[schema(inline)]
+        profile: Profile,
+    }
+
+    //* When
+    let references = Pet::schema_references();
+
+    //* Then
+    assert_eq!(
+        references,
+        vec!["Tag", "Owner"],
+        "references of the inlined type should be reported once, after the direct ones"
+    );
+}
+
+#[test]
+fn derive_schema_with_reference_like_text_reports_no_references() {
+    //* Given
+    /// Mirrors `#/components/schemas/Owner` without referencing it.
+    #[derive(utocli::ToSchema)]
+    struct Note {
+        #[schema(example = "#/components/schemas/Tag")]
+        text: String,
+    }
+
+    //* When
+    let references = Note::schema_references();
+
+    //* Then
+    assert!(
+        references.is_empty(),
+        "descriptions and examples should not be reported as references, got {references:?}"
+    );
+}
@@ -270,6 +270,38 @@ fn derive_to_schema_with_serde_rename_all_container_applies_camel_case() {
     );
 }
 
+#[test]
+fn derive_to_schema_with_split_serde_rename_all_uses_serialize_rule() {
+    //* Given
+    #[derive(serde::Deserialize, utocli::ToSchema)]
+    #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+    struct ApiResponse {
+        status_code: u32,
+        error_message: String,
+    }
+
+    //* When
+    let schema = ApiResponse::schema();
+
+    //* Then
+    let Schema::Object(obj) = schema else {
+        panic!("Expected Object schema for struct");
+    };
+
+    let props = obj
+        .properties
+        .as_ref()
+        .expect("properties should be present");
+    assert!(
+        props.contains_key("statusCode") && props.contains_key("errorMessage"),
+        "fields should be renamed with the serialize rule"
+    );
+    assert!(
+        !props.contains_key("status_code"),
+        "the deserialize rule should not be used"
+    );
+}
+
 #[test]
 fn derive_to_schema_with_rename_all_and_individual_rename_respects_precedence() {
     //* Given
@@ -0,0 +1,568 @@
+//! E2E tests for ToParameter derive macro.
+//!
+//! These tests verify that the derive macro generates correct parameter definitions
+//! from struct fields and their `#[param(...)]` attributes.
+
+#![allow(dead_code)]
+
+#[test]
+fn derive_to_parameter_with_env_binds_environment_variable() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct ConfigParam {
+        /// Path to the configuration file
+        #[param(env = "OCS_CONFIG_PATH")]
+        config: Option<String>,
+
+        verbose: bool,
+    }
+
+    //* When
+    let params = ConfigParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].env.as_deref(),
+        Some("OCS_CONFIG_PATH"),
+        "config should fall back to the OCS_CONFIG_PATH environment variable"
+    );
+    assert_eq!(
+        params[1].env, None,
+        "verbose should not be bound to an environment variable"
+    );
+
+    let value = serde_json::to_value(&params[0]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-env"],
+        serde_json::json!("OCS_CONFIG_PATH"),
+        "env binding should be serialized as the x-env extension"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_deprecated_marks_parameter_deprecated() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct SyncParam {
+        /// Replaced by `--new-flag`
+        #[param(deprecated)]
+        old_flag: bool,
+
+        new_flag: bool,
+    }
+
+    //* When
+    let params = SyncParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].deprecated,
+        Some(true),
+        "old_flag should be marked deprecated"
+    );
+    assert_eq!(
+        params[1].deprecated, None,
+        "new_flag should not be marked deprecated"
+    );
+
+    let value = serde_json::to_value(&params[0]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-deprecated"],
+        serde_json::json!(true),
+        "deprecated flag should be serialized as the x-deprecated extension"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_long_short_and_visible_aliases_maps_name_and_aliases() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct OutputParam {
+        /// Output file
+        #[param(
+            long = "output",
+            short = 'o',
+            visible_alias = "out",
+            visible_alias = "dest"
+        )]
+        output_file: Option<String>,
+    }
+
+    //* When
+    let params = OutputParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].name, "output",
+        "long name should be the parameter name"
+    );
+    assert_eq!(
+        params[0].alias,
+        Some(vec!["o".to_string(), "out".to_string(), "dest".to_string()]),
+        "short and visible aliases should all be parameter aliases"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_alias_list_accumulates_aliases() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct ListParam {
+        /// Show all entries
+        #[param(alias("a", "all"))]
+        show_all: bool,
+
+        /// Sort entries
+        #[param(alias = "s", alias("order", "sort-by"))]
+        sort: Option<String>,
+    }
+
+    //* When
+    let params = ListParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].alias,
+        Some(vec!["a".to_string(), "all".to_string()]),
+        "list form should add every alias"
+    );
+    assert_eq!(
+        params[1].alias,
+        Some(vec![
+            "s".to_string(),
+            "order".to_string(),
+            "sort-by".to_string()
+        ]),
+        "scalar and list forms should accumulate"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_clap_default_values_sets_schema_defaults() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct LogParams {
+        /// Log level
+        #[param(default_value_t = 3)]
+        level: u8,
+
+        /// User name
+        #[param(default_value = "anon")]
+        name: String,
+    }
+
+    //* When
+    let params = LogParams::parameters();
+
+    //* Then
+    let defaults = params
+        .iter()
+        .map(|param| match &param.schema {
+            Some(utocli::RefOr::T(utocli::Schema::Object(obj))) => obj.default.clone(),
+            other => panic!("expected an inline object schema, got {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        defaults[0],
+        Some(serde_json::json!(3)),
+        "--level should default to the typed value 3"
+    );
+    assert_eq!(
+        defaults[1],
+        Some(serde_json::json!("anon")),
+        "--name should default to the string \"anon\""
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_defaulted_positional_validates_as_not_required() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct CopyParams {
+        #[param(position = 1)]
+        source: String,
+
+        #[param(position = 2, default_value = ".")]
+        target: String,
+    }
+
+    let mut commands = utocli::Commands::new();
+    commands.insert(
+        "copy".to_string(),
+        utocli::Command::new().parameters(CopyParams::parameters()),
+    );
+    let opencli =
+        utocli::opencli::OpenCli::new(utocli::Info::new("app", "1.0.0")).commands(commands);
+
+    //* When
+    let result = opencli.validate();
+
+    //* Then
+    let params = CopyParams::parameters();
+    assert_eq!(
+        params[0].required,
+        Some(true),
+        "a positional without a default should be required"
+    );
+    assert_eq!(
+        params[1].required, None,
+        "a positional with a default should not be required"
+    );
+    assert!(
+        result.is_ok(),
+        "a defaulted positional should pass validation, got {result:?}"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_doc_comment_and_help_prefers_explicit_description() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct BuildParam {
+        /// Build in release mode
+        release: bool,
+
+        /// Number of parallel jobs
+        #[param(help = "Parallel jobs to run")]
+        jobs: Option<u32>,
+
+        /// Target directory
+        #[param(
+            help = "Directory for build artifacts",
+            description = "Output directory"
+        )]
+        target_dir: Option<String>,
+    }
+
+    //* When
+    let params = BuildParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].description.as_deref(),
+        Some("Build in release mode"),
+        "doc comment should be used when no explicit description is given"
+    );
+    assert_eq!(
+        params[1].description.as_deref(),
+        Some("Parallel jobs to run"),
+        "help should take precedence over the doc comment"
+    );
+    assert_eq!(
+        params[2].description.as_deref(),
+        Some("Output directory"),
+        "description should take precedence over help and the doc comment"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_rename_all_kebab_case_renames_parameter_names() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    #[param(rename_all = "kebab-case")]
+    struct BuildParam {
+        output_dir: Option<String>,
+
+        dry_run: bool,
+
+        #[param(rename = "jobs_count")]
+        max_jobs: Option<u32>,
+    }
+
+    //* When
+    let params = BuildParam::parameters();
+
+    //* Then
+    let names = params
+        .iter()
+        .map(|param| param.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["output-dir", "dry-run", "jobs_count"],
+        "snake_case fields should become kebab-case unless renamed explicitly"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_positional_vec_marks_variadic_trailing_argument() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct LintParam {
+        #[param(position = 1)]
+        files: Vec<std::path::PathBuf>,
+    }
+
+    //* When
+    let params = LintParam::parameters();
+
+    //* Then
+    let files = &params[0];
+    assert_eq!(files.position, Some(1), "files should keep its position");
+    assert_eq!(
+        files.arity,
+        Some(utocli::Arity::new().min(0)),
+        "files should accept zero or more values with no upper bound"
+    );
+    assert_eq!(
+        files.required, None,
+        "a variadic argument should not be marked required"
+    );
+    assert!(files.is_variadic(), "files should be variadic");
+}
+
+#[test]
+fn derive_to_parameter_with_hidden_or_clap_hide_marks_parameter_hidden() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct DebugParam {
+        #[param(hidden)]
+        dump_ast: bool,
+
+        #[param(hide = true)]
+        trace_file: Option<String>,
+
+        verbose: bool,
+    }
+
+    //* When
+    let params = DebugParam::parameters();
+
+    //* Then
+    assert_eq!(params[0].hidden, Some(true), "dump_ast should be hidden");
+    assert_eq!(
+        params[1].hidden,
+        Some(true),
+        "clap-style hide should mark trace_file hidden"
+    );
+    assert_eq!(params[2].hidden, None, "verbose should not be hidden");
+
+    let value = serde_json::to_value(&params[0]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-hidden"],
+        serde_json::json!(true),
+        "hidden parameters should be serialized as the x-hidden extension"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_in_environment_sets_environment_location() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct DeployParam {
+        #[param(in = "environment", rename = "DEPLOY_TOKEN")]
+        token: Option<String>,
+    }
+
+    //* When
+    let params = DeployParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].name, "DEPLOY_TOKEN",
+        "the parameter should be named after the environment variable"
+    );
+    assert_eq!(
+        params[0].in_,
+        Some(utocli::ParameterIn::Environment),
+        "token should be sourced from the environment"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_conflicts_with_relates_parameters_by_name() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    #[param(rename_all = "kebab-case")]
+    struct ExportParam {
+        #[param(conflicts_with = "yaml")]
+        json: bool,
+
+        #[param(conflicts_with = "json")]
+        yaml: bool,
+
+        #[param(requires = "output_format")]
+        output_dir: Option<String>,
+
+        output_format: Option<String>,
+    }
+
+    //* When
+    let params = ExportParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].conflicts,
+        Some(vec!["yaml".to_string()]),
+        "--json should conflict with --yaml"
+    );
+    assert_eq!(
+        params[1].conflicts,
+        Some(vec!["json".to_string()]),
+        "--yaml should conflict with --json"
+    );
+    assert_eq!(
+        params[2].requires,
+        Some(vec!["output-format".to_string()]),
+        "field references should resolve to the renamed parameter name"
+    );
+
+    let value = serde_json::to_value(&params[0]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-conflicts"],
+        serde_json::json!(["yaml"]),
+        "conflicts should be serialized as x-conflicts"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_value_name_sets_help_placeholder() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct OutputParam {
+        #[param(value_name = "FILE")]
+        output: Option<String>,
+
+        format: Option<String>,
+    }
+
+    //* When
+    let params = OutputParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].value_name.as_deref(),
+        Some("FILE"),
+        "--output should use the FILE placeholder"
+    );
+    assert_eq!(
+        params[1].value_name, None,
+        "--format should have no value name"
+    );
+
+    let value = serde_json::to_value(&params[0]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-value-name"],
+        serde_json::json!("FILE"),
+        "value name should be serialized as x-value-name"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_possible_values_from_references_enum_schema_component() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    enum LogLevel {
+        Debug,
+        Info,
+        Error,
+    }
+
+    #[derive(utocli::ToParameter)]
+    struct LogParam {
+        #[param(possible_values_from = LogLevel)]
+        level: Option<String>,
+    }
+
+    //* When
+    let params = LogParam::parameters();
+
+    //* Then
+    match &params[0].schema {
+        Some(utocli::RefOr::Ref(reference)) => assert_eq!(
+            reference.ref_path, "#/components/schemas/LogLevel",
+            "possible_values_from should reference the enum schema component"
+        ),
+        other => panic!("expected a schema reference, got {other:?}"),
+    }
+}
+
+#[test]
+fn derive_to_parameter_with_section_and_help_heading_sets_help_section() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct NetworkParam {
+        #[param(section = "Networking")]
+        proxy: Option<String>,
+
+        #[param(help_heading = "Networking")]
+        timeout: Option<String>,
+
+        verbose: bool,
+    }
+
+    //* When
+    let params = NetworkParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].section.as_deref(),
+        Some("Networking"),
+        "--proxy should be grouped under the section attribute"
+    );
+    assert_eq!(
+        params[1].section.as_deref(),
+        Some("Networking"),
+        "--timeout should be grouped under clap's help_heading"
+    );
+    assert_eq!(params[2].section, None, "--verbose should have no section");
+
+    let value = serde_json::to_value(&params[1]).expect("should serialize parameter");
+    assert_eq!(
+        value["x-section"],
+        serde_json::json!("Networking"),
+        "section should be serialized as x-section"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_flag_style_sets_flag_style() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct LintParam {
+        #[param(flag_style = "presence")]
+        strict: bool,
+        #[param(flag_style = "value")]
+        color: bool,
+    }
+
+    //* When
+    let params = LintParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].flag_style,
+        Some(utocli::FlagStyle::Presence),
+        "--strict should be a presence flag"
+    );
+    assert_eq!(
+        params[1].flag_style,
+        Some(utocli::FlagStyle::Value),
+        "--color should take an explicit boolean"
+    );
+}
+
+#[test]
+fn derive_to_parameter_with_default_missing_value_makes_color_value_optional() {
+    //* Given
+    #[derive(utocli::ToParameter)]
+    struct ColorParam {
+        #[param(default_missing_value = "always")]
+        color: Option<String>,
+    }
+
+    //* When
+    let params = ColorParam::parameters();
+
+    //* Then
+    assert_eq!(
+        params[0].default_missing_value.as_deref(),
+        Some("always"),
+        "a bare --color should default to always"
+    );
+    assert_eq!(
+        params[0].arity,
+        Some(utocli::Arity::range(0, 1)),
+        "--color should accept zero or one value"
+    );
+}
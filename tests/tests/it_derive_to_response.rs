@@ -0,0 +1,141 @@
+//! E2E tests for ToResponse derive macro.
+//!
+//! These tests verify that the derive macro generates correct response definitions
+//! from struct fields and their `#[content(...)]` attributes.
+
+#![allow(dead_code)]
+
+use utocli::{RefOr, ToResponse as _};
+
+#[test]
+fn derive_to_response_with_schema_and_no_media_type_defaults_to_json() {
+    //* Given
+    #[derive(utocli::ToResponse)]
+    #[response(description = "Validation completed successfully")]
+    struct ValidationSuccess {
+        #[content(schema = "ValidationResult")]
+        output: (),
+    }
+
+    //* When
+    let (_, response) = ValidationSuccess::response();
+
+    //* Then
+    let RefOr::T(response) = response else {
+        panic!("expected an inline response");
+    };
+    let content = response.content.expect("should have content");
+    assert_eq!(content.len(), 1, "should have a single content entry");
+    let media = content
+        .get("application/json")
+        .expect("schema-bearing content should default to application/json");
+    assert!(
+        matches!(&media.schema, Some(RefOr::Ref(reference)) if reference.ref_path == "#/components/schemas/ValidationResult"),
+        "content should reference the ValidationResult schema"
+    );
+}
+
+#[test]
+fn derive_to_response_with_example_only_and_no_media_type_defaults_to_text_plain() {
+    //* Given
+    #[derive(utocli::ToResponse)]
+    #[response(description = "Validation completed successfully")]
+    struct ValidationSuccess {
+        #[content(media_type = "application/json", schema = "ValidationResult")]
+        json_output: (),
+
+        #[content(example = "✓ Validation successful")]
+        text_output: (),
+    }
+
+    //* When
+    let (_, response) = ValidationSuccess::response();
+
+    //* Then
+    let RefOr::T(response) = response else {
+        panic!("expected an inline response");
+    };
+    let content = response.content.expect("should have content");
+    assert_eq!(content.len(), 2, "should have both content entries");
+    let media = content
+        .get("text/plain")
+        .expect("example-only content should default to text/plain");
+    assert_eq!(
+        media.example,
+        Some(serde_json::json!("✓ Validation successful")),
+        "text/plain content should keep the example"
+    );
+    assert_eq!(
+        media.schema, None,
+        "text/plain content should have no schema"
+    );
+}
+
+#[test]
+fn derive_to_response_with_example_file_embeds_file_contents() {
+    //* Given
+    #[derive(utocli::ToResponse)]
+    #[response(description = "Validation completed successfully")]
+    struct ValidationSuccess {
+        #[content(
+            media_type = "application/json",
+            example_file = "tests/assets/examples/validate-success.json"
+        )]
+        json_output: (),
+
+        #[content(
+            media_type = "text/plain",
+            example_file = "tests/assets/examples/validate-success.txt"
+        )]
+        text_output: (),
+    }
+
+    //* When
+    let (_, response) = ValidationSuccess::response();
+
+    //* Then
+    let RefOr::T(response) = response else {
+        panic!("expected an inline response");
+    };
+    let content = response.content.expect("should have content");
+    assert_eq!(
+        content["application/json"].example,
+        Some(serde_json::json!({ "valid": true, "file": "opencli.yaml", "errors": [] })),
+        "JSON example file should be embedded as its JSON value"
+    );
+    assert_eq!(
+        content["text/plain"].example,
+        Some(serde_json::json!("✓ Validation successful\n")),
+        "text example file should be embedded as a string"
+    );
+}
+
+#[test]
+fn derive_to_response_with_extend_adds_response_extensions() {
+    //* Given
+    #[derive(utocli::ToResponse)]
+    #[response(
+        description = "Download failed",
+        extend(x_retryable = "true", category = "io")
+    )]
+    struct DownloadFailed;
+
+    //* When
+    let (_, response) = DownloadFailed::response();
+
+    //* Then
+    let RefOr::T(response) = response else {
+        panic!("expected an inline response");
+    };
+    let extensions = response.extensions.expect("should have extensions");
+    assert_eq!(
+        extensions.get("x-retryable"),
+        Some(&serde_json::json!("true")),
+        "x_retryable should become the x-retryable extension"
+    );
+    assert_eq!(
+        extensions.get("x-category"),
+        Some(&serde_json::json!("io")),
+        "keys without the x- prefix should be prefixed"
+    );
+}
@@ -2,7 +2,7 @@
 
 #![allow(dead_code)]
 
-use utocli::{ParameterIn, RefOr, Schema, ToSchema};
+use utocli::{ExclusiveBound, ParameterIn, RefOr, Schema, ToSchema};
 
 #[test]
 fn derive_to_parameter_with_numeric_minimum_and_maximum_applies_constraints() {
@@ -479,7 +479,7 @@ fn derive_to_parameter_with_exclusive_minimum_and_maximum_applies_constraints()
         );
         assert_eq!(
             obj.exclusive_minimum,
-            Some(true),
+            Some(ExclusiveBound::Flag(true)),
             "percentage should have exclusive_minimum constraint"
         );
         assert_eq!(
@@ -489,7 +489,7 @@ fn derive_to_parameter_with_exclusive_minimum_and_maximum_applies_constraints()
         );
         assert_eq!(
             obj.exclusive_maximum,
-            Some(true),
+            Some(ExclusiveBound::Flag(true)),
             "percentage should have exclusive_maximum constraint"
         );
     } else {
@@ -565,7 +565,7 @@ fn derive_to_schema_with_exclusive_minimum_and_maximum_applies_to_fields() {
             );
             assert_eq!(
                 pos_obj.exclusive_minimum,
-                Some(true),
+                Some(ExclusiveBound::Flag(true)),
                 "positive_value should have exclusive_minimum constraint"
             );
         } else {
@@ -580,7 +580,7 @@ fn derive_to_schema_with_exclusive_minimum_and_maximum_applies_to_fields() {
             );
             assert_eq!(
                 below_obj.exclusive_maximum,
-                Some(true),
+                Some(ExclusiveBound::Flag(true)),
                 "below_hundred should have exclusive_maximum constraint"
             );
         } else {
@@ -664,15 +664,142 @@ fn derive_to_parameter_with_all_advanced_validations_applies_all_constraints() {
         );
         assert_eq!(
             obj.exclusive_minimum,
-            Some(false),
+            Some(ExclusiveBound::Flag(false)),
             "score should have exclusive_minimum set to false"
         );
         assert_eq!(
             obj.exclusive_maximum,
-            Some(true),
+            Some(ExclusiveBound::Flag(true)),
             "score should have exclusive_maximum set to true"
         );
     } else {
         panic!("Expected object schema for score parameter");
     }
 }
+
+#[test]
+fn derive_to_schema_with_container_string_constraints_applies_to_unit_struct_and_enum() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(max_length = 16)]
+    struct Token;
+
+    #[derive(utocli::ToSchema)]
+    #[schema(rename_all = "lowercase", pattern = "^[a-z]+$", min_length = 3)]
+    enum Format {
+        Json,
+        Yaml,
+    }
+
+    //* When
+    let token = Token::schema();
+    let format = Format::schema();
+
+    //* Then
+    let Schema::Object(token) = token else {
+        panic!("Expected Object schema for unit struct");
+    };
+    assert_eq!(
+        token.max_length,
+        Some(16),
+        "unit struct string schema should have maxLength"
+    );
+
+    let Schema::Object(format) = format else {
+        panic!("Expected Object schema for plain enum");
+    };
+    assert_eq!(
+        format.pattern.as_deref(),
+        Some("^[a-z]+$"),
+        "enum string schema should have pattern"
+    );
+    assert_eq!(
+        format.min_length,
+        Some(3),
+        "enum string schema should have minLength"
+    );
+    assert_eq!(
+        format.enum_values,
+        Some(vec![serde_json::json!("json"), serde_json::json!("yaml")]),
+        "enum values should be kept"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_exclusive_bounds_serializes_legacy_flags_by_default() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    struct Item {
+        #[schema(
+            minimum = 0,
+            exclusive_minimum = true,
+            maximum = 1,
+            exclusive_maximum = true
+        )]
+        ratio: f64,
+    }
+
+    //* When
+    let schema = Item::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["ratio"],
+        serde_json::json!({
+            "type": "number",
+            "format": "double",
+            "minimum": 0.0,
+            "maximum": 1.0,
+            "exclusiveMinimum": true,
+            "exclusiveMaximum": true
+        }),
+        "exclusive bounds should be boolean flags on the minimum and maximum"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_numeric_exclusive_bounds_serializes_bound_values() {
+    //* Given
+    #[derive(utocli::ToSchema)]
+    #[schema(numeric_exclusive_bounds)]
+    struct Item {
+        #[schema(
+            minimum = 0,
+            exclusive_minimum = true,
+            maximum = 1,
+            exclusive_maximum = true
+        )]
+        ratio: f64,
+
+        #[schema(minimum = 1, exclusive_minimum = false)]
+        count: u32,
+    }
+
+    //* When
+    let schema = Item::schema();
+
+    //* Then
+    let value = serde_json::to_value(&schema).expect("should serialize schema");
+    assert_eq!(
+        value["properties"]["ratio"],
+        serde_json::json!({
+            "type": "number",
+            "format": "double",
+            "exclusiveMinimum": 0.0,
+            "exclusiveMaximum": 1.0
+        }),
+        "exclusive bounds should be the 2020-12 numeric values"
+    );
+    assert_eq!(
+        value["properties"]["count"]["minimum"],
+        serde_json::json!(1.0),
+        "inclusive bounds should stay minimums"
+    );
+    assert!(
+        value["properties"]["count"]
+            .get("exclusiveMinimum")
+            .is_none(),
+        "false flags should not be emitted in the numeric form"
+    );
+}